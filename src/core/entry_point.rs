@@ -290,10 +290,8 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint32 {
         let entry_point_length = raw[Self::ENTRY_POINT_LENGTH_OFFSET] as usize;
         match raw.get(0..entry_point_length) {
             Some(checked_bytes) => {
-                if !verify_checksum(checked_bytes) {
-                    return Err(Error::new(
-                ErrorKind::InvalidData,"Entry Point Structure checksum verification failed"));
-                }
+                verify_checksum(checked_bytes, Self::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET)
+                    .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
             }
             None => return Err(Error::new(
                 ErrorKind::InvalidData,"The Entry Point Length field specified a value which exceeded the bounds of the Entry Point Structure")),
@@ -315,16 +313,15 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint32 {
         // Verify the IEPS checksum
         // The checksum is calculated for a length of 0x0F
         let intermediate_entry_point_structure: [u8; 0x0F] = raw
-            [Self::INTERMEDIATE_ANCHOR_OFFSET..]
+            [Self::INTERMEDIATE_ANCHOR_OFFSET..Self::INTERMEDIATE_ANCHOR_OFFSET + 0x0F]
             .try_into()
             .expect("0x0F bytes");
 
-        if !verify_checksum(&intermediate_entry_point_structure) {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "Intermediate entry point structure checksum verification failed",
-            ));
-        }
+        verify_checksum(
+            &intermediate_entry_point_structure,
+            Self::INTERMEDIATE_CHECKSUM_OFFSET - Self::INTERMEDIATE_ANCHOR_OFFSET,
+        )
+        .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
 
         Ok(SMBiosEntryPoint32 { raw })
     }
@@ -332,7 +329,7 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint32 {
 
 impl fmt::Debug for SMBiosEntryPoint32 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosEntryPoint32>())
+        fmt.debug_struct(core::any::type_name::<SMBiosEntryPoint32>())
             .field(
                 "entry_point_structure_checksum",
                 &self.entry_point_structure_checksum(),
@@ -556,7 +553,7 @@ impl<'a> SMBiosEntryPoint64 {
 
 impl fmt::Debug for SMBiosEntryPoint64 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosEntryPoint64>())
+        fmt.debug_struct(core::any::type_name::<SMBiosEntryPoint64>())
             .field(
                 "entry_point_structure_checksum",
                 &self.entry_point_structure_checksum(),
@@ -624,9 +621,8 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint64 {
         let entry_point_length = raw[Self::ENTRY_POINT_LENGTH_OFFSET] as usize;
         match raw.get(0..entry_point_length) {
             Some(checked_bytes) => {
-                if !verify_checksum(checked_bytes) {
-                    return Err(Error::new(ErrorKind::InvalidData,"Entry Point Structure checksum verification failed"));
-                }
+                verify_checksum(checked_bytes, Self::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET)
+                    .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
             }
             None => return Err(Error::new(ErrorKind::InvalidData,"The Entry Point Length field specified a value which exceeded the bounds of the Entry Point Structure")),
         }
@@ -635,7 +631,75 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint64 {
     }
 }
 
-/// Verifies EPS and IEPS Checksums
+/// # SMBIOS Entry Point structure
+///
+/// Either the SMBIOS 2.1 (32 bit) `_SM_` or the SMBIOS 3.0 (64 bit) `_SM3_`
+/// Entry Point structure, whichever precedes the structure table.
+#[derive(Debug, Serialize)]
+pub enum SMBiosEntryPoint {
+    /// SMBIOS 2.1 (32 bit) Entry Point structure
+    Bit32(SMBiosEntryPoint32),
+    /// SMBIOS 3.0 (64 bit) Entry Point structure
+    Bit64(SMBiosEntryPoint64),
+}
+
+impl SMBiosEntryPoint {
+    /// SMBIOS Major Version
+    pub fn major_version(&self) -> u8 {
+        match self {
+            SMBiosEntryPoint::Bit32(entry_point) => entry_point.major_version(),
+            SMBiosEntryPoint::Bit64(entry_point) => entry_point.major_version(),
+        }
+    }
+
+    /// SMBIOS Minor Version
+    pub fn minor_version(&self) -> u8 {
+        match self {
+            SMBiosEntryPoint::Bit32(entry_point) => entry_point.minor_version(),
+            SMBiosEntryPoint::Bit64(entry_point) => entry_point.minor_version(),
+        }
+    }
+
+    /// Structure Table Length
+    ///
+    /// For the 32 bit entry point this is the exact length of the structure
+    /// table; for the 64 bit entry point it is the maximum size of the table.
+    pub fn structure_table_length(&self) -> u32 {
+        match self {
+            SMBiosEntryPoint::Bit32(entry_point) => entry_point.structure_table_length() as u32,
+            SMBiosEntryPoint::Bit64(entry_point) => entry_point.structure_table_maximum_size(),
+        }
+    }
+
+    /// Structure Table Address
+    ///
+    /// Physical starting address of the SMBIOS Structure Table
+    pub fn structure_table_address(&self) -> u64 {
+        match self {
+            SMBiosEntryPoint::Bit32(entry_point) => entry_point.structure_table_address() as u64,
+            SMBiosEntryPoint::Bit64(entry_point) => entry_point.structure_table_address(),
+        }
+    }
+
+    /// Load this structure from a file
+    pub fn try_load_from_file(filename: &Path) -> Result<Self, Error> {
+        read(filename)?.try_into()
+    }
+}
+
+impl TryFrom<Vec<u8>> for SMBiosEntryPoint {
+    type Error = Error;
+
+    fn try_from(raw: Vec<u8>) -> Result<Self, Self::Error> {
+        if raw.starts_with(&SMBiosEntryPoint64::SM3_ANCHOR) {
+            SMBiosEntryPoint64::try_from(raw).map(SMBiosEntryPoint::Bit64)
+        } else {
+            SMBiosEntryPoint32::try_from(raw).map(SMBiosEntryPoint::Bit32)
+        }
+    }
+}
+
+/// Computes an EPS or IEPS checksum
 ///
 /// The EPS and IEPS contain a checksum value.
 ///
@@ -643,10 +707,189 @@ impl<'a> TryFrom<Vec<u8>> for SMBiosEntryPoint64 {
 /// the value 00h (using 8-bit addition [Wrapping] calculations).
 /// Values in the EPS are summed starting at offset 00h, for 'entry_point_length'
 /// bytes.
-fn verify_checksum(data: &[u8]) -> bool {
+///
+/// Returns the checksum byte `data` should hold at `checksum_offset`; the
+/// byte currently at `checksum_offset` is not included in the sum.
+pub fn compute_checksum(data: &[u8], checksum_offset: usize) -> u8 {
     let mut sum = Wrapping(0u8);
 
-    data.iter().for_each(|b| sum += Wrapping(*b));
+    data.iter()
+        .enumerate()
+        .filter(|(offset, _)| *offset != checksum_offset)
+        .for_each(|(_, b)| sum += Wrapping(*b));
+
+    (Wrapping(0u8) - sum).0
+}
+
+/// Verifies an EPS or IEPS checksum
+///
+/// `data` is the checksummed region and `checksum_offset` the offset of its
+/// checksum byte within `data`, see [compute_checksum].
+pub fn verify_checksum(data: &[u8], checksum_offset: usize) -> Result<(), SMBiosChecksumError> {
+    let actual = *data
+        .get(checksum_offset)
+        .ok_or(SMBiosChecksumError::Missing)?;
+    let expected = compute_checksum(data, checksum_offset);
+
+    match expected == actual {
+        true => Ok(()),
+        false => Err(SMBiosChecksumError::Mismatch { expected, actual }),
+    }
+}
+
+/// # SMBiosChecksumError
+///
+/// An entry point checksum verification error
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SMBiosChecksumError {
+    /// The checksum byte does not match the data
+    Mismatch {
+        /// The checksum byte the data should contain
+        expected: u8,
+        /// The checksum byte the data contains
+        actual: u8,
+    },
+    /// The data does not contain the checksum offset
+    Missing,
+}
+
+impl fmt::Display for SMBiosChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SMBiosChecksumError::Mismatch { expected, actual } => write!(
+                f,
+                "Checksum verification failed: expected 0x{:02X}, found 0x{:02X}",
+                expected, actual
+            ),
+            SMBiosChecksumError::Missing => {
+                write!(f, "Checksum verification failed: checksum byte not present")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SMBiosChecksumError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sets the checksum byte at `checksum_offset` so that `data` sums to zero
+    fn fix_checksum(data: &mut [u8], checksum_offset: usize) {
+        data[checksum_offset] = compute_checksum(data, checksum_offset);
+    }
+
+    fn entry_point_32_bytes() -> Vec<u8> {
+        let mut raw = vec![
+            b'_', b'S', b'M', b'_', 0x00, 0x1F, 0x02, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, b'_', b'D', b'M', b'I', b'_', 0x00, 0x34, 0x12, 0x00, 0x00, 0x0E, 0x00,
+            0x2A, 0x00, 0x28,
+        ];
+        fix_checksum(&mut raw[0x10..0x1F], 0x05);
+        fix_checksum(&mut raw, 0x04);
+        raw
+    }
+
+    fn entry_point_64_bytes() -> Vec<u8> {
+        let mut raw = vec![
+            b'_', b'S', b'M', b'3', b'_', 0x00, 0x18, 0x03, 0x04, 0x00, 0x01, 0x00, 0x00, 0x20,
+            0x00, 0x00, 0x00, 0x10, 0x32, 0x54, 0x01, 0x00, 0x00, 0x00,
+        ];
+        fix_checksum(&mut raw, 0x05);
+        raw
+    }
+
+    #[test]
+    fn test_entry_point_32() {
+        let entry_point = SMBiosEntryPoint::try_from(entry_point_32_bytes()).unwrap();
+        assert!(matches!(entry_point, SMBiosEntryPoint::Bit32(_)));
+        assert_eq!(entry_point.major_version(), 2);
+        assert_eq!(entry_point.minor_version(), 8);
+        assert_eq!(entry_point.structure_table_length(), 0x1234);
+        assert_eq!(entry_point.structure_table_address(), 0x000E_0000);
+
+        // Trailing bytes beyond the entry point are tolerated
+        let mut raw = entry_point_32_bytes();
+        raw.push(0xFF);
+        assert!(SMBiosEntryPoint32::try_from(raw).is_ok());
+
+        let mut raw = entry_point_32_bytes();
+        raw[0x04] = raw[0x04].wrapping_add(1);
+        let err = SMBiosEntryPoint::try_from(raw).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // Intermediate checksum is also verified
+        let mut raw = entry_point_32_bytes();
+        raw[0x15] = raw[0x15].wrapping_add(1);
+        raw[0x04] = raw[0x04].wrapping_sub(1);
+        let err = SMBiosEntryPoint::try_from(raw).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_entry_point_64() {
+        let entry_point = SMBiosEntryPoint::try_from(entry_point_64_bytes()).unwrap();
+        assert!(matches!(entry_point, SMBiosEntryPoint::Bit64(_)));
+        assert_eq!(entry_point.major_version(), 3);
+        assert_eq!(entry_point.minor_version(), 4);
+        assert_eq!(entry_point.structure_table_length(), 0x2000);
+        assert_eq!(entry_point.structure_table_address(), 0x0000_0001_5432_1000);
+
+        let mut raw = entry_point_64_bytes();
+        raw[0x05] = raw[0x05].wrapping_add(1);
+        let err = SMBiosEntryPoint::try_from(raw).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
 
-    sum == Wrapping(0)
+    #[test]
+    fn test_no_anchor() {
+        let mut raw = entry_point_32_bytes();
+        raw[0x01] = b'X';
+        let err = SMBiosEntryPoint::try_from(raw).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        let err = SMBiosEntryPoint::try_from(vec![b'_', b'S', b'M', b'3', b'_']).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let raw = entry_point_64_bytes();
+        assert_eq!(compute_checksum(&raw, 0x05), raw[0x05]);
+        assert_eq!(verify_checksum(&raw, 0x05), Ok(()));
+        assert_eq!(
+            verify_checksum(&raw, 0x40),
+            Err(SMBiosChecksumError::Missing)
+        );
+
+        // A single flipped byte
+        let mut tampered = raw.clone();
+        tampered[0x0C] ^= 0x01;
+        assert_eq!(
+            verify_checksum(&tampered, 0x05),
+            Err(SMBiosChecksumError::Mismatch {
+                expected: raw[0x05].wrapping_sub(0x01),
+                actual: raw[0x05]
+            })
+        );
+
+        let err = SMBiosEntryPoint64::try_from(tampered).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let checksum_err = err
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<SMBiosChecksumError>())
+            .unwrap();
+        assert!(matches!(
+            checksum_err,
+            SMBiosChecksumError::Mismatch { actual, .. } if *actual == raw[0x05]
+        ));
+
+        // The intermediate checksum of the 32 bit entry point
+        let mut tampered = entry_point_32_bytes();
+        tampered[0x16] ^= 0x80;
+        fix_checksum(&mut tampered, 0x04);
+        assert!(verify_checksum(&tampered[..0x1F], 0x04).is_ok());
+        assert!(verify_checksum(&tampered[0x10..0x1F], 0x05).is_err());
+        assert!(SMBiosEntryPoint32::try_from(tampered).is_err());
+    }
 }
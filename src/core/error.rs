@@ -0,0 +1,166 @@
+#[cfg(feature = "std")]
+use super::entry_point::SMBiosChecksumError;
+use core::fmt;
+use serde::Serialize;
+
+/// # SMBiosError
+///
+/// A crate-level SMBIOS error
+///
+/// The lenient accessors return `Option` and cannot tell an absent field from
+/// a truncated or invalid one. The `try_get_field_*()` functions of
+/// [UndefinedStruct](crate::UndefinedStruct) report a truncated structure as
+/// [SMBiosError::Truncated]. With the `strict` feature enabled, it also offers
+/// `strict_*` mirrors of its accessors which report every reason as an
+/// [SMBiosError].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SMBiosError {
+    /// The field lies beyond the length declared by the structure's header
+    ///
+    /// This is the normal case for a field added in a newer SMBIOS version.
+    OutOfBounds {
+        /// Offset of the requested field
+        offset: usize,
+        /// Length declared by the structure's header
+        length: u8,
+    },
+    /// The field references a string number outside the structure's string-set
+    InvalidStringNumber {
+        /// Offset of the requested field
+        offset: usize,
+        /// String number found in the field
+        string_number: u8,
+    },
+    /// The string referenced by the field is not valid UTF-8
+    InvalidUtf8 {
+        /// Offset of the requested field
+        offset: usize,
+    },
+    /// The structure is not of the requested type
+    UnexpectedType {
+        /// The requested structure type
+        expected: u8,
+        /// The structure's actual type
+        actual: u8,
+    },
+    /// Checksum verification failed
+    Checksum {
+        /// The checksum byte the data should contain
+        expected: u8,
+        /// The checksum byte the data contains
+        actual: u8,
+    },
+    /// The data does not contain the checksum byte
+    ChecksumMissing,
+    /// The field is within the length declared by the structure's header but
+    /// the structure's data is truncated and does not contain it
+    Truncated {
+        /// Offset of the requested field
+        offset: usize,
+        /// Actual length of the structure's formatted section
+        len: usize,
+    },
+}
+
+impl fmt::Display for SMBiosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SMBiosError::OutOfBounds { offset, length } => write!(
+                f,
+                "The field at offset {} is beyond the declared structure length {}",
+                offset, length
+            ),
+            SMBiosError::InvalidStringNumber {
+                offset,
+                string_number,
+            } => write!(
+                f,
+                "The field at offset {} references string {} which is not in the string-set",
+                offset, string_number
+            ),
+            SMBiosError::InvalidUtf8 { offset } => write!(
+                f,
+                "The string referenced by the field at offset {} is not valid UTF-8",
+                offset
+            ),
+            SMBiosError::UnexpectedType { expected, actual } => write!(
+                f,
+                "Expected a type {} structure, found type {}",
+                expected, actual
+            ),
+            SMBiosError::Checksum { expected, actual } => write!(
+                f,
+                "Checksum verification failed: expected 0x{:02X}, found 0x{:02X}",
+                expected, actual
+            ),
+            SMBiosError::ChecksumMissing => {
+                write!(f, "Checksum verification failed: checksum byte not present")
+            }
+            SMBiosError::Truncated { offset, len } => write!(
+                f,
+                "The field at offset {} is out of bounds of the truncated structure (length {})",
+                offset, len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SMBiosError {}
+
+#[cfg(feature = "std")]
+impl From<SMBiosChecksumError> for SMBiosError {
+    fn from(err: SMBiosChecksumError) -> Self {
+        match err {
+            SMBiosChecksumError::Mismatch { expected, actual } => {
+                SMBiosError::Checksum { expected, actual }
+            }
+            SMBiosChecksumError::Missing => SMBiosError::ChecksumMissing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_truncated() {
+        // Header declares a length of 0x0C but the formatted section is missing
+        let parts = crate::UndefinedStruct::new(&[0x80, 0x0C, 0x01, 0x00, 0x00, 0x00]);
+        let err = parts.try_get_field_word(0x06).unwrap_err();
+        assert_eq!(err, SMBiosError::Truncated { offset: 6, len: 0 });
+        assert_eq!(
+            err.to_string(),
+            "The field at offset 6 is out of bounds of the truncated structure (length 0)"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_checksum_error() {
+        let mut raw = vec![b'_', b'D', b'M', b'I', b'_', 0x00];
+        let err = SMBiosError::from(crate::verify_checksum(&raw, 5).unwrap_err());
+        assert_eq!(
+            err,
+            SMBiosError::Checksum {
+                expected: 0x68,
+                actual: 0x00
+            }
+        );
+
+        raw[5] = 0x68;
+        assert_eq!(
+            crate::verify_checksum(&raw, 5).map_err(SMBiosError::from),
+            Ok(())
+        );
+
+        let err = SMBiosError::from(crate::verify_checksum(&raw, 6).unwrap_err());
+        assert_eq!(err, SMBiosError::ChecksumMissing);
+        assert_eq!(
+            err.to_string(),
+            "Checksum verification failed: checksum byte not present"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use core::fmt;
+use serde::Serialize;
+
+/// # Field Presence
+///
+/// Classifies a field value as a real value or a placeholder.
+///
+/// Firmware often fills unpopulated fields (UUID, serial number, asset tag)
+/// with all 0x00 or all 0xFF rather than leaving them out. See
+/// [crate::UndefinedStruct::get_field_presence] for multi-byte fields and
+/// [crate::SMBiosString::presence] for strings.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FieldPresence {
+    /// The field holds a real value
+    Present,
+    /// The field is all 0x00, or the string "0", "0000…" or "0x0000…"
+    ///
+    /// A string field referencing no string (string number 0) is also all zero.
+    AllZero,
+    /// The field is all 0xFF (not set), or the string "FFFF…" or "0xFFFF…"
+    AllOnes,
+}
+
+impl fmt::Display for FieldPresence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FieldPresence::Present => "Present",
+            FieldPresence::AllZero => "All zero",
+            FieldPresence::AllOnes => "All ones",
+        })
+    }
+}
+
+impl FieldPresence {
+    /// Classifies a string value
+    ///
+    /// Surrounding whitespace and a "0x" prefix are ignored. A string made up
+    /// only of '0' is [FieldPresence::AllZero] and a string made up only of
+    /// 'F' is [FieldPresence::AllOnes].
+    pub fn of_str(value: &str) -> Self {
+        let value = value.trim();
+        let digits = value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+            .unwrap_or(value);
+
+        if value.is_empty() || (!digits.is_empty() && digits.chars().all(|c| c == '0')) {
+            FieldPresence::AllZero
+        } else if !digits.is_empty() && digits.chars().all(|c| c == 'F' || c == 'f') {
+            FieldPresence::AllOnes
+        } else {
+            FieldPresence::Present
+        }
+    }
+
+    /// Returns `true` if the field holds a real value
+    pub fn is_present(&self) -> bool {
+        *self == FieldPresence::Present
+    }
+}
+
+impl From<&[u8]> for FieldPresence {
+    /// Classifies raw bytes; an empty slice is [FieldPresence::AllZero]
+    fn from(bytes: &[u8]) -> Self {
+        if bytes.iter().all(|&byte| byte == 0x00) {
+            FieldPresence::AllZero
+        } else if bytes.iter().all(|&byte| byte == 0xFF) {
+            FieldPresence::AllOnes
+        } else {
+            FieldPresence::Present
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{SMBiosString, SMBiosStringSet, UndefinedStruct};
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(FieldPresence::from(&[0u8; 16][..]), FieldPresence::AllZero);
+        assert_eq!(
+            FieldPresence::from(&[0xFFu8; 16][..]),
+            FieldPresence::AllOnes
+        );
+        assert_eq!(
+            FieldPresence::from(&[0x00u8, 0xFF][..]),
+            FieldPresence::Present
+        );
+        assert_eq!(FieldPresence::from(&[][..]), FieldPresence::AllZero);
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(FieldPresence::of_str(""), FieldPresence::AllZero);
+        assert_eq!(FieldPresence::of_str("0"), FieldPresence::AllZero);
+        assert_eq!(FieldPresence::of_str("00000000"), FieldPresence::AllZero);
+        assert_eq!(FieldPresence::of_str("0x0000"), FieldPresence::AllZero);
+        assert_eq!(FieldPresence::of_str("FFFFFFFF "), FieldPresence::AllOnes);
+        assert_eq!(FieldPresence::of_str("0xffff"), FieldPresence::AllOnes);
+        assert_eq!(FieldPresence::of_str("0x"), FieldPresence::Present);
+        assert_eq!(
+            FieldPresence::of_str("023078193757"),
+            FieldPresence::Present
+        );
+        assert_eq!(FieldPresence::of_str("F0F0"), FieldPresence::Present);
+
+        let string = |value: &str| SMBiosString::from(Ok(value.to_string()));
+        assert_eq!(string("00000000").presence(), Some(FieldPresence::AllZero));
+        assert_eq!(string("PF1ABCDE").presence(), Some(FieldPresence::Present));
+
+        // A string-set holding an all-0xFF string is not valid UTF-8
+        let strings = SMBiosStringSet::new(vec![0xFF, 0xFF, 0xFF, 0xFF]);
+        assert!(strings.get_string(1).is_err());
+        assert_eq!(
+            strings.get_string(1).presence(),
+            Some(FieldPresence::AllOnes)
+        );
+        assert_eq!(
+            strings.get_string(0).presence(),
+            Some(FieldPresence::AllZero)
+        );
+        assert_eq!(strings.get_string(2).presence(), None);
+    }
+
+    #[test]
+    fn test_get_field_presence() {
+        let struct_type1 = vec![
+            0x01, 0x1B, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x06, 0x00, 0x00, 0x00,
+            0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type1);
+
+        assert_eq!(
+            parts.get_field_presence(0x08, 0x18),
+            Some(FieldPresence::AllOnes)
+        );
+        assert_eq!(
+            parts.get_field_presence(0x04, 0x08),
+            Some(FieldPresence::AllZero)
+        );
+        assert_eq!(
+            parts.get_field_presence(0x17, 0x19),
+            Some(FieldPresence::Present)
+        );
+        assert_eq!(parts.get_field_presence(0x18, 0x20), None);
+    }
+}
@@ -0,0 +1,120 @@
+use super::header::Handle;
+use super::undefined_struct::UndefinedStruct;
+
+#[cfg(feature = "std")]
+type IndexMap = std::collections::HashMap<Handle, usize>;
+#[cfg(not(feature = "std"))]
+type IndexMap = alloc::collections::BTreeMap<Handle, usize>;
+
+/// # Handle Map
+///
+/// An index from structure [Handle] to the position of the structure in its
+/// collection, built once for resolving many cross-structure references.
+///
+/// [crate::SMBiosData::find_by_handle] scans the table on every call. A
+/// [HandleMap] is built with [crate::SMBiosData::handle_map] and looked up in
+/// constant time (logarithmic without the `std` feature). Retrieve the
+/// structure at the returned index with [crate::SMBiosData::get].
+///
+/// When several structures share a handle, as corrupt tables sometimes do, the
+/// first one is indexed, matching [crate::SMBiosData::find_by_handle].
+#[derive(Debug, Clone, Default)]
+pub struct HandleMap {
+    indices: IndexMap,
+}
+
+impl HandleMap {
+    /// Builds the index over the given structures, in collection order
+    pub fn new<'a, I>(structures: I) -> Self
+    where
+        I: IntoIterator<Item = &'a UndefinedStruct>,
+    {
+        let mut indices = IndexMap::new();
+        for (index, undefined_struct) in structures.into_iter().enumerate() {
+            indices
+                .entry(undefined_struct.header.handle())
+                .or_insert(index);
+        }
+        HandleMap { indices }
+    }
+
+    /// The index of the structure with the given handle
+    pub fn get(&self, handle: Handle) -> Option<usize> {
+        self.indices.get(&handle).copied()
+    }
+
+    /// Returns `true` if a structure with the given handle is indexed
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.indices.contains_key(&handle)
+    }
+
+    /// The number of distinct handles indexed
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if no handles are indexed
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{
+        SMBiosBaseboardInformation, SMBiosData, SMBiosMemoryDevice, SMBiosStruct,
+        SMBiosSystemChassisInformation, StructureBuilder,
+    };
+
+    #[test]
+    fn test_handle_map() {
+        let mut table_data = Vec::new();
+        table_data.extend(
+            StructureBuilder::new(SMBiosSystemChassisInformation::STRUCT_TYPE, 0x0300)
+                .string("Chassis Manufacturer")
+                .build(),
+        );
+        table_data.extend(
+            StructureBuilder::new(SMBiosBaseboardInformation::STRUCT_TYPE, 0x0200)
+                .string("Board Manufacturer")
+                .build(),
+        );
+        for handle in 0x1100..0x1104 {
+            table_data.extend(
+                StructureBuilder::new(SMBiosMemoryDevice::STRUCT_TYPE, handle)
+                    .handle(Handle(0x1000))
+                    .build(),
+            );
+        }
+        // A duplicate handle resolves to its first structure
+        table_data.extend(StructureBuilder::new(0x80, 0x0200).build());
+        table_data.extend(StructureBuilder::new(127, 0xFEFF).build());
+
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let handle_map = data.handle_map();
+
+        assert_eq!(handle_map.len(), 7);
+        assert_eq!(handle_map.get(Handle(0x0300)), Some(0));
+        assert_eq!(handle_map.get(Handle(0x0200)), Some(1));
+        assert_eq!(handle_map.get(Handle(0x1102)), Some(4));
+        assert_eq!(handle_map.get(Handle(0xFEFF)), Some(7));
+        assert!(handle_map.contains(Handle(0x1103)));
+        assert_eq!(handle_map.get(Handle(0x1000)), None);
+
+        for memory_device in data.collect::<SMBiosMemoryDevice<'_>>() {
+            let index = handle_map.get(memory_device.handle()).unwrap();
+            assert_eq!(
+                data.get(index).unwrap().header.handle(),
+                memory_device.handle()
+            );
+        }
+        let baseboard = data.get(handle_map.get(Handle(0x0200)).unwrap()).unwrap();
+        assert_eq!(
+            baseboard.header.struct_type(),
+            SMBiosBaseboardInformation::STRUCT_TYPE
+        );
+        assert!(HandleMap::default().is_empty());
+    }
+}
@@ -1,5 +1,5 @@
+use core::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 
 /// # Structure Handle
 ///
@@ -7,7 +7,7 @@ use std::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 /// Some structures will reference other structures by using this value.
 ///
 /// Dereference a handle (*handle) to access its u16 value.
-#[derive(Serialize, PartialEq, Eq)]
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Handle(pub u16);
 
 impl Handle {
@@ -21,9 +21,10 @@ impl fmt::Debug for Handle {
     }
 }
 
+/// Formats as zero-padded hexadecimal with a `0x` prefix (e.g. `0x0003`), as dmidecode does
 impl fmt::Display for Handle {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", &self.0)
+        write!(f, "0x{:04X}", &self.0)
     }
 }
 
@@ -35,15 +36,30 @@ impl Deref for Handle {
     }
 }
 
+impl From<u16> for Handle {
+    fn from(value: u16) -> Self {
+        Handle(value)
+    }
+}
+
+impl From<Handle> for u16 {
+    fn from(handle: Handle) -> Self {
+        handle.0
+    }
+}
+
+/// Parses a `0x` (or `0X`) prefixed hexadecimal handle as produced by [fmt::Display],
+/// or a decimal handle when there is no prefix
 impl FromStr for Handle {
-    type Err = std::num::ParseIntError;
+    type Err = core::num::ParseIntError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Handle(if s.starts_with("0x") && s.len() > 2 {
-            u16::from_str_radix(&s[2..], 16)?
-        } else {
-            u16::from_str(s)?
-        }))
+        Ok(Handle(
+            match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                Some(hex) => u16::from_str_radix(hex, 16)?,
+                None => u16::from_str(s)?,
+            },
+        ))
     }
 }
 
@@ -52,7 +68,7 @@ impl FromStr for Handle {
 /// Each SMBIOS structure has a type number associated with it.
 ///
 /// Dereference a structure type (*struct_type) to access its u8 value.
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Copy)]
 pub struct SMBiosType(pub u8);
 
 impl Deref for SMBiosType {
@@ -65,7 +81,7 @@ impl Deref for SMBiosType {
 
 impl fmt::Debug for SMBiosType {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosType>())
+        fmt.debug_struct(core::any::type_name::<SMBiosType>())
             .field("type", &self.0)
             .finish()
     }
@@ -78,7 +94,7 @@ pub struct Header([u8; 4]);
 
 impl fmt::Debug for Header {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<Header>())
+        fmt.debug_struct(core::any::type_name::<Header>())
             .field("struct_type", &self.struct_type())
             .field("length", &self.length())
             .field("handle", &self.handle())
@@ -143,7 +159,7 @@ impl Header {
     }
 
     /// Byte iterator of the header
-    pub fn iter(&self) -> std::slice::Iter<'_, u8> {
+    pub fn iter(&self) -> core::slice::Iter<'_, u8> {
         self.0.iter()
     }
 }
@@ -161,3 +177,54 @@ impl Deref for Header {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_handle_display_round_trip() {
+        assert_eq!(Handle(3).to_string(), "0x0003");
+        assert_eq!(Handle(0xABCD).to_string(), "0xABCD");
+        assert_eq!(Handle(0xFFFF).to_string(), "0xFFFF");
+
+        for value in [0u16, 3, 0x00FF, 0x1234, 0xFFFE, 0xFFFF].iter() {
+            let handle = Handle(*value);
+            assert_eq!(handle.to_string().parse::<Handle>(), Ok(handle));
+        }
+
+        assert_eq!("0x0003".parse::<Handle>(), Ok(Handle(3)));
+        assert_eq!("0Xabcd".parse::<Handle>(), Ok(Handle(0xABCD)));
+        assert_eq!("18".parse::<Handle>(), Ok(Handle(18)));
+        assert!("0x".parse::<Handle>().is_err());
+        assert!("0x10000".parse::<Handle>().is_err());
+        assert!("handle".parse::<Handle>().is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_handle_as_key() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(Handle(0x0002), "Baseboard");
+        map.insert(Handle::from(0x0003), "Chassis");
+        map.insert(Handle(0x0002), "Baseboard Information");
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&Handle(0x0002)), Some(&"Baseboard Information"));
+        assert_eq!(map.get(&Handle(0x0003)), Some(&"Chassis"));
+        assert_eq!(map.get(&Handle(0x0004)), None);
+
+        let handle = Handle(0x1100);
+        let copy = handle;
+        assert_eq!(handle, copy);
+        assert_eq!(u16::from(handle), 0x1100);
+        assert!(Handle(0x0100) < Handle(0x1000));
+        assert_eq!(
+            [Handle(3), Handle(1), Handle(2)].iter().max(),
+            Some(&Handle(3))
+        );
+    }
+}
@@ -1,12 +1,22 @@
+#[cfg(feature = "std")]
 mod entry_point;
+mod error;
+mod field_presence;
+mod handle_map;
 mod header;
 mod smbios_data;
 /// SMBIOS String module
 pub mod strings;
+mod structure_builder;
 mod undefined_struct;
 
+#[cfg(feature = "std")]
 pub use entry_point::*;
+pub use error::*;
+pub use field_presence::*;
+pub use handle_map::*;
 pub use header::*;
 pub use smbios_data::*;
 pub use strings::*;
+pub use structure_builder::*;
 pub use undefined_struct::*;
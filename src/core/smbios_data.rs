@@ -1,18 +1,20 @@
-use super::header::Handle;
-use super::undefined_struct::{UndefinedStruct, UndefinedStructTable};
-use crate::structs::{DefinedStructTable, SMBiosStruct};
+use super::handle_map::HandleMap;
+use super::header::{Handle, Header};
+use super::undefined_struct::{RawStructIterator, UndefinedStruct, UndefinedStructTable};
+use crate::prelude::*;
+use crate::structs::{DefinedStructTable, SMBiosOemDefined, SMBiosStruct};
+use core::fmt;
+use core::{cmp::Ordering, convert::TryInto, slice::Iter};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::io::Error;
-use std::{cmp::Ordering, slice::Iter};
-use std::{fmt, fs::read};
+#[cfg(feature = "std")]
+use std::{fs::read, io::Error, path::Path};
 
 /// # SMBIOS Data
 ///
 /// Contains an optional SMBIOS version and a collection of SMBIOS structures.
 pub struct SMBiosData {
     table: UndefinedStructTable,
-    /// Version of the contained SMBIOS structures.
-    pub version: Option<SMBiosVersion>,
+    version: Option<SMBiosVersion>,
 }
 
 impl<'a> SMBiosData {
@@ -20,7 +22,8 @@ impl<'a> SMBiosData {
     ///
     /// `table` is iterable table data.
     /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
-    pub fn new(table: UndefinedStructTable, version: Option<SMBiosVersion>) -> Self {
+    pub fn new(mut table: UndefinedStructTable, version: Option<SMBiosVersion>) -> Self {
+        table.set_version(version);
         Self { table, version }
     }
 
@@ -29,23 +32,107 @@ impl<'a> SMBiosData {
     /// `data` is a block of bytes representing the raw table data.
     /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
     pub fn from_vec_and_version(data: Vec<u8>, version: Option<SMBiosVersion>) -> Self {
-        Self {
-            table: UndefinedStructTable::from(data),
-            version,
-        }
+        Self::new(UndefinedStructTable::from(data), version)
+    }
+
+    /// Creates an SMBIOS table reader over a block of bytes already in memory
+    ///
+    /// `data` holds the structures only, such as the region at the structure
+    /// table address given by the entry point. The structures are read in
+    /// place; see [SMBiosDataRef]. The version is unknown; set it from the
+    /// entry point with [SMBiosDataRef::set_version] when it is available.
+    pub fn from_slice(data: &[u8]) -> SMBiosDataRef<'_> {
+        SMBiosDataRef::new(data, None)
+    }
+
+    /// Creates an SMBIOS table reader over a raw memory region, such as the
+    /// mapped structure table on bare-metal or UEFI targets
+    ///
+    /// See [SMBiosData::from_slice].
+    ///
+    /// # Safety
+    ///
+    /// The returned [SMBiosDataRef] reads the region in place, so the caller
+    /// must ensure that, for the whole lifetime `'b` of the returned value:
+    /// - `ptr` is non-null and valid for reads of `len` bytes, which lie within
+    ///   a single mapped region
+    /// - the `len` bytes are initialized and not mutated
+    /// - `len` is no greater than `isize::MAX`
+    pub unsafe fn from_raw_parts<'b>(ptr: *const u8, len: usize) -> SMBiosDataRef<'b> {
+        Self::from_slice(core::slice::from_raw_parts(ptr, len))
+    }
+
+    /// Loads the SMBIOS table of the running system
+    ///
+    /// Dispatches at compile time to the loader for the target platform: sysfs
+    /// or `/dev/mem` on Linux, Android and FreeBSD, `GetSystemFirmwareTable` on
+    /// Windows, and the IORegistry on macOS. Other targets return an
+    /// [std::io::ErrorKind::Unsupported] error.
+    ///
+    /// ```
+    /// use smbioslib::*;
+    ///
+    /// match SMBiosData::load() {
+    ///     Ok(data) => println!("{} structures", data.iter().count()),
+    ///     Err(err) => println!("failure: {}", err),
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn load() -> Result<SMBiosData, Error> {
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_family = "windows",
+            target_os = "macos",
+            target_os = "ios"
+        ))]
+        return crate::table_load_from_device();
+
+        #[cfg(not(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            target_family = "windows",
+            target_os = "macos",
+            target_os = "ios"
+        )))]
+        return Err(Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Loading SMBIOS data is not supported on this platform",
+        ));
+    }
+
+    /// Loads a raw DMI table dump from a file for offline analysis
+    ///
+    /// The file holds the structures only, in the format of
+    /// `/sys/firmware/dmi/tables/DMI`. The version is unknown as the entry
+    /// point is not part of the dump.
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SMBiosData, Error> {
+        Ok(SMBiosData::from_vec_and_version(read(path)?, None))
     }
 
     /// Loads raw SMBios table data from a file
+    #[cfg(feature = "std")]
     pub fn try_load_from_file(
         filename: &str,
         version: Option<SMBiosVersion>,
     ) -> Result<SMBiosData, Error> {
         let data = read(filename)?;
-        let result = Self {
-            table: UndefinedStructTable::from(data),
-            version,
-        };
-        Ok(result)
+        Ok(Self::new(UndefinedStructTable::from(data), version))
+    }
+
+    /// Version of the contained SMBIOS structures, when known
+    pub fn version(&self) -> Option<SMBiosVersion> {
+        self.version
+    }
+
+    /// Sets [SMBiosData::version] and the [UndefinedStruct::version] of every
+    /// contained structure
+    pub fn set_version(&mut self, version: Option<SMBiosVersion>) {
+        self.table.set_version(version);
+        self.version = version;
     }
 
     /// Iterator of the contained [UndefinedStruct] items
@@ -132,12 +219,97 @@ impl<'a> SMBiosData {
         self.table.filter_map(f)
     }
 
+    /// Re-emits the raw table bytes, see [UndefinedStructTable::to_bytes]
+    ///
+    /// The result can be reloaded with [SMBiosData::from_vec_and_version].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.table.to_bytes()
+    }
+
+    /// Serializes the SMBIOS version and every structure into pretty-printed JSON
+    ///
+    /// The result is an object with a `version` field and a `structures` array
+    /// holding one entry per structure, in table order. Structures with a parser
+    /// are serialized with their typed fields; OEM and unknown structures are
+    /// serialized as their [crate::Header] and the structure's raw bytes encoded
+    /// as an uppercase hexadecimal string.
+    #[cfg(feature = "std")]
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        let structures = self
+            .table
+            .iter()
+            .map(|undefined_struct| match undefined_struct.defined_struct() {
+                crate::structs::DefinedStruct::Undefined(_) => {
+                    let raw: String = undefined_struct
+                        .to_bytes()
+                        .iter()
+                        .map(|byte| format!("{:02X}", byte))
+                        .collect();
+                    Ok(serde_json::json!({
+                        "Undefined": {
+                            "header": undefined_struct.header,
+                            "raw": raw,
+                        }
+                    }))
+                }
+                defined_struct => serde_json::to_value(&defined_struct),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        serde_json::to_string_pretty(&serde_json::json!({
+            "version": self.version,
+            "structures": structures,
+        }))
+    }
+
+    /// An iterator over the OEM-defined structures (types 128 to 255)
+    pub fn oem_defined_iter(&'a self) -> impl Iterator<Item = SMBiosOemDefined<'a>> + 'a {
+        self.table.oem_defined_iter()
+    }
+
+    /// An iterator over the structures of the given structure type
+    pub fn all_of_type(
+        &'a self,
+        struct_type: u8,
+    ) -> impl Iterator<Item = &'a UndefinedStruct> + 'a {
+        self.table.all_of_type(struct_type)
+    }
+
     /// Finds the structure matching the given handle
-    pub fn find_by_handle(&'a self, handle: &Handle) -> Option<&UndefinedStruct> {
+    pub fn find_by_handle(&'a self, handle: &Handle) -> Option<&'a UndefinedStruct> {
         self.table.find_by_handle(handle)
     }
 
+    /// Finds the structure matching the given handle and downcasts it to `T`
+    ///
+    /// None is returned if no structure has the handle or it is not of type `T`.
+    pub fn resolve<T>(&'a self, handle: Handle) -> Option<T>
+    where
+        T: SMBiosStruct<'a>,
+    {
+        self.table.resolve(handle)
+    }
+
+    /// The structure at the given position in the table, see [HandleMap::get]
+    pub fn get(&self, index: usize) -> Option<&UndefinedStruct> {
+        self.table.get(index)
+    }
+
+    /// Builds a [HandleMap] for resolving many handles without scanning the table
+    pub fn handle_map(&self) -> HandleMap {
+        self.table.handle_map()
+    }
+
+    /// An iterator over the header of every structure as
+    /// `(handle, struct_type, byte_offset)`, see [UndefinedStructTable::headers]
+    pub fn headers(&self) -> impl Iterator<Item = (Handle, u8, usize)> + '_ {
+        self.table.headers()
+    }
+
     /// Finds all occurances of the structure
+    ///
+    /// The structure type is taken from [SMBiosStruct::STRUCT_TYPE], e.g.
+    /// `collect::<SMBiosMemoryDevice<'_>>()` returns every Type 17 structure.
     pub fn collect<T>(&'a self) -> Vec<T>
     where
         T: SMBiosStruct<'a>,
@@ -148,7 +320,7 @@ impl<'a> SMBiosData {
 
 impl IntoIterator for SMBiosData {
     type Item = UndefinedStruct;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.table.into_iter()
@@ -160,7 +332,7 @@ impl fmt::Debug for SMBiosData {
         // Convert to defined structures to see the structure fields
         let defined_table: DefinedStructTable<'_> = self.table.iter().collect();
 
-        fmt.debug_struct(std::any::type_name::<SMBiosData>())
+        fmt.debug_struct(core::any::type_name::<SMBiosData>())
             .field("version", &self.version)
             .field("table", &defined_table)
             .finish()
@@ -182,6 +354,59 @@ impl Serialize for SMBiosData {
     }
 }
 
+/// # SMBIOS Data Read in Place
+///
+/// Borrows a block of table bytes, such as the mapped structure table on
+/// bare-metal or UEFI targets, and walks its structures where they lie.
+/// Nothing is copied until a structure is decoded with [UndefinedStruct::new]
+/// or the whole table is converted into an [SMBiosData] with `From`.
+pub struct SMBiosDataRef<'a> {
+    data: &'a [u8],
+    version: Option<SMBiosVersion>,
+}
+
+impl<'a> SMBiosDataRef<'a> {
+    /// Creates an SMBIOS table reader over `data`
+    ///
+    /// `data` holds the structures only.
+    /// `version` is optional and represents the DMTF SMBIOS Standard version of the bytes in `data`.
+    pub fn new(data: &'a [u8], version: Option<SMBiosVersion>) -> Self {
+        Self { data, version }
+    }
+
+    /// Version of the contained SMBIOS structures, when known
+    pub fn version(&self) -> Option<SMBiosVersion> {
+        self.version
+    }
+
+    /// Sets [SMBiosDataRef::version]
+    pub fn set_version(&mut self, version: Option<SMBiosVersion>) {
+        self.version = version;
+    }
+
+    /// Iterator of the raw bytes of each structure, borrowed from the table
+    pub fn iter(&self) -> RawStructIterator<'a> {
+        RawStructIterator::new(self.data)
+    }
+
+    /// An iterator over the header of every structure as
+    /// `(handle, struct_type, byte_offset)`, see [SMBiosData::headers]
+    pub fn headers(&self) -> impl Iterator<Item = (Handle, u8, usize)> + 'a {
+        self.iter().scan(0usize, |offset, raw| {
+            let byte_offset = *offset;
+            *offset += raw.len();
+            let header = Header::new(raw[..Header::SIZE].try_into().expect("4 bytes"));
+            Some((header.handle(), header.struct_type(), byte_offset))
+        })
+    }
+}
+
+impl From<SMBiosDataRef<'_>> for SMBiosData {
+    fn from(data: SMBiosDataRef<'_>) -> Self {
+        SMBiosData::new(UndefinedStructTable::from(data.data), data.version)
+    }
+}
+
 /// # Version of SMBIOS Structure
 #[derive(Debug, Eq, PartialEq, Serialize, Clone, Copy)]
 pub struct SMBiosVersion {
@@ -229,3 +454,102 @@ impl PartialOrd for SMBiosVersion {
         Some(self.cmp(other))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StructureBuilder;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_json_pretty() {
+        let mut table_data = StructureBuilder::new(1, 0x0001).string("LENOVO").build();
+        table_data.extend(StructureBuilder::new(0x80, 0x0002).word(0xBEEF).build());
+        table_data.extend(StructureBuilder::new(127, 0x0003).build());
+
+        let data = SMBiosData::from_vec_and_version(table_data, Some(SMBiosVersion::new(3, 2, 0)));
+        let json: serde_json::Value =
+            serde_json::from_str(&data.to_json_pretty().unwrap()).unwrap();
+
+        let structures = json["structures"].as_array().unwrap();
+        assert_eq!(structures.len(), data.iter().count());
+        assert_eq!(json["version"]["major"], 3);
+        assert_eq!(structures[0]["SystemInformation"]["manufacturer"], "LENOVO");
+        assert_eq!(structures[1]["Undefined"]["raw"], "80060200EFBE0000");
+        assert!(structures[2].get("EndOfTable").is_some());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_file() {
+        let data = SMBiosData::from_file("tests/fixtures/qemu_i440fx_synthetic.bin").unwrap();
+        assert_eq!(data.iter().count(), 9);
+        assert_eq!(data.version(), None);
+
+        let err = SMBiosData::from_file("tests/fixtures/missing.bin").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_headers() {
+        let mut table_data = StructureBuilder::new(1, 0x0001).string("LENOVO").build();
+        table_data.extend(StructureBuilder::new(0x80, 0x0002).word(0xBEEF).build());
+        table_data.extend(StructureBuilder::new(127, 0xFEFF).build());
+        let data = SMBiosData::from_vec_and_version(table_data.clone(), None);
+
+        // Type 1: 4 byte header + 1 string number + "LENOVO\0\0" = 13 bytes
+        // Type 0x80: 4 byte header + 2 byte word + "\0\0" = 8 bytes
+        let headers: Vec<(Handle, u8, usize)> = data.headers().collect();
+        assert_eq!(
+            headers,
+            vec![
+                (Handle(0x0001), 1, 0),
+                (Handle(0x0002), 0x80, 13),
+                (Handle(0xFEFF), 127, 21),
+            ]
+        );
+
+        for (handle, struct_type, byte_offset) in headers {
+            assert_eq!(table_data[byte_offset], struct_type);
+            assert_eq!(
+                u16::from_le_bytes([table_data[byte_offset + 2], table_data[byte_offset + 3]]),
+                *handle
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let mut region = StructureBuilder::new(1, 0x0001).string("LENOVO").build();
+        region.extend(StructureBuilder::new(127, 0xFEFF).build());
+        // Padding after the End-of-Table is not a structure
+        region.extend_from_slice(&[0u8; 16]);
+
+        let data = SMBiosData::from_slice(&region);
+        assert_eq!(data.version(), None);
+
+        // The structures are read in place
+        let raw_structs: Vec<&[u8]> = data.iter().collect();
+        assert_eq!(raw_structs, vec![&region[..13], &region[13..19]]);
+        assert_eq!(raw_structs[0].as_ptr(), region.as_ptr());
+        assert_eq!(
+            UndefinedStruct::new(raw_structs[0])
+                .get_field_string(0x04)
+                .to_string(),
+            "LENOVO"
+        );
+        assert_eq!(
+            data.headers().collect::<Vec<_>>(),
+            vec![(Handle(0x0001), 1, 0), (Handle(0xFEFF), 127, 13)]
+        );
+
+        let raw_data = unsafe { SMBiosData::from_raw_parts(region.as_ptr(), region.len()) };
+        assert_eq!(raw_data.iter().collect::<Vec<_>>(), raw_structs);
+
+        let owned = SMBiosData::from(data);
+        assert_eq!(owned.iter().count(), 2);
+        assert_eq!(owned.to_bytes(), region[..region.len() - 16]);
+
+        assert_eq!(SMBiosData::from_slice(&[]).iter().count(), 0);
+    }
+}
@@ -1,6 +1,11 @@
+use super::field_presence::FieldPresence;
+use crate::prelude::*;
+use alloc::borrow::Cow;
+use alloc::string::FromUtf8Error;
+use core::fmt;
 use serde::{ser::SerializeSeq, Serialize, Serializer};
+#[cfg(feature = "std")]
 use std::error;
-use std::{fmt, string::FromUtf8Error};
 
 /// # SMBIOS String-Set
 ///
@@ -8,6 +13,7 @@ use std::{fmt, string::FromUtf8Error};
 pub struct SMBiosStringSet {
     strings: Vec<Vec<u8>>,
     current_string_index: usize,
+    terminated: bool,
 }
 
 impl SMBiosStringSet {
@@ -15,7 +21,7 @@ impl SMBiosStringSet {
     pub fn new(string_area: Vec<u8>) -> SMBiosStringSet {
         SMBiosStringSet {
             strings: {
-                if string_area == &[] {
+                if string_area.is_empty() {
                     vec![]
                 } else {
                     string_area
@@ -26,9 +32,23 @@ impl SMBiosStringSet {
                 }
             },
             current_string_index: 0,
+            terminated: true,
         }
     }
 
+    /// Creates a string-set from a structure whose double-zero terminator may be missing
+    pub(crate) fn new_with_terminator(string_area: Vec<u8>, terminated: bool) -> SMBiosStringSet {
+        SMBiosStringSet {
+            terminated,
+            ..SMBiosStringSet::new(string_area)
+        }
+    }
+
+    /// Returns `true` if the string-set ended with the double-zero terminator
+    pub fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+
     fn reset(&mut self) {
         self.current_string_index = 0;
     }
@@ -68,8 +88,37 @@ impl SMBiosStringSet {
         }
     }
 
+    /// Returns a borrowed UTF-8 [str] at the given 1 based `index` without allocating
+    ///
+    /// If the index is 0 an empty string "" is returned.
+    /// None is returned if the index is out of range or the string is not valid UTF-8.
+    pub fn get_string_ref(&self, index: u8) -> Option<&str> {
+        match index {
+            0 => Some(""),
+            _ => self
+                .strings
+                .get(index as usize - 1)
+                .and_then(|bytes| core::str::from_utf8(bytes).ok()),
+        }
+    }
+
+    /// Returns the string at the given 1 based `index`, replacing invalid UTF-8 sequences
+    /// with U+FFFD REPLACEMENT CHARACTER
+    ///
+    /// If the index is 0 an empty string "" is returned.
+    /// None is returned if the index is out of range.
+    pub fn get_string_lossy(&self, index: u8) -> Option<Cow<'_, str>> {
+        match index {
+            0 => Some(Cow::Borrowed("")),
+            _ => self
+                .strings
+                .get(index as usize - 1)
+                .map(|bytes| String::from_utf8_lossy(bytes)),
+        }
+    }
+
     /// Iterates the raw bytes of the strings. The terminating 0 is not included in each string.
-    pub fn iter(&self) -> std::slice::Iter<'_, Vec<u8>> {
+    pub fn iter(&self) -> core::slice::Iter<'_, Vec<u8>> {
         self.strings.iter()
     }
 }
@@ -100,6 +149,7 @@ impl IntoIterator for &SMBiosStringSet {
         SMBiosStringSet {
             strings: self.strings.clone(),
             current_string_index: 0,
+            terminated: self.terminated,
         }
     }
 }
@@ -130,7 +180,7 @@ impl Serialize for SMBiosStringSet {
 /// # SMBiosStringError
 ///
 /// An SMBIOS String retrival error
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone)]
 pub enum SMBiosStringError {
     /// The structure's field is out of bounds of the formatted portion of the SMBIOS structure
     FieldOutOfBounds,
@@ -172,6 +222,7 @@ impl fmt::Display for SMBiosStringError {
     }
 }
 
+#[cfg(feature = "std")]
 impl error::Error for SMBiosStringError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
@@ -199,6 +250,69 @@ impl From<Result<String, SMBiosStringError>> for SMBiosString {
     }
 }
 
+fn parse_date(date: &str) -> Option<(u16, u8, u8)> {
+    fn number(digits: &str, min_len: usize, max_len: usize) -> Option<u16> {
+        match digits.len() >= min_len
+            && digits.len() <= max_len
+            && digits.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            true => digits.parse().ok(),
+            false => None,
+        }
+    }
+
+    let mut parts = date.trim().split('/');
+    let month = number(parts.next()?, 1, 2)?;
+    let day = number(parts.next()?, 1, 2)?;
+    let year_digits = parts.next()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let year = match year_digits.len() {
+        2 => 1900 + number(year_digits, 2, 2)?,
+        _ => number(year_digits, 4, 4)?,
+    };
+
+    match (1..=12).contains(&month) && (1..=31).contains(&day) {
+        true => Some((year, month as u8, day as u8)),
+        false => None,
+    }
+}
+
+/// Placeholder strings commonly found in fields the vendor did not fill in
+///
+/// Used by [SMBiosString::normalized].
+pub const PLACEHOLDER_STRINGS: &[&str] = &[
+    "To Be Filled By O.E.M.",
+    "Default string",
+    "Not Specified",
+    "Not Applicable",
+    "Not Available",
+    "None",
+    "Unknown",
+    "N/A",
+    "O.E.M.",
+    "OEM",
+    "System Manufacturer",
+    "System Product Name",
+    "System Version",
+    "System Serial Number",
+    "System SKU Number",
+    "Chassis Manufacturer",
+    "Chassis Manufacture",
+    "Chassis Version",
+    "Chassis Serial Number",
+    "Base Board Manufacturer",
+    "Base Board Product Name",
+    "Base Board Version",
+    "Base Board Serial Number",
+    "Type2 - Board Serial Number",
+    "Type2 - Board Asset Tag",
+    "Asset-1234567890",
+    "No Asset Tag",
+    "No Asset Information",
+];
+
 /// # SMBiosString
 ///
 /// Contains the retrival result for an SMBIOS string field.
@@ -207,6 +321,52 @@ pub struct SMBiosString {
 }
 
 impl SMBiosString {
+    /// Parses a date in mm/dd/yyyy or mm/dd/yy format into `(year, month, day)`
+    ///
+    /// Used by date strings such as [crate::SMBiosInformation::release_date] and
+    /// [crate::SMBiosPortableBattery::manufacture_date]. A two digit year is
+    /// assumed to be 19yy, as defined for the BIOS release date. Returns None
+    /// if the string could not be retrieved or does not match the format.
+    pub fn to_date(&self) -> Option<(u16, u8, u8)> {
+        parse_date(self.as_ref().ok()?)
+    }
+
+    /// Returns the trimmed string, or None if it is empty or one of the known
+    /// [PLACEHOLDER_STRINGS] vendors write into fields that are effectively empty
+    ///
+    /// Placeholders are matched ignoring case and surrounding whitespace. None
+    /// is also returned if the string could not be retrieved. The raw value
+    /// remains available through [SMBiosString::as_ref].
+    pub fn normalized(&self) -> Option<&str> {
+        self.normalized_with(PLACEHOLDER_STRINGS)
+    }
+
+    /// Behaves like [SMBiosString::normalized] with the given placeholders
+    pub fn normalized_with(&self, placeholders: &[&str]) -> Option<&str> {
+        let value = self.value.as_ref().ok()?.trim();
+        match value.is_empty()
+            || placeholders
+                .iter()
+                .any(|placeholder| placeholder.eq_ignore_ascii_case(value))
+        {
+            true => None,
+            false => Some(value),
+        }
+    }
+
+    /// Classifies the string as a real value or an all-zero or all-FF placeholder
+    ///
+    /// A string which is not valid UTF-8 is classified by its bytes, so a
+    /// string of 0xFF bytes is [FieldPresence::AllOnes]. Returns None if the
+    /// string could not be retrieved.
+    pub fn presence(&self) -> Option<FieldPresence> {
+        match &self.value {
+            Ok(val) => Some(FieldPresence::of_str(val)),
+            Err(SMBiosStringError::Utf8(utf8)) => Some(FieldPresence::from(utf8.as_bytes())),
+            Err(_) => None,
+        }
+    }
+
     /// Produces a UTF-8 which includes invalid UTF-8 characters; otherwise, returns
     /// Option::None for all other conditions.
     pub fn to_utf8_lossy(&self) -> Option<String> {
@@ -326,4 +486,66 @@ mod tests {
         let fourth_string = string_iterator.next().unwrap().value.unwrap();
         assert_eq!(fourth_string, "ja|JP|unicode".to_string());
     }
+
+    #[test]
+    fn test_to_date() {
+        let date = |value: &str| SMBiosString::from(Ok(value.to_string())).to_date();
+
+        // Valid
+        assert_eq!(date("03/15/2021"), Some((2021, 3, 15)));
+        assert_eq!(date("1/2/2020"), Some((2020, 1, 2)));
+        assert_eq!(date("12/31/1999 "), Some((1999, 12, 31)));
+
+        // Two digit year
+        assert_eq!(date("07/04/98"), Some((1998, 7, 4)));
+        assert_eq!(date("07/04/05"), Some((1905, 7, 4)));
+
+        // Malformed
+        assert_eq!(date(""), None);
+        assert_eq!(date("2021-03-15"), None);
+        assert_eq!(date("13/01/2021"), None);
+        assert_eq!(date("00/10/2021"), None);
+        assert_eq!(date("01/32/2021"), None);
+        assert_eq!(date("01/15/202"), None);
+        assert_eq!(date("01/15/2021/1"), None);
+        assert_eq!(date("+1/15/2021"), None);
+        assert_eq!(date("Not Specified"), None);
+        assert_eq!(
+            SMBiosString::from(Err(SMBiosStringError::InvalidStringNumber(2))).to_date(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_normalized() {
+        let string = |value: &str| SMBiosString::from(Ok(value.to_string()));
+
+        assert_eq!(string("To Be Filled By O.E.M.").normalized(), None);
+        assert_eq!(string("To be filled by O.E.M.").normalized(), None);
+        assert_eq!(string("Default string").normalized(), None);
+        assert_eq!(string("System Serial Number").normalized(), None);
+        assert_eq!(string("Not Specified ").normalized(), None);
+        assert_eq!(string("").normalized(), None);
+        assert_eq!(string("   ").normalized(), None);
+        assert_eq!(
+            SMBiosString::from(Err(SMBiosStringError::InvalidStringNumber(2))).normalized(),
+            None
+        );
+
+        // Real values pass through trimmed; the raw value is untouched
+        let part_number = string("HMAA1GS6CMR6N-UH    ");
+        assert_eq!(part_number.normalized(), Some("HMAA1GS6CMR6N-UH"));
+        assert_eq!(part_number.to_string(), "HMAA1GS6CMR6N-UH    ");
+        assert_eq!(
+            string("Surface Laptop 3").normalized(),
+            Some("Surface Laptop 3")
+        );
+
+        // A custom set of placeholders
+        assert_eq!(
+            string("Default string").normalized_with(&["0123456789"]),
+            Some("Default string")
+        );
+        assert_eq!(string("0123456789").normalized_with(&["0123456789"]), None);
+    }
 }
@@ -0,0 +1,274 @@
+use super::header::{Handle, Header};
+use crate::prelude::*;
+use core::convert::TryFrom;
+
+/// # Structure Builder
+///
+/// Synthesizes the raw bytes of an SMBIOS structure, such as a test fixture.
+///
+/// Fields are appended to the formatted section in order, starting at offset 4
+/// (after the [Header]). The length byte and the string-set, including the
+/// double-zero terminator, are computed by [StructureBuilder::build].
+///
+/// ```
+/// use smbioslib::*;
+///
+/// let raw = StructureBuilder::new(SMBiosSystemInformation::STRUCT_TYPE, 0x0001)
+///     .string("LENOVO") // 0x04 Manufacturer
+///     .string("30BFS07500") // 0x05 Product Name
+///     .build();
+///
+/// let parts = UndefinedStruct::new(&raw);
+/// let system_information = SMBiosSystemInformation::new(&parts);
+/// assert_eq!(system_information.manufacturer().to_string(), "LENOVO");
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructureBuilder {
+    struct_type: u8,
+    handle: u16,
+    fields: Vec<u8>,
+    strings: Vec<String>,
+}
+
+impl StructureBuilder {
+    /// Creates a builder for a structure of the given type and handle
+    pub fn new(struct_type: u8, handle: u16) -> Self {
+        StructureBuilder {
+            struct_type,
+            handle,
+            fields: Vec::new(),
+            strings: Vec::new(),
+        }
+    }
+
+    /// Appends a BYTE field
+    pub fn byte(mut self, value: u8) -> Self {
+        self.fields.push(value);
+        self
+    }
+
+    /// Appends a WORD field
+    pub fn word(mut self, value: u16) -> Self {
+        self.fields.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends a DWORD field
+    pub fn dword(mut self, value: u32) -> Self {
+        self.fields.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends a QWORD field
+    pub fn qword(mut self, value: u64) -> Self {
+        self.fields.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    /// Appends a [Handle] field referencing another structure
+    pub fn handle(self, handle: Handle) -> Self {
+        self.word(*handle)
+    }
+
+    /// Appends a block of bytes
+    pub fn bytes(mut self, values: &[u8]) -> Self {
+        self.fields.extend_from_slice(values);
+        self
+    }
+
+    /// Appends a string number field without registering a string
+    ///
+    /// Use 0 for a field which references no string, or a number beyond the
+    /// registered strings to build a corrupt reference.
+    pub fn string_ref(self, string_number: u8) -> Self {
+        self.byte(string_number)
+    }
+
+    /// Registers a string in the string-set and appends a field referencing it
+    ///
+    /// A string already registered is reused rather than added again. An empty
+    /// string appends string number 0, which references no string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` would be string number 256 or later, as string
+    /// numbers are a single byte, or if `value` contains a 0 byte.
+    pub fn string(mut self, value: &str) -> Self {
+        if value.is_empty() {
+            return self.string_ref(0);
+        }
+
+        let string_number = match self.strings.iter().position(|string| string == value) {
+            Some(index) => index + 1,
+            None => {
+                self = self.add_string(value);
+                self.strings.len()
+            }
+        };
+        let string_number =
+            u8::try_from(string_number).expect("A structure is limited to 255 strings");
+        self.string_ref(string_number)
+    }
+
+    /// Registers a string in the string-set without appending a field
+    ///
+    /// Useful for structures whose strings are not referenced by fields, such
+    /// as OEM Strings (Type 11).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is empty or contains a 0 byte, as neither can be
+    /// represented in a string-set.
+    pub fn add_string(mut self, value: &str) -> Self {
+        assert!(
+            !value.is_empty() && !value.contains('\0'),
+            "SMBIOS strings must be non-empty and may not contain 0"
+        );
+        self.strings.push(value.to_string());
+        self
+    }
+
+    /// Emits the raw bytes of the structure
+    ///
+    /// # Panics
+    ///
+    /// Panics if the formatted section exceeds 255 bytes.
+    pub fn build(&self) -> Vec<u8> {
+        let length = Header::SIZE + self.fields.len();
+        assert!(
+            length <= u8::MAX as usize,
+            "The formatted section is limited to 255 bytes"
+        );
+
+        let mut raw = Vec::with_capacity(length + 2);
+        raw.push(self.struct_type);
+        raw.push(length as u8);
+        raw.extend_from_slice(&self.handle.to_le_bytes());
+        raw.extend_from_slice(&self.fields);
+        match self.strings.is_empty() {
+            true => raw.push(0),
+            false => {
+                for string in &self.strings {
+                    raw.extend_from_slice(string.as_bytes());
+                    raw.push(0);
+                }
+            }
+        }
+        raw.push(0);
+        raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        SMBiosMemoryDevice, SMBiosStruct, SMBiosSystemInformation, UndefinedStruct,
+        UndefinedStructTable,
+    };
+
+    #[test]
+    fn test_build_without_strings() {
+        let raw = StructureBuilder::new(0x80, 0x1234)
+            .byte(0x01)
+            .word(0x0302)
+            .dword(0x0706_0504)
+            .qword(0x0F0E_0D0C_0B0A_0908)
+            .build();
+
+        assert_eq!(
+            raw,
+            vec![
+                0x80, 0x13, 0x34, 0x12, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A,
+                0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x00, 0x00,
+            ]
+        );
+
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(parts.header.struct_type(), 0x80);
+        assert_eq!(parts.header.handle(), Handle(0x1234));
+        assert_eq!(parts.get_field_word(0x05), Some(0x0302));
+        assert_eq!(parts.get_field_qword(0x0B), Some(0x0F0E_0D0C_0B0A_0908));
+        assert_eq!(parts.len(), raw.len());
+        assert_eq!(parts.validate(0x13), Ok(()));
+    }
+
+    #[test]
+    fn test_build_round_trip() {
+        let raw = StructureBuilder::new(SMBiosSystemInformation::STRUCT_TYPE, 0x0001)
+            .string("LENOVO")
+            .string("30BFS07500")
+            .string_ref(0)
+            .string("LENOVO")
+            .build();
+
+        assert_eq!(raw[1], 0x08);
+        assert_eq!(&raw[4..8], &[1, 2, 0, 1]);
+
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        assert_eq!(test_struct.manufacturer().to_string(), "LENOVO");
+        assert_eq!(test_struct.product_name().to_string(), "30BFS07500");
+        assert_eq!(test_struct.version().to_string(), "");
+        assert_eq!(test_struct.serial_number().to_string(), "LENOVO");
+        assert_eq!(parts.strings().count(), 2);
+        assert_eq!(parts.len(), raw.len());
+        assert_eq!(test_struct.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_build_empty_string() {
+        let raw = StructureBuilder::new(SMBiosSystemInformation::STRUCT_TYPE, 0x0001)
+            .string("LENOVO")
+            .string("")
+            .build();
+
+        assert_eq!(&raw[4..6], &[1, 0]);
+
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        assert_eq!(test_struct.product_name().to_string(), "");
+        assert_eq!(parts.strings().count(), 1);
+    }
+
+    fn builder_with_255_strings() -> StructureBuilder {
+        (1..=255).fold(StructureBuilder::new(11, 0x0001), |builder, number| {
+            builder.add_string(&number.to_string())
+        })
+    }
+
+    #[test]
+    fn test_build_string_limit() {
+        // Registered strings are reused, so 255 remains a valid reference
+        let raw = builder_with_255_strings().string("255").build();
+        assert_eq!(raw[4], 255);
+    }
+
+    #[test]
+    #[should_panic(expected = "limited to 255 strings")]
+    fn test_build_too_many_strings() {
+        builder_with_255_strings().string("256");
+    }
+
+    #[test]
+    fn test_build_table() {
+        let mut table_data = Vec::new();
+        for handle in 1..4 {
+            table_data.extend(
+                StructureBuilder::new(SMBiosMemoryDevice::STRUCT_TYPE, handle)
+                    .handle(Handle(0x0100))
+                    .build(),
+            );
+        }
+        table_data.extend(StructureBuilder::new(127, 4).build());
+
+        let table = UndefinedStructTable::from(table_data);
+        let devices = table.collect::<SMBiosMemoryDevice<'_>>();
+        assert_eq!(devices.len(), 3);
+        assert_eq!(devices[2].handle(), Handle(3));
+        assert_eq!(
+            devices[0].physical_memory_array_handle(),
+            Some(Handle(0x0100))
+        );
+    }
+}
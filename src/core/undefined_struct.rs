@@ -1,13 +1,20 @@
+use super::error::SMBiosError;
+use super::field_presence::FieldPresence;
+use super::handle_map::HandleMap;
 use super::header::{Handle, Header};
+use super::smbios_data::SMBiosVersion;
 use super::strings::*;
-use crate::structs::{DefinedStruct, SMBiosEndOfTable, SMBiosStruct};
+use super::structure_builder::StructureBuilder;
+use crate::prelude::*;
+use crate::structs::{DefinedStruct, SMBiosEndOfTable, SMBiosOemDefined, SMBiosStruct};
+use alloc::borrow::Cow;
+use core::fmt;
+use core::{convert::TryInto, slice::Iter};
 use serde::{Serialize, Serializer};
-use std::fmt;
+#[cfg(feature = "std")]
 use std::{
-    convert::TryInto,
     fs::File,
     io::{prelude::*, Error, ErrorKind, SeekFrom},
-    slice::Iter,
 };
 /// # Embodies the three basic parts of an SMBIOS structure
 ///
@@ -50,6 +57,10 @@ pub struct UndefinedStruct {
     /// The strings of the structure
     #[serde(serialize_with = "ser_strings")]
     pub strings: SMBiosStringSet,
+
+    /// Version of the SMBIOS table containing the structure, when known
+    #[serde(skip)]
+    version: Option<SMBiosVersion>,
 }
 
 fn ser_strings<S>(data: &SMBiosStringSet, serializer: S) -> Result<S::Ok, S::Error>
@@ -61,18 +72,22 @@ where
 
 impl<'a> UndefinedStruct {
     /// Creates a structure instance of the given byte array slice
-    pub fn new(raw: &Vec<u8>) -> Self {
+    ///
+    /// The formatted section and strings are copied out of `raw`.
+    pub fn new(raw: &[u8]) -> Self {
         match raw.get(Header::LENGTH_OFFSET) {
             Some(&header_length) => UndefinedStruct {
                 header: Header::new(raw[..Header::SIZE].try_into().expect("4 bytes")),
                 fields: raw.get(..(header_length as usize)).unwrap_or(&[]).to_vec(),
                 strings: {
-                    SMBiosStringSet::new(
+                    SMBiosStringSet::new_with_terminator(
                         raw.get((header_length as usize)..raw.len() - 2)
                             .unwrap_or(&[])
                             .to_vec(),
+                        raw.len() >= header_length as usize + 2 && raw.ends_with(&[0, 0]),
                     )
                 },
+                version: None,
             },
             None => UndefinedStruct {
                 ..Default::default()
@@ -80,6 +95,53 @@ impl<'a> UndefinedStruct {
         }
     }
 
+    /// The length of the structure's formatted section as declared by the [Header]
+    pub fn formatted_len(&self) -> u8 {
+        self.header.length()
+    }
+
+    /// The total size of the structure in bytes
+    ///
+    /// Includes the formatted section, each string with its terminating 0,
+    /// and the final 0 of the double-zero terminator. A structure with no
+    /// strings ends with two 0 bytes. This is the distance from the start of
+    /// this structure to the start of the next one in the table.
+    pub fn len(&self) -> usize {
+        const DOUBLE_ZERO_SIZE: usize = 2usize;
+        let strings_len = match self.strings.iter().len() {
+            0 => DOUBLE_ZERO_SIZE,
+            _ => {
+                self.strings
+                    .iter()
+                    .map(|string| string.len() + 1)
+                    .sum::<usize>()
+                    + 1
+            }
+        };
+        self.fields.len() + strings_len
+    }
+
+    /// Returns `true` if the structure has no formatted section (not even a [Header])
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Version of the SMBIOS table containing the structure, when known
+    ///
+    /// Set by [crate::SMBiosData::set_version] and used by accessors of fields
+    /// introduced in a later SMBIOS version than the structure itself.
+    pub fn version(&self) -> Option<SMBiosVersion> {
+        self.version
+    }
+
+    /// Returns `true` when the table [UndefinedStruct::version] is known and
+    /// predates `introduced`, the version which added a field
+    ///
+    /// Accessors ignore such a field even if its bytes are present.
+    pub fn version_predates(&self, introduced: SMBiosVersion) -> bool {
+        self.version.is_some_and(|version| version < introduced)
+    }
+
     /// Retrieve a byte at the given offset from the structure's data section
     pub fn get_field_byte(&self, offset: usize) -> Option<u8> {
         match self.fields.get(offset..offset + 1) {
@@ -122,12 +184,75 @@ impl<'a> UndefinedStruct {
         }
     }
 
+    /// Retrieve a block of `size` bytes at the given offset, distinguishing
+    /// a field beyond the declared structure length from a truncated structure
+    ///
+    /// Returns Ok(None) when the field lies beyond the length given in the
+    /// [Header] (such as a field added in a newer SMBIOS version). Returns
+    /// [SMBiosError::Truncated] when the field is within the declared length
+    /// but the structure's data is too short to contain it.
+    fn try_get_field_bytes(
+        &self,
+        offset: usize,
+        size: usize,
+    ) -> Result<Option<&[u8]>, SMBiosError> {
+        let end = offset + size;
+        if end > self.header.length() as usize {
+            return Ok(None);
+        }
+
+        match self.fields.get(offset..end) {
+            Some(val) => Ok(Some(val)),
+            None => Err(SMBiosError::Truncated {
+                offset,
+                len: self.fields.len(),
+            }),
+        }
+    }
+
+    /// Retrieve a byte at the given offset, reporting a truncated structure as an error
+    pub fn try_get_field_byte(&self, offset: usize) -> Result<Option<u8>, SMBiosError> {
+        Ok(self.try_get_field_bytes(offset, 1)?.map(|val| val[0]))
+    }
+
+    /// Retrieve a WORD at the given offset, reporting a truncated structure as an error
+    pub fn try_get_field_word(&self, offset: usize) -> Result<Option<u16>, SMBiosError> {
+        Ok(self
+            .try_get_field_bytes(offset, 2)?
+            .map(|val| u16::from_le_bytes(val.try_into().expect("u16 is 2 bytes"))))
+    }
+
+    /// Retrieve a [Handle] at the given offset, reporting a truncated structure as an error
+    pub fn try_get_field_handle(&self, offset: usize) -> Result<Option<Handle>, SMBiosError> {
+        Ok(self
+            .try_get_field_bytes(offset, Handle::SIZE)?
+            .map(|val| Handle(u16::from_le_bytes(val.try_into().expect("u16 is 2 bytes")))))
+    }
+
+    /// Retrieve a DWORD at the given offset, reporting a truncated structure as an error
+    pub fn try_get_field_dword(&self, offset: usize) -> Result<Option<u32>, SMBiosError> {
+        Ok(self
+            .try_get_field_bytes(offset, 4)?
+            .map(|val| u32::from_le_bytes(val.try_into().expect("u32 is 4 bytes"))))
+    }
+
+    /// Retrieve a QWORD at the given offset, reporting a truncated structure as an error
+    pub fn try_get_field_qword(&self, offset: usize) -> Result<Option<u64>, SMBiosError> {
+        Ok(self
+            .try_get_field_bytes(offset, 8)?
+            .map(|val| u64::from_le_bytes(val.try_into().expect("u64 is 8 bytes"))))
+    }
+
     /// Retrieve a String of the given offset
     ///
     /// Retrieval of strings is a two part operation. The given offset
     /// contains a byte whose value is a 1 based index into the strings section.
     /// The string is thus retrieved from the strings section based on the
     /// byte value at the given offset.
+    ///
+    /// This never panics. A string which is not valid UTF-8 is reported as
+    /// [SMBiosStringError::Utf8]; see [UndefinedStruct::get_field_string_lossy]
+    /// to decode such strings anyway.
     pub fn get_field_string(&self, offset: usize) -> SMBiosString {
         match self.get_field_byte(offset) {
             Some(val) => self.strings.get_string(val),
@@ -135,14 +260,67 @@ impl<'a> UndefinedStruct {
         }
     }
 
+    /// Retrieve a borrowed string of the given offset without allocating
+    ///
+    /// Behaves like [UndefinedStruct::get_field_string] but returns a slice of
+    /// the structure's string-set. UTF-8 validation is performed on each call.
+    /// None is returned if the field is out of bounds, the string number is
+    /// invalid, or the string is not valid UTF-8.
+    pub fn get_field_string_ref(&self, offset: usize) -> Option<&str> {
+        self.get_field_byte(offset)
+            .and_then(|val| self.strings.get_string_ref(val))
+    }
+
+    /// Retrieve a string of the given offset, replacing invalid UTF-8 sequences
+    /// with U+FFFD REPLACEMENT CHARACTER
+    ///
+    /// Some firmware emits Latin-1 or stray high bytes (e.g. in serial numbers).
+    /// The string is borrowed when it is valid UTF-8 and only allocated when
+    /// replacement is required. None is returned if the field is out of bounds
+    /// or the string number is invalid.
+    pub fn get_field_string_lossy(&self, offset: usize) -> Option<Cow<'_, str>> {
+        self.get_field_byte(offset)
+            .and_then(|val| self.strings.get_string_lossy(val))
+    }
+
+    /// Iterates all strings of the structure's string-set in order
+    ///
+    /// Invalid UTF-8 sequences are replaced with U+FFFD REPLACEMENT CHARACTER
+    /// so that the n-th item always corresponds to string number n + 1.
+    /// A structure with no strings (immediate double-zero) yields nothing.
+    pub fn strings(&self) -> impl Iterator<Item = Cow<'_, str>> {
+        self.strings
+            .iter()
+            .map(|bytes| String::from_utf8_lossy(bytes))
+    }
+
     // todo: learn how to pass an index range (SliceIndex?) rather than start/end indices.
     // This would better conform to the Rust design look and feel.
 
     /// Retrieve a block of bytes from the structure's data section
+    ///
+    /// `start_index` and `end_index` are offsets into the formatted area (header
+    /// included) and `end_index` is exclusive. None is returned if the range
+    /// extends past the structure length or `start_index` exceeds `end_index`;
+    /// a truncated prefix is never returned.
     pub fn get_field_data(&self, start_index: usize, end_index: usize) -> Option<&[u8]> {
         return self.fields.get(start_index..end_index);
     }
 
+    /// Classifies a block of bytes from the structure's data section as a real
+    /// value or an all-zero or all-FF placeholder
+    ///
+    /// The range is given as for [UndefinedStruct::get_field_data]; None is
+    /// returned when it extends past the structure length.
+    pub fn get_field_presence(
+        &self,
+        start_index: usize,
+        end_index: usize,
+    ) -> Option<FieldPresence> {
+        self.get_field_data(start_index, end_index)
+            .map(FieldPresence::from)
+    }
+
     /// Cast to a given structure
     ///
     /// When this library does not contain a [DefinedStruct] variant
@@ -162,16 +340,131 @@ impl<'a> UndefinedStruct {
         }
     }
 
+    /// Re-emits the raw bytes of the structure
+    ///
+    /// The formatted section is followed by the string-set and its double-zero
+    /// terminator, the same layout the structure was parsed from.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.len());
+        raw.extend_from_slice(&self.fields);
+        match self.strings.iter().len() {
+            0 => raw.push(0),
+            _ => {
+                for string in self.strings.iter() {
+                    raw.extend_from_slice(string);
+                    raw.push(0);
+                }
+            }
+        }
+        raw.push(0);
+        raw
+    }
+
+    /// Validates the structure's declared length and string-set
+    ///
+    /// Checks that the length in the [Header] is at least `min_length`, that the
+    /// formatted section contains as many bytes as the [Header] declares, and that
+    /// the string-set ends with the double-zero terminator. A malformed or
+    /// tampered structure otherwise silently yields `None` from field accessors.
+    pub fn validate(&self, min_length: u8) -> Result<(), SMBiosStructError> {
+        let length = self.header.length();
+        if length < min_length {
+            return Err(SMBiosStructError::LengthTooShort {
+                struct_type: self.header.struct_type(),
+                length,
+                min_length,
+            });
+        }
+
+        if self.fields.len() < length as usize {
+            return Err(SMBiosStructError::Truncated {
+                length,
+                len: self.fields.len(),
+            });
+        }
+
+        if !self.strings.is_terminated() {
+            return Err(SMBiosStructError::MissingStringTerminator);
+        }
+
+        Ok(())
+    }
+
     /// Down casts the current structure to its specific defined BIOS structure type
     pub fn defined_struct(&self) -> DefinedStruct<'_> {
         self.into()
     }
 }
 
+#[cfg(feature = "strict")]
+impl<'a> UndefinedStruct {
+    /// Converts the result of a `try_get_field_*()` function, reporting a
+    /// field beyond the declared structure length as an error
+    fn strict_field<T>(
+        &self,
+        offset: usize,
+        field: Result<Option<T>, SMBiosError>,
+    ) -> Result<T, SMBiosError> {
+        field?.ok_or(SMBiosError::OutOfBounds {
+            offset,
+            length: self.header.length(),
+        })
+    }
+
+    /// Retrieve a byte at the given offset, reporting why it is unavailable
+    pub fn strict_field_byte(&self, offset: usize) -> Result<u8, SMBiosError> {
+        self.strict_field(offset, self.try_get_field_byte(offset))
+    }
+
+    /// Retrieve a WORD at the given offset, reporting why it is unavailable
+    pub fn strict_field_word(&self, offset: usize) -> Result<u16, SMBiosError> {
+        self.strict_field(offset, self.try_get_field_word(offset))
+    }
+
+    /// Retrieve a [Handle] at the given offset, reporting why it is unavailable
+    pub fn strict_field_handle(&self, offset: usize) -> Result<Handle, SMBiosError> {
+        self.strict_field(offset, self.try_get_field_handle(offset))
+    }
+
+    /// Retrieve a DWORD at the given offset, reporting why it is unavailable
+    pub fn strict_field_dword(&self, offset: usize) -> Result<u32, SMBiosError> {
+        self.strict_field(offset, self.try_get_field_dword(offset))
+    }
+
+    /// Retrieve a QWORD at the given offset, reporting why it is unavailable
+    pub fn strict_field_qword(&self, offset: usize) -> Result<u64, SMBiosError> {
+        self.strict_field(offset, self.try_get_field_qword(offset))
+    }
+
+    /// Retrieve a String of the given offset, reporting why it is unavailable
+    ///
+    /// A string number of 0 (no string) yields an empty string, as with
+    /// [UndefinedStruct::get_field_string].
+    pub fn strict_field_string(&self, offset: usize) -> Result<String, SMBiosError> {
+        let string_number = self.strict_field_byte(offset)?;
+        match self.strings.get_string(string_number).as_ref() {
+            Ok(value) => Ok(value.clone()),
+            Err(SMBiosStringError::Utf8(_)) => Err(SMBiosError::InvalidUtf8 { offset }),
+            Err(_) => Err(SMBiosError::InvalidStringNumber {
+                offset,
+                string_number,
+            }),
+        }
+    }
+
+    /// Cast to a given structure, reporting a mismatched structure type
+    pub fn strict_as_type<T: SMBiosStruct<'a>>(&'a self) -> Result<T, SMBiosError> {
+        self.as_type().ok_or(SMBiosError::UnexpectedType {
+            expected: T::STRUCT_TYPE,
+            actual: self.header.struct_type(),
+        })
+    }
+}
+
 impl fmt::Debug for UndefinedStruct {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let fields = &self.fields[Header::SIZE..];
-        fmt.debug_struct(std::any::type_name::<UndefinedStruct>())
+        fmt.debug_struct(core::any::type_name::<UndefinedStruct>())
             .field("header", &self.header)
             .field("fields", &fields)
             .field("strings", &self.strings)
@@ -186,10 +479,68 @@ impl Default for UndefinedStruct {
             header: Header::new(v),
             fields: (&[]).to_vec(),
             strings: { SMBiosStringSet::new((&[]).to_vec()) },
+            version: None,
         }
     }
 }
 
+/// # SMBiosStructError
+///
+/// An SMBIOS structure validation error
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SMBiosStructError {
+    /// The length declared by the structure's header is shorter than the
+    /// minimum length for the structure type
+    LengthTooShort {
+        /// Structure type
+        struct_type: u8,
+        /// Length declared by the structure's header
+        length: u8,
+        /// Minimum length for the structure type
+        min_length: u8,
+    },
+    /// The structure's formatted section is shorter than the length declared
+    /// by the structure's header
+    Truncated {
+        /// Length declared by the structure's header
+        length: u8,
+        /// Actual length of the structure's formatted section
+        len: usize,
+    },
+    /// The structure's string-set does not end with the double-zero terminator
+    MissingStringTerminator,
+}
+
+impl fmt::Display for SMBiosStructError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SMBiosStructError::LengthTooShort {
+                struct_type,
+                length,
+                min_length,
+            } => write!(
+                f,
+                "Type {} structure length {} is less than the minimum length {}",
+                struct_type, length, min_length
+            ),
+            SMBiosStructError::Truncated { length, len } => write!(
+                f,
+                "The structure declares length {} but only {} bytes are present",
+                length, len
+            ),
+            SMBiosStructError::MissingStringTerminator => {
+                write!(
+                    f,
+                    "The structure's string-set is missing its double-zero terminator"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SMBiosStructError {}
+
 /// # Undefined Struct Table
 ///
 /// A collection of [UndefinedStruct] items.
@@ -197,12 +548,11 @@ impl Default for UndefinedStruct {
 pub struct UndefinedStructTable(Vec<UndefinedStruct>);
 
 impl<'a> UndefinedStructTable {
-    fn new() -> UndefinedStructTable {
-        UndefinedStructTable(Vec::new())
-    }
-
-    fn add(&mut self, elem: UndefinedStruct) {
-        self.0.push(elem);
+    /// Sets the [UndefinedStruct::version] of every structure in the table
+    pub(crate) fn set_version(&mut self, version: Option<SMBiosVersion>) {
+        for undefined_struct in self.0.iter_mut() {
+            undefined_struct.version = version;
+        }
     }
 
     /// Iterator of the contained [UndefinedStruct] items.
@@ -299,13 +649,67 @@ impl<'a> UndefinedStructTable {
         self.defined_struct_iter().filter_map(f)
     }
 
+    /// An iterator over the OEM-defined structures (types 128 to 255)
+    pub fn oem_defined_iter(&'a self) -> impl Iterator<Item = SMBiosOemDefined<'a>> + 'a {
+        self.iter().filter_map(SMBiosOemDefined::try_new)
+    }
+
+    /// An iterator over the structures of the given structure type
+    ///
+    /// Unlike [UndefinedStructTable::collect] this works for any type number,
+    /// including OEM types (128-255) which have no [SMBiosStruct] implementation.
+    pub fn all_of_type(
+        &'a self,
+        struct_type: u8,
+    ) -> impl Iterator<Item = &'a UndefinedStruct> + 'a {
+        self.iter()
+            .filter(move |undefined_struct| undefined_struct.header.struct_type() == struct_type)
+    }
+
     /// Finds the structure matching the given handle
     ///
     /// To downcast to the defined struct, call .defined_struct() on the result.
     pub fn find_by_handle(&'a self, handle: &Handle) -> Option<&'a UndefinedStruct> {
         self.iter()
             .find(|smbios_struct| smbios_struct.header.handle() == *handle)
-            .and_then(|undefined_struct| Some(undefined_struct))
+    }
+
+    /// The structure at the given position in the table
+    pub fn get(&self, index: usize) -> Option<&UndefinedStruct> {
+        self.0.get(index)
+    }
+
+    /// Builds a [HandleMap] for resolving many handles without scanning the table
+    pub fn handle_map(&self) -> HandleMap {
+        HandleMap::new(self.iter())
+    }
+
+    /// An iterator over the header of every structure as
+    /// `(handle, struct_type, byte_offset)`
+    ///
+    /// `byte_offset` is the position of the structure in the raw table, as
+    /// loaded and as emitted by [UndefinedStructTable::to_bytes]. Use it to
+    /// index the table cheaply and downcast only the structures of interest.
+    pub fn headers(&self) -> impl Iterator<Item = (Handle, u8, usize)> + '_ {
+        self.iter().scan(0usize, |offset, undefined_struct| {
+            let byte_offset = *offset;
+            *offset += undefined_struct.len();
+            Some((
+                undefined_struct.header.handle(),
+                undefined_struct.header.struct_type(),
+                byte_offset,
+            ))
+        })
+    }
+
+    /// Finds the structure matching the given handle and downcasts it to `T`
+    ///
+    /// None is returned if no structure has the handle or it is not of type `T`.
+    pub fn resolve<T>(&'a self, handle: Handle) -> Option<T>
+    where
+        T: SMBiosStruct<'a>,
+    {
+        self.find_by_handle(&handle)?.as_type()
     }
 
     /// Returns all occurances of the structure
@@ -316,7 +720,30 @@ impl<'a> UndefinedStructTable {
         self.defined_struct_iter().collect()
     }
 
+    /// Re-emits the raw table bytes
+    ///
+    /// Structures are concatenated in order. If the table does not contain an
+    /// End-of-Table (Type 127) structure one is appended, using the handle after
+    /// the highest handle in the table. Bytes which were not part of a structure,
+    /// such as padding following the End-of-Table, are not emitted.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut raw: Vec<u8> = self.iter().flat_map(|elem| elem.to_bytes()).collect();
+        let has_end_of_table = self
+            .iter()
+            .any(|elem| elem.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE);
+        if !has_end_of_table {
+            let handle = self
+                .iter()
+                .map(|elem| *elem.header.handle())
+                .max()
+                .map_or(0, |handle| handle.wrapping_add(1));
+            raw.extend(StructureBuilder::new(SMBiosEndOfTable::STRUCT_TYPE, handle).build());
+        }
+        raw
+    }
+
     /// Load an [UndefinedStructTable] by seeking and reading the file offsets.
+    #[cfg(feature = "std")]
     pub fn try_load_from_file_offset(
         file: &mut File,
         table_offset: u64,
@@ -339,64 +766,488 @@ impl<'a> UndefinedStructTable {
 
 impl From<Vec<u8>> for UndefinedStructTable {
     fn from(data: Vec<u8>) -> Self {
+        UndefinedStructTable::from(data.as_slice())
+    }
+}
+
+impl From<&[u8]> for UndefinedStructTable {
+    fn from(data: &[u8]) -> Self {
+        UndefinedStructTable(
+            RawStructIterator::new(data)
+                .map(UndefinedStruct::new)
+                .collect(),
+        )
+    }
+}
+
+/// # Iterator of the Raw Structures of a Table
+///
+/// Walks a block of table bytes in place and yields the bytes of each
+/// structure: its formatted section, strings and double-zero terminator.
+/// Nothing is copied. Iteration ends after the End-of-Table (Type 127)
+/// structure, as any remaining bytes are padding, or at the first structure
+/// which is malformed or does not fit in the data.
+pub struct RawStructIterator<'a> {
+    data: &'a [u8],
+    current_index: usize,
+}
+
+impl<'a> RawStructIterator<'a> {
+    /// Creates an iterator over the structures in `data`
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            current_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for RawStructIterator<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
         const DOUBLE_ZERO_SIZE: usize = 2usize;
         const MIN_STRUCT_SIZE: usize = Header::SIZE + DOUBLE_ZERO_SIZE;
-        let mut result = Self::new();
-        let mut current_index = 0usize;
-
-        loop {
-            // Is the next structure long enough?
-            match data.get(current_index..current_index + MIN_STRUCT_SIZE) {
-                Some(min_struct) => {
-                    // Read the structure's self-reported length in its header
-                    let struct_len = min_struct[Header::LENGTH_OFFSET] as usize;
-
-                    // Bad reported length
-                    if struct_len < Header::SIZE {
-                        break;
-                    }
-
-                    // Beyond the structure length are the structure's strings
-                    // Find the /0/0 which marks the end of this structure and the
-                    // beginning of the next.
-                    match data.get(current_index + struct_len..) {
-                        Some(strings_etc) => {
-                            match strings_etc
-                                .windows(DOUBLE_ZERO_SIZE)
-                                .position(|x| x[0] == x[1] && x[1] == 0)
-                            {
-                                Some(double_zero_position) => {
-                                    // The next structure will start at this index
-                                    let next_index = current_index
-                                        + struct_len
-                                        + double_zero_position
-                                        + DOUBLE_ZERO_SIZE;
-
-                                    // Copy the current structure to the collection
-                                    result.add(UndefinedStruct::new(
-                                        &data[current_index..next_index].to_vec(),
-                                    ));
-                                    current_index = next_index;
-                                }
-                                None => break,
-                            }
-                        }
-                        None => break,
-                    };
-                }
-                None => break,
-            }
+        let current_index = self.current_index;
+
+        // Is the next structure long enough?
+        let min_struct = self
+            .data
+            .get(current_index..current_index + MIN_STRUCT_SIZE)?;
+
+        // Read the structure's self-reported length in its header
+        let struct_len = min_struct[Header::LENGTH_OFFSET] as usize;
+
+        // Bad reported length
+        if struct_len < Header::SIZE {
+            return None;
         }
 
-        result
+        // Beyond the structure length are the structure's strings
+        // Find the /0/0 which marks the end of this structure and the
+        // beginning of the next.
+        let double_zero_position = self
+            .data
+            .get(current_index + struct_len..)?
+            .windows(DOUBLE_ZERO_SIZE)
+            .position(|x| x[0] == x[1] && x[1] == 0)?;
+        let next_index = current_index + struct_len + double_zero_position + DOUBLE_ZERO_SIZE;
+
+        self.current_index =
+            if min_struct[Header::STRUCT_TYPE_OFFSET] == SMBiosEndOfTable::STRUCT_TYPE {
+                self.data.len()
+            } else {
+                next_index
+            };
+
+        Some(&self.data[current_index..next_index])
     }
 }
 
 impl IntoIterator for UndefinedStructTable {
     type Item = UndefinedStruct;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_iteration() {
+        let table_data = vec![
+            // Type 1 (System Information) with one string
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, b'A', 0x00, 0x00,
+            // Type 2 (Baseboard Information) without strings
+            0x02, 0x04, 0x02, 0x00, 0x00, 0x00, // Type 127 (End-of-Table)
+            0x7F, 0x04, 0x03, 0x00, 0x00, 0x00, // Padding after the End-of-Table
+            0x01, 0x04, 0x04, 0x00, 0x00, 0x00,
+        ];
+
+        let table = UndefinedStructTable::from(table_data);
+        let handles: Vec<(u8, u16)> = table
+            .iter()
+            .map(|undefined_struct| {
+                (
+                    undefined_struct.header.struct_type(),
+                    *undefined_struct.header.handle(),
+                )
+            })
+            .collect();
+        assert_eq!(handles, vec![(1, 1), (2, 2), (127, 3)]);
+
+        let first = table.iter().next().unwrap();
+        assert_eq!(first.fields.len(), 8);
+        assert_eq!(first.get_field_string(0x04).to_string(), "A".to_string());
+    }
+
+    #[test]
+    fn test_table_iteration_truncated() {
+        // The second structure is missing its double-zero terminator
+        let table_data = vec![
+            0x02, 0x04, 0x02, 0x00, 0x00, 0x00, 0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+            b'A',
+        ];
+
+        let table = UndefinedStructTable::from(table_data);
+        assert_eq!(table.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_find_by_handle() {
+        let table_data = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, b'A', 0x00, 0x00, 0x02, 0x04, 0x02,
+            0x00, 0x00, 0x00, 0x7F, 0x04, 0x03, 0x00, 0x00, 0x00,
+        ];
+
+        let table = UndefinedStructTable::from(table_data);
+
+        let found = table.find_by_handle(&Handle(2)).unwrap();
+        assert_eq!(found.header.struct_type(), 2);
+        assert!(table.find_by_handle(&Handle(0x1234)).is_none());
+
+        let first: crate::SMBiosSystemInformation<'_> = table.first().unwrap();
+        assert_eq!(*first.parts().header.handle(), 1);
+        assert!(table.first::<crate::SMBiosInformation<'_>>().is_none());
+    }
+
+    #[test]
+    fn test_as_type() {
+        let struct_type29 = vec![
+            0x1D, 0x16, 0x33, 0x00, 0x01, 0x67, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80,
+            0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, b'A', b'B', b'C', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type29);
+
+        let probe = parts
+            .as_type::<crate::SMBiosElectricalCurrentProbe<'_>>()
+            .unwrap();
+        assert_eq!(probe.description().to_string(), "ABC".to_string());
+        assert!(parts
+            .as_type::<crate::SMBiosSystemChassisInformation<'_>>()
+            .is_none());
+    }
+
+    #[test]
+    fn test_try_get_field_truncated() {
+        // Header declares a length of 0x0C but only 0x08 bytes are present
+        let truncated = UndefinedStruct {
+            header: Header::new([0x80, 0x0C, 0x01, 0x00]),
+            fields: vec![0x80, 0x0C, 0x01, 0x00, 0x11, 0x22, 0x33, 0x44],
+            strings: SMBiosStringSet::new(vec![]),
+            version: None,
+        };
+
+        assert_eq!(truncated.try_get_field_byte(0x04), Ok(Some(0x11)));
+        assert_eq!(truncated.try_get_field_word(0x04), Ok(Some(0x2211)));
+        assert_eq!(truncated.try_get_field_dword(0x04), Ok(Some(0x44332211)));
+        assert_eq!(
+            truncated.try_get_field_dword(0x06),
+            Err(SMBiosError::Truncated { offset: 6, len: 8 })
+        );
+        assert_eq!(
+            truncated.try_get_field_qword(0x04),
+            Err(SMBiosError::Truncated { offset: 4, len: 8 })
+        );
+        // Beyond the declared length the field is simply not present
+        assert_eq!(truncated.try_get_field_byte(0x0C), Ok(None));
+        assert_eq!(truncated.try_get_field_handle(0x0B), Ok(None));
+
+        // The non-try accessors cannot distinguish the two cases
+        assert_eq!(truncated.get_field_dword(0x06), None);
+        assert_eq!(truncated.get_field_byte(0x0C), None);
+    }
+
+    #[test]
+    fn test_get_field_string_ref() {
+        // Type 1 with string numbers 1, 2, 0 (none), 5 (invalid) and a non UTF-8 string 3
+        let struct_type1 = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x02, 0x00, 0x05, b'A', b'B', b'C', 0x00, b'x', b'y',
+            0x00, 0xFF, 0xFE, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type1);
+
+        for offset in 0x04..0x06 {
+            let borrowed = parts.get_field_string_ref(offset).unwrap();
+            assert_eq!(borrowed, parts.get_field_string(offset).ok().unwrap());
+        }
+        assert_eq!(parts.get_field_string_ref(0x04), Some("ABC"));
+        assert_eq!(parts.get_field_string_ref(0x06), Some(""));
+        assert_eq!(parts.get_field_string_ref(0x07), None);
+        assert_eq!(parts.get_field_string_ref(0x08), None);
+        assert_eq!(parts.strings.get_string_ref(3), None);
+        assert!(parts.get_field_string(0x04).is_ok());
+    }
+
+    #[test]
+    fn test_get_field_string_lossy() {
+        // Type 1 with string numbers 1 (Latin-1 serial), 2 (valid), 0 (none) and 5 (invalid)
+        let struct_type1 = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x02, 0x00, 0x05, b'S', b'N', 0xFF, 0xE9, b'1', 0x00,
+            b'o', b'k', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type1);
+
+        match parts.get_field_string(0x04).err() {
+            Some(SMBiosStringError::Utf8(_)) => (),
+            other => panic!("expected a UTF-8 error, got {:?}", other),
+        }
+
+        let lossy = parts.get_field_string_lossy(0x04).unwrap();
+        assert!(matches!(lossy, Cow::Owned(_)));
+        assert_eq!(lossy, "SN\u{FFFD}\u{FFFD}1");
+
+        let valid = parts.get_field_string_lossy(0x05).unwrap();
+        assert!(matches!(valid, Cow::Borrowed("ok")));
+        assert_eq!(parts.get_field_string_lossy(0x06).unwrap(), "");
+        assert!(parts.get_field_string_lossy(0x07).is_none());
+        assert!(parts.get_field_string_lossy(0x08).is_none());
+    }
+
+    #[test]
+    fn test_get_field_data() {
+        // Type 0x80 (OEM) with a length of 0x0A
+        let struct_oem = vec![
+            0x80, 0x0A, 0x01, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_oem);
+
+        // In-bounds
+        assert_eq!(parts.get_field_data(0x04, 0x06), Some(&[0x11, 0x22][..]));
+        assert_eq!(
+            parts.get_field_data(0x00, 0x04),
+            Some(&[0x80, 0x0A, 0x01, 0x00][..])
+        );
+        assert_eq!(parts.get_field_data(0x05, 0x05), Some(&[][..]));
+
+        // Exact boundary
+        assert_eq!(
+            parts.get_field_data(0x04, 0x0A),
+            Some(&[0x11, 0x22, 0x33, 0x44, 0x55, 0x66][..])
+        );
+        assert_eq!(parts.get_field_data(0x0A, 0x0A), Some(&[][..]));
+
+        // Out-of-bounds
+        assert!(parts.get_field_data(0x04, 0x0B).is_none());
+        assert!(parts.get_field_data(0x0B, 0x0C).is_none());
+        assert!(parts.get_field_data(0x06, 0x04).is_none());
+    }
+
+    #[test]
+    fn test_get_field_qword() {
+        // Type 0x80 (OEM) with a length of 0x0F
+        let struct_oem = vec![
+            0x80, 0x0F, 0x01, 0x00, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0xAA, 0xBB,
+            0xCC, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_oem);
+
+        assert_eq!(parts.get_field_qword(0x04), Some(0x0102_0304_0506_0708));
+        assert_eq!(parts.get_field_qword(0x07), Some(0xCCBB_AA01_0203_0405));
+        // One byte past the end of the structure
+        assert_eq!(parts.get_field_qword(0x08), None);
+        assert_eq!(parts.get_field_qword(0x0F), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        // Type 1 with one string
+        let mut struct_type1 = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, b'A', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type1);
+        assert_eq!(parts.validate(0x08), Ok(()));
+        assert_eq!(
+            parts.validate(0x09),
+            Err(SMBiosStructError::LengthTooShort {
+                struct_type: 1,
+                length: 0x08,
+                min_length: 0x09
+            })
+        );
+
+        // The double-zero terminator is missing
+        struct_type1.pop();
+        let unterminated = UndefinedStruct::new(&struct_type1);
+        assert_eq!(
+            unterminated.validate(0x08),
+            Err(SMBiosStructError::MissingStringTerminator)
+        );
+
+        // Header declares a length of 0x0C but only 0x08 bytes are present
+        let truncated = UndefinedStruct::new(&[0x80, 0x0C, 0x01, 0x00, 0x11, 0x22, 0x00, 0x00]);
+        assert_eq!(
+            truncated.validate(0x04),
+            Err(SMBiosStructError::Truncated {
+                length: 0x0C,
+                len: 0x00
+            })
+        );
+    }
+
+    #[test]
+    fn test_strings() {
+        // No strings
+        let parts = UndefinedStruct::new(&[0x80, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(parts.strings().count(), 0);
+
+        // One string
+        let parts = UndefinedStruct::new(&[0x80, 0x05, 0x01, 0x00, 0x01, b'S', b'N', 0x00, 0x00]);
+        assert_eq!(parts.strings().collect::<Vec<_>>(), vec!["SN"]);
+
+        // Multiple strings, including one that is not valid UTF-8
+        let parts = UndefinedStruct::new(&[
+            0x80, 0x05, 0x01, 0x00, 0x01, b'A', 0x00, 0xFF, 0x00, b'C', b'D', 0x00, 0x00,
+        ]);
+        assert_eq!(
+            parts.strings().collect::<Vec<_>>(),
+            vec!["A", "\u{FFFD}", "CD"]
+        );
+        assert!(parts.strings().any(|string| string == "CD"));
+    }
+
+    #[test]
+    fn test_len() {
+        // No strings
+        let raw = vec![0x80, 0x05, 0x01, 0x00, 0x00, 0x00, 0x00];
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(parts.formatted_len(), 0x05);
+        assert_eq!(parts.len(), raw.len());
+        assert!(!parts.is_empty());
+
+        // One string
+        let raw = vec![0x80, 0x05, 0x01, 0x00, 0x01, b'S', b'N', 0x00, 0x00];
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(parts.formatted_len(), 0x05);
+        assert_eq!(parts.len(), raw.len());
+
+        // Multiple strings
+        let raw = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x02, 0x00, 0x00, b'A', 0x00, b'B', b'C', 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(parts.formatted_len(), 0x08);
+        assert_eq!(parts.len(), raw.len());
+
+        assert!(UndefinedStruct::default().is_empty());
+    }
+
+    #[test]
+    fn test_all_of_type() {
+        let mut table_data = vec![
+            // Type 16 (Physical Memory Array)
+            0x10, 0x04, 0x01, 0x00, 0x00, 0x00,
+        ];
+        for handle in 2..5u8 {
+            // Type 17 (Memory Device)
+            table_data.extend_from_slice(&[0x11, 0x04, handle, 0x00, 0x00, 0x00]);
+        }
+        table_data.extend_from_slice(&[
+            // Type 0x80 (OEM) and Type 127 (End-of-Table)
+            0x80, 0x04, 0x05, 0x00, 0x00, 0x00, 0x7F, 0x04, 0x06, 0x00, 0x00, 0x00,
+        ]);
+
+        let table = UndefinedStructTable::from(table_data);
+
+        let handles: Vec<u16> = table
+            .all_of_type(17)
+            .map(|undefined_struct| *undefined_struct.header.handle())
+            .collect();
+        assert_eq!(handles, vec![2, 3, 4]);
+        assert_eq!(table.collect::<crate::SMBiosMemoryDevice<'_>>().len(), 3);
+        assert_eq!(table.all_of_type(0x80).count(), 1);
+        assert_eq!(table.all_of_type(4).count(), 0);
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let table_data = vec![
+            // Type 1 (System Information) with an empty first string
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, b'A', 0x00, 0x00,
+            // Type 2 (Baseboard Information) without strings
+            0x02, 0x04, 0x02, 0x00, 0x00, 0x00, // Type 127 (End-of-Table)
+            0x7F, 0x04, 0x03, 0x00, 0x00, 0x00,
+        ];
+
+        let table = UndefinedStructTable::from(table_data.clone());
+        assert_eq!(table.to_bytes(), table_data);
+
+        // Missing End-of-Table
+        let table = UndefinedStructTable::from(table_data[..18].to_vec());
+        let mut expected = table_data[..18].to_vec();
+        expected.extend_from_slice(&[0x7F, 0x04, 0x03, 0x00, 0x00, 0x00]);
+        assert_eq!(table.to_bytes(), expected);
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn test_strict_accessors() {
+        use crate::{SMBiosError, SMBiosSystemInformation};
+
+        // Type 1 declaring length 0x09 with string 1 valid, string 2 invalid UTF-8,
+        // and a reference to a missing string 3
+        let struct_type1 = vec![
+            0x01, 0x09, 0x01, 0x00, 0x01, 0x02, 0x03, 0x00, 0x00, b'A', 0x00, 0xFF, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type1);
+
+        assert_eq!(parts.strict_field_byte(0x04), Ok(0x01));
+        assert_eq!(parts.strict_field_word(0x04), Ok(0x0201));
+        assert_eq!(parts.strict_field_string(0x04), Ok("A".to_string()));
+        assert_eq!(parts.strict_field_string(0x07), Ok(String::new()));
+        assert_eq!(
+            parts.strict_field_string(0x05),
+            Err(SMBiosError::InvalidUtf8 { offset: 0x05 })
+        );
+        assert_eq!(
+            parts.strict_field_string(0x06),
+            Err(SMBiosError::InvalidStringNumber {
+                offset: 0x06,
+                string_number: 3
+            })
+        );
+        assert_eq!(
+            parts.strict_field_dword(0x08),
+            Err(SMBiosError::OutOfBounds {
+                offset: 0x08,
+                length: 0x09
+            })
+        );
+        assert!(parts
+            .strict_as_type::<SMBiosSystemInformation<'_>>()
+            .is_ok());
+        assert!(matches!(
+            parts.strict_as_type::<crate::SMBiosBaseboardInformation<'_>>(),
+            Err(SMBiosError::UnexpectedType {
+                expected: 2,
+                actual: 1
+            })
+        ));
+
+        // Declares length 0x08 but only 0x06 bytes of fields are present
+        let truncated = UndefinedStruct {
+            header: Header::new([0x01, 0x08, 0x01, 0x00]),
+            fields: vec![0x01, 0x08, 0x01, 0x00, 0x00, 0x00],
+            strings: SMBiosStringSet::new(Vec::new()),
+            version: None,
+        };
+        assert_eq!(truncated.strict_field_byte(0x05), Ok(0x00));
+        assert_eq!(
+            truncated.strict_field_word(0x06),
+            Err(SMBiosError::Truncated {
+                offset: 0x06,
+                len: 0x06
+            })
+        );
+    }
+}
@@ -0,0 +1,757 @@
+use crate::core::{SMBiosData, SMBiosString, SMBiosStringError, SMBiosVersion, UndefinedStruct};
+use crate::prelude::*;
+use crate::structs::{
+    BoardType, ChassisHeight, ChassisSecurityStatus, ChassisState, ChassisType, DefinedStruct,
+    ElementMaximum, ElementMinimum, ElementType, PowerCords, RomSize, SMBiosBaseboardInformation,
+    SMBiosInformation, SMBiosSystemChassisInformation, SMBiosSystemInformation, SystemUuidData,
+    SystemWakeUpType,
+};
+use core::fmt;
+
+/// # dmidecode Text Rendering of a Structure
+///
+/// Formats an [UndefinedStruct] the way the `dmidecode` tool does: a
+/// `Handle 0x0003, DMI type 3, 22 bytes` line, the structure name, and one
+/// tab indented line per field using dmidecode's labels. This allows the
+/// output of this library to be diffed against the reference tool.
+///
+/// BIOS (Type 0), System (Type 1), Base Board (Type 2) and Chassis (Type 3)
+/// information are decoded field by field. All other structures are rendered
+/// as a hex dump of their header and data followed by their strings.
+pub struct DmiDecode<'a> {
+    parts: &'a UndefinedStruct,
+}
+
+impl<'a> DmiDecode<'a> {
+    /// Creates a renderer for `parts`
+    ///
+    /// The [UndefinedStruct::version] of `parts`, if known, is used for version
+    /// dependent formatting such as the UUID byte order.
+    pub fn new(parts: &'a UndefinedStruct) -> Self {
+        Self { parts }
+    }
+}
+
+impl fmt::Display for DmiDecode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = &self.parts.header;
+        writeln!(
+            f,
+            "Handle {}, DMI type {}, {} bytes",
+            header.handle(),
+            header.struct_type(),
+            header.length()
+        )?;
+
+        match self.parts.defined_struct() {
+            DefinedStruct::Information(data) => write_bios_information(f, &data),
+            DefinedStruct::SystemInformation(data) => {
+                write_system_information(f, &data, self.parts.version().as_ref())
+            }
+            DefinedStruct::BaseBoardInformation(data) => write_baseboard_information(f, &data),
+            DefinedStruct::SystemChassisInformation(data) => write_chassis_information(f, &data),
+            DefinedStruct::Inactive(_) => writeln!(f, "Inactive"),
+            DefinedStruct::EndOfTable(_) => writeln!(f, "End Of Table"),
+            _ => {
+                writeln!(f, "{}", structure_name(header.struct_type()))?;
+                write_dump(f, self.parts)
+            }
+        }
+    }
+}
+
+/// # dmidecode Text Rendering of a Table
+///
+/// Formats every structure of an [SMBiosData] with [DmiDecode], separated by
+/// blank lines, after dmidecode's `SMBIOS x.y present.` line when the version
+/// of the table is known.
+pub struct DmiDecodeTable<'a> {
+    data: &'a SMBiosData,
+}
+
+impl<'a> DmiDecodeTable<'a> {
+    /// Creates a renderer for `data`
+    pub fn new(data: &'a SMBiosData) -> Self {
+        Self { data }
+    }
+}
+
+impl fmt::Display for DmiDecodeTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(version) = self.data.version() {
+            if version.major >= 3 {
+                writeln!(
+                    f,
+                    "SMBIOS {}.{}.{} present.",
+                    version.major, version.minor, version.revision
+                )?;
+            } else {
+                writeln!(f, "SMBIOS {}.{} present.", version.major, version.minor)?;
+            }
+            writeln!(f)?;
+        }
+
+        for parts in self.data.iter() {
+            writeln!(f, "{}", DmiDecode::new(parts))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_bios_information(f: &mut fmt::Formatter<'_>, data: &SMBiosInformation<'_>) -> fmt::Result {
+    writeln!(f, "BIOS Information")?;
+    write_string(f, "Vendor", data.vendor())?;
+    write_string(f, "Version", data.version())?;
+    write_string(f, "Release Date", data.release_date())?;
+
+    if let Some(segment) = data.starting_address_segment() {
+        if segment != 0 {
+            writeln!(f, "\tAddress: 0x{:04X}0", segment)?;
+            let runtime_size = (0x10000 - segment as u32) << 4;
+            if runtime_size & 0x3FF != 0 {
+                writeln!(f, "\tRuntime Size: {} bytes", runtime_size)?;
+            } else {
+                writeln!(f, "\tRuntime Size: {} kB", runtime_size >> 10)?;
+            }
+        }
+    }
+
+    if let Some(rom_size) = data.rom_size() {
+        let rom_size = match rom_size {
+            RomSize::SeeExtendedRomSize => {
+                data.extended_rom_size().unwrap_or(RomSize::Megabytes(16))
+            }
+            _ => rom_size,
+        };
+
+        match rom_size {
+            RomSize::Kilobytes(size) => writeln!(f, "\tROM Size: {}", memory_size(size as u64))?,
+            RomSize::Megabytes(size) => writeln!(f, "\tROM Size: {} MB", size)?,
+            RomSize::Gigabytes(size) => writeln!(f, "\tROM Size: {} GB", size)?,
+            RomSize::Undefined(raw) => writeln!(f, "\tROM Size: {} {}", raw & 0x3FFF, OUT_OF_SPEC)?,
+            RomSize::SeeExtendedRomSize => (),
+        }
+    }
+
+    if let Some(characteristics) = data.characteristics() {
+        writeln!(f, "\tCharacteristics:")?;
+        if characteristics.bios_characteristics_not_supported() {
+            writeln!(f, "\t\tBIOS characteristics not supported")?;
+        } else {
+            write_flags(f, characteristics.raw, 4, BIOS_CHARACTERISTICS)?;
+            if let Some(extension) = data.characteristics_extension0() {
+                write_flags(f, extension.raw as u32, 0, BIOS_CHARACTERISTICS_EXTENSION_0)?;
+            }
+            if let Some(extension) = data.characteristics_extension1() {
+                write_flags(f, extension.raw as u32, 0, BIOS_CHARACTERISTICS_EXTENSION_1)?;
+            }
+        }
+    }
+
+    if let (Some(major), Some(minor)) = (
+        data.system_bios_major_release(),
+        data.system_bios_minor_release(),
+    ) {
+        if major != 0xFF && minor != 0xFF {
+            writeln!(f, "\tBIOS Revision: {}.{}", major, minor)?;
+        }
+    }
+
+    if let (Some(major), Some(minor)) = (
+        data.e_c_firmware_major_release(),
+        data.e_c_firmware_minor_release(),
+    ) {
+        if major != 0xFF && minor != 0xFF {
+            writeln!(f, "\tFirmware Revision: {}.{}", major, minor)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_system_information(
+    f: &mut fmt::Formatter<'_>,
+    data: &SMBiosSystemInformation<'_>,
+    version: Option<&SMBiosVersion>,
+) -> fmt::Result {
+    writeln!(f, "System Information")?;
+    write_string(f, "Manufacturer", data.manufacturer())?;
+    write_string(f, "Product Name", data.product_name())?;
+    write_string(f, "Version", data.version())?;
+    write_string(f, "Serial Number", data.serial_number())?;
+
+    if let Some(uuid) = data.uuid() {
+        let uuid = match uuid {
+            SystemUuidData::IdNotPresentButSettable => "Not Settable".to_string(),
+            SystemUuidData::IdNotPresent => "Not Present".to_string(),
+            SystemUuidData::Uuid(uuid) => match version {
+                Some(version) => uuid.to_string_for_version(version),
+                None => uuid.to_string(),
+            }
+            .to_uppercase(),
+        };
+        writeln!(f, "\tUUID: {}", uuid)?;
+    }
+
+    if let Some(wakeup_type) = data.wakeup_type() {
+        writeln!(
+            f,
+            "\tWake-up Type: {}",
+            match wakeup_type.value {
+                SystemWakeUpType::None if wakeup_type.raw == 0x00 => "Reserved".to_string(),
+                value => label(value, SystemWakeUpType::None),
+            }
+        )?;
+    }
+
+    write_string(f, "SKU Number", data.sku_number())?;
+    write_string(f, "Family", data.family())
+}
+
+fn write_baseboard_information(
+    f: &mut fmt::Formatter<'_>,
+    data: &SMBiosBaseboardInformation<'_>,
+) -> fmt::Result {
+    writeln!(f, "Base Board Information")?;
+    write_string(f, "Manufacturer", data.manufacturer())?;
+    write_string(f, "Product Name", data.product())?;
+    write_string(f, "Version", data.version())?;
+    write_string(f, "Serial Number", data.serial_number())?;
+    write_string(f, "Asset Tag", data.asset_tag())?;
+
+    if let Some(features) = data.feature_flags() {
+        if features.raw & 0x1F == 0 {
+            writeln!(f, "\tFeatures: None")?;
+        } else {
+            writeln!(f, "\tFeatures:")?;
+            write_flags(f, features.raw as u32, 0, BASEBOARD_FEATURES)?;
+        }
+    }
+
+    write_string(f, "Location In Chassis", data.location_in_chassis())?;
+
+    if let Some(handle) = data.chassis_handle() {
+        writeln!(f, "\tChassis Handle: {}", handle)?;
+    }
+
+    if let Some(board_type) = data.board_type() {
+        writeln!(f, "\tType: {}", label(board_type.value, BoardType::None))?;
+    }
+
+    if let Some(count) = data.number_of_contained_object_handles() {
+        writeln!(f, "\tContained Object Handles: {}", count)?;
+        for handle in data.contained_object_handle_iterator() {
+            writeln!(f, "\t\t{}", handle)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_chassis_information(
+    f: &mut fmt::Formatter<'_>,
+    data: &SMBiosSystemChassisInformation<'_>,
+) -> fmt::Result {
+    writeln!(f, "Chassis Information")?;
+    write_string(f, "Manufacturer", data.manufacturer())?;
+
+    if let Some(chassis_type) = data.chassis_type() {
+        writeln!(
+            f,
+            "\tType: {}",
+            label(chassis_type.value, ChassisType::None)
+        )?;
+        writeln!(
+            f,
+            "\tLock: {}",
+            match chassis_type.raw & 0x80 {
+                0 => "Not Present",
+                _ => "Present",
+            }
+        )?;
+    }
+
+    write_string(f, "Version", data.version())?;
+    write_string(f, "Serial Number", data.serial_number())?;
+    write_string(f, "Asset Tag", data.asset_tag_number())?;
+
+    for (name, state) in [
+        ("Boot-up State", data.bootup_state()),
+        ("Power Supply State", data.power_supply_state()),
+        ("Thermal State", data.thermal_state()),
+    ]
+    .iter()
+    {
+        if let Some(state) = state {
+            writeln!(f, "\t{}: {}", name, label(state.value, ChassisState::None))?;
+        }
+    }
+
+    if let Some(security_status) = data.security_status() {
+        writeln!(
+            f,
+            "\tSecurity Status: {}",
+            label(security_status.value, ChassisSecurityStatus::None)
+        )?;
+    }
+
+    if let Some(oem_defined) = data.oem_defined() {
+        writeln!(f, "\tOEM Information: 0x{:08X}", oem_defined)?;
+    }
+
+    if let Some(height) = data.height() {
+        match height {
+            ChassisHeight::Unspecified => writeln!(f, "\tHeight: Unspecified")?,
+            ChassisHeight::U(height) => writeln!(f, "\tHeight: {} U", height)?,
+        }
+    }
+
+    if let Some(power_cords) = data.number_of_power_cords() {
+        match power_cords {
+            PowerCords::Unspecified => writeln!(f, "\tNumber Of Power Cords: Unspecified")?,
+            PowerCords::Count(count) => writeln!(f, "\tNumber Of Power Cords: {}", count)?,
+        }
+    }
+
+    if let Some(contained_elements) = data.contained_elements() {
+        writeln!(
+            f,
+            "\tContained Elements: {}",
+            data.contained_element_count().unwrap_or(0)
+        )?;
+        for element in &contained_elements {
+            let element_type = match element.element_type() {
+                ElementType::BaseboardType(board_type) => label(board_type.value, BoardType::None),
+                ElementType::SMBiosType(struct_type) => structure_type(*struct_type).to_string(),
+            };
+            let minimum = match element.element_minimum() {
+                ElementMinimum::Count(count) => count,
+                ElementMinimum::Reserved => 0xFF,
+            };
+            let maximum = match element.element_maximum() {
+                ElementMaximum::Count(count) => count,
+                ElementMaximum::Reserved => 0x00,
+            };
+            if minimum == maximum {
+                writeln!(f, "\t\t{} ({})", element_type, minimum)?;
+            } else {
+                writeln!(f, "\t\t{} ({}-{})", element_type, minimum, maximum)?;
+            }
+        }
+    }
+
+    write_string(f, "SKU Number", data.sku_number())
+}
+
+/// Hex dump of the header, data and strings of a structure
+fn write_dump(f: &mut fmt::Formatter<'_>, parts: &UndefinedStruct) -> fmt::Result {
+    writeln!(f, "\tHeader and Data:")?;
+    for row in parts.fields.chunks(16) {
+        let bytes: Vec<String> = row.iter().map(|byte| format!("{:02X}", byte)).collect();
+        writeln!(f, "\t\t{}", bytes.join(" "))?;
+    }
+
+    let mut strings = parts.strings.iter().peekable();
+    if strings.peek().is_some() {
+        writeln!(f, "\tStrings:")?;
+        for string in strings {
+            writeln!(f, "\t\t{}", ascii_filter(&String::from_utf8_lossy(string)))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a string field unless the field lies beyond the structure's length
+///
+/// Like dmidecode a string number of 0 is "Not Specified" and a string
+/// number beyond the string-set is "<BAD INDEX>".
+fn write_string(f: &mut fmt::Formatter<'_>, label: &str, string: SMBiosString) -> fmt::Result {
+    let value = match string.as_ref() {
+        Ok(value) if value.is_empty() => "Not Specified".to_string(),
+        Ok(value) => ascii_filter(value),
+        Err(SMBiosStringError::FieldOutOfBounds) => return Ok(()),
+        Err(SMBiosStringError::InvalidStringNumber(_)) => "<BAD INDEX>".to_string(),
+        Err(SMBiosStringError::Utf8(_)) => {
+            ascii_filter(&string.to_utf8_lossy().unwrap_or_default())
+        }
+    };
+    writeln!(f, "\t{}: {}", label, value)
+}
+
+/// Writes one list item for each bit set in `raw`, starting from bit `first_bit`
+fn write_flags(
+    f: &mut fmt::Formatter<'_>,
+    raw: u32,
+    first_bit: usize,
+    names: &[&str],
+) -> fmt::Result {
+    for (index, name) in names.iter().enumerate() {
+        if raw & (1 << (first_bit + index)) != 0 {
+            writeln!(f, "\t\t{}", name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Replaces control characters with '.' as dmidecode does
+fn ascii_filter(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c < ' ' || c == '\x7F' { '.' } else { c })
+        .collect()
+}
+
+/// Memory size in the largest unit that does not lose precision (dmidecode style)
+fn memory_size(kilobytes: u64) -> String {
+    const UNITS: [&str; 6] = ["kB", "MB", "GB", "TB", "PB", "EB"];
+
+    let split: Vec<u64> = (0..UNITS.len())
+        .map(|unit| (kilobytes >> (10 * unit)) & 0x3FF)
+        .collect();
+
+    let mut unit = (1..UNITS.len())
+        .rev()
+        .find(|&unit| split[unit] != 0)
+        .unwrap_or(0);
+
+    let capacity = if unit > 0 && split[unit - 1] != 0 {
+        unit -= 1;
+        split[unit] + (split[unit + 1] << 10)
+    } else {
+        split[unit]
+    };
+
+    format!("{} {}", capacity, UNITS[unit])
+}
+
+const OUT_OF_SPEC: &str = "<OUT OF SPEC>";
+
+/// The [fmt::Display] label of a decoded value, or [OUT_OF_SPEC] when the raw
+/// value decoded to the `undefined` variant
+fn label<T: fmt::Display + PartialEq>(value: T, undefined: T) -> String {
+    if value == undefined {
+        OUT_OF_SPEC.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Section title dmidecode uses for each structure type
+fn structure_name(struct_type: u8) -> &'static str {
+    match struct_type {
+        0..=46 => STRUCTURE_NAMES[struct_type as usize],
+        126 => "Inactive",
+        127 => "End Of Table",
+        128..=255 => "OEM-specific Type",
+        _ => "Unknown Type",
+    }
+}
+
+/// Short structure type name dmidecode uses for chassis contained elements
+fn structure_type(struct_type: u8) -> &'static str {
+    match struct_type {
+        0..=46 => STRUCTURE_TYPES[struct_type as usize],
+        128..=255 => "OEM",
+        _ => OUT_OF_SPEC,
+    }
+}
+
+const STRUCTURE_NAMES: &[&str] = &[
+    "BIOS Information",
+    "System Information",
+    "Base Board Information",
+    "Chassis Information",
+    "Processor Information",
+    "Memory Controller Information",
+    "Memory Module Information",
+    "Cache Information",
+    "Port Connector Information",
+    "System Slot Information",
+    "On Board Device Information",
+    "OEM Strings",
+    "System Configuration Options",
+    "BIOS Language Information",
+    "Group Associations",
+    "System Event Log",
+    "Physical Memory Array",
+    "Memory Device",
+    "32-bit Memory Error Information",
+    "Memory Array Mapped Address",
+    "Memory Device Mapped Address",
+    "Built-in Pointing Device",
+    "Portable Battery",
+    "System Reset",
+    "Hardware Security",
+    "System Power Controls",
+    "Voltage Probe",
+    "Cooling Device",
+    "Temperature Probe",
+    "Electrical Current Probe",
+    "Out-of-band Remote Access",
+    "Boot Integrity Services Entry Point",
+    "System Boot Information",
+    "64-bit Memory Error Information",
+    "Management Device",
+    "Management Device Component",
+    "Management Device Threshold Data",
+    "Memory Channel",
+    "IPMI Device Information",
+    "System Power Supply",
+    "Additional Information",
+    "Onboard Device",
+    "Management Controller Host Interface",
+    "TPM Device",
+    "Processor Additional Information",
+    "Firmware Inventory Information",
+    "String Property",
+];
+
+const STRUCTURE_TYPES: &[&str] = &[
+    "BIOS",
+    "System",
+    "Base Board",
+    "Chassis",
+    "Processor",
+    "Memory Controller",
+    "Memory Module",
+    "Cache",
+    "Port Connector",
+    "System Slots",
+    "On Board Devices",
+    "OEM Strings",
+    "System Configuration Options",
+    "BIOS Language",
+    "Group Associations",
+    "System Event Log",
+    "Physical Memory Array",
+    "Memory Device",
+    "32-bit Memory Error",
+    "Memory Array Mapped Address",
+    "Memory Device Mapped Address",
+    "Built-in Pointing Device",
+    "Portable Battery",
+    "System Reset",
+    "Hardware Security",
+    "System Power Controls",
+    "Voltage Probe",
+    "Cooling Device",
+    "Temperature Probe",
+    "Electrical Current Probe",
+    "Out-of-band Remote Access",
+    "Boot Integrity Services",
+    "System Boot",
+    "64-bit Memory Error",
+    "Management Device",
+    "Management Device Component",
+    "Management Device Threshold Data",
+    "Memory Channel",
+    "IPMI Device",
+    "Power Supply",
+    "Additional Information",
+    "Onboard Device",
+    "Management Controller Host Interface",
+    "TPM Device",
+    "Processor",
+    "Firmware",
+    "String Property",
+];
+
+/// BIOS Characteristics bits 4 through 31
+const BIOS_CHARACTERISTICS: &[&str] = &[
+    "ISA is supported",
+    "MCA is supported",
+    "EISA is supported",
+    "PCI is supported",
+    "PC Card (PCMCIA) is supported",
+    "PNP is supported",
+    "APM is supported",
+    "BIOS is upgradeable",
+    "BIOS shadowing is allowed",
+    "VLB is supported",
+    "ESCD support is available",
+    "Boot from CD is supported",
+    "Selectable boot is supported",
+    "BIOS ROM is socketed",
+    "Boot from PC Card (PCMCIA) is supported",
+    "EDD is supported",
+    "Japanese floppy for NEC 9800 1.2 MB is supported (int 13h)",
+    "Japanese floppy for Toshiba 1.2 MB is supported (int 13h)",
+    "5.25\"/360 kB floppy services are supported (int 13h)",
+    "5.25\"/1.2 MB floppy services are supported (int 13h)",
+    "3.5\"/720 kB floppy services are supported (int 13h)",
+    "3.5\"/2.88 MB floppy services are supported (int 13h)",
+    "Print screen service is supported (int 5h)",
+    "8042 keyboard services are supported (int 9h)",
+    "Serial services are supported (int 14h)",
+    "Printer services are supported (int 17h)",
+    "CGA/mono video services are supported (int 10h)",
+    "NEC PC-98",
+];
+
+const BIOS_CHARACTERISTICS_EXTENSION_0: &[&str] = &[
+    "ACPI is supported",
+    "USB legacy is supported",
+    "AGP is supported",
+    "I2O boot is supported",
+    "LS-120 boot is supported",
+    "ATAPI Zip drive boot is supported",
+    "IEEE 1394 boot is supported",
+    "Smart battery is supported",
+];
+
+const BIOS_CHARACTERISTICS_EXTENSION_1: &[&str] = &[
+    "BIOS boot specification is supported",
+    "Function key-initiated network boot is supported",
+    "Targeted content distribution is supported",
+    "UEFI is supported",
+    "System is a virtual machine",
+    "Manufacturing mode is supported",
+    "Manufacturing mode is enabled",
+];
+
+const BASEBOARD_FEATURES: &[&str] = &[
+    "Board is a hosting board",
+    "Board requires at least one daughter board",
+    "Board is removable",
+    "Board is replaceable",
+    "Board is hot swappable",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UndefinedStructTable;
+
+    #[test]
+    fn test_chassis_information() {
+        let struct_type3 = vec![
+            0x03, 0x1C, 0x03, 0x00, 0x01, 0x03, 0x02, 0x03, 0x04, 0x03, 0x03, 0x03, 0x03, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x85, 0x00, 0x02, 0x05, 0x00, 0x02, 0x05,
+            b'L', b'E', b'N', b'O', b'V', b'O', 0x00, b'N', b'o', b'n', b'e', 0x00, b'M', b'J',
+            b'0', b'6', b'U', b'R', b'D', b'Z', 0x00, b'4', b'0', b'8', b'9', b'9', b'8', b'5',
+            0x00, b'D', b'e', b'f', b'a', b'u', b'l', b't', b' ', b's', b't', b'r', b'i', b'n',
+            b'g', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type3);
+
+        assert_eq!(
+            DmiDecode::new(&parts).to_string(),
+            "Handle 0x0003, DMI type 3, 28 bytes\n\
+             Chassis Information\n\
+             \tManufacturer: LENOVO\n\
+             \tType: Desktop\n\
+             \tLock: Not Present\n\
+             \tVersion: None\n\
+             \tSerial Number: MJ06URDZ\n\
+             \tAsset Tag: 4089985\n\
+             \tBoot-up State: Safe\n\
+             \tPower Supply State: Safe\n\
+             \tThermal State: Safe\n\
+             \tSecurity Status: None\n\
+             \tOEM Information: 0x00000000\n\
+             \tHeight: Unspecified\n\
+             \tNumber Of Power Cords: 1\n\
+             \tContained Elements: 2\n\
+             \t\tMemory Controller (0-2)\n\
+             \t\tSystem Management Module (0-2)\n\
+             \tSKU Number: Default string\n"
+        );
+    }
+
+    #[test]
+    fn test_chassis_type_labels() {
+        // The chassis type is printed with its Display label, lock bit masked
+        for (chassis_type, expected) in [
+            (0x93, "\tType: Sub Chassis\n\tLock: Present\n"),
+            (0x7F, "\tType: <OUT OF SPEC>\n\tLock: Not Present\n"),
+        ] {
+            let parts =
+                UndefinedStruct::new(&[0x03, 0x06, 0x03, 0x00, 0x00, chassis_type, 0x00, 0x00]);
+            let text = DmiDecode::new(&parts).to_string();
+            assert!(text.contains(expected), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_bios_information() {
+        let struct_type0 = vec![
+            0x00, 0x18, 0x00, 0x00, 0x01, 0x02, 0x00, 0xF0, 0x03, 0xFF, 0x80, 0x98, 0x8B, 0x3F,
+            0x01, 0x00, 0x11, 0x00, 0x03, 0x0D, 0x00, 0x21, 0xFF, 0xFF, 0x4C, 0x45, 0x4E, 0x4F,
+            0x56, 0x4F, 0x00, 0x53, 0x30, 0x33, 0x4B, 0x54, 0x33, 0x33, 0x41, 0x00, 0x30, 0x38,
+            0x2F, 0x30, 0x36, 0x2F, 0x32, 0x30, 0x31, 0x39, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type0);
+
+        assert_eq!(
+            DmiDecode::new(&parts).to_string(),
+            "Handle 0x0000, DMI type 0, 24 bytes\n\
+             BIOS Information\n\
+             \tVendor: LENOVO\n\
+             \tVersion: S03KT33A\n\
+             \tRelease Date: 08/06/2019\n\
+             \tAddress: 0xF0000\n\
+             \tRuntime Size: 64 kB\n\
+             \tROM Size: 16 MB\n\
+             \tCharacteristics:\n\
+             \t\tPCI is supported\n\
+             \t\tBIOS is upgradeable\n\
+             \t\tBIOS shadowing is allowed\n\
+             \t\tBoot from CD is supported\n\
+             \t\tSelectable boot is supported\n\
+             \t\tBIOS ROM is socketed\n\
+             \t\tEDD is supported\n\
+             \t\t5.25\"/1.2 MB floppy services are supported (int 13h)\n\
+             \t\t3.5\"/720 kB floppy services are supported (int 13h)\n\
+             \t\t3.5\"/2.88 MB floppy services are supported (int 13h)\n\
+             \t\tPrint screen service is supported (int 5h)\n\
+             \t\t8042 keyboard services are supported (int 9h)\n\
+             \t\tSerial services are supported (int 14h)\n\
+             \t\tPrinter services are supported (int 17h)\n\
+             \t\tACPI is supported\n\
+             \t\tUSB legacy is supported\n\
+             \t\tBIOS boot specification is supported\n\
+             \t\tTargeted content distribution is supported\n\
+             \t\tUEFI is supported\n\
+             \tBIOS Revision: 0.33\n"
+        );
+    }
+
+    #[test]
+    fn test_unknown_structure_dump() {
+        let table = UndefinedStructTable::from(vec![
+            0xDD, 0x0C, 0x20, 0x00, 0x01, 0x01, 0x00, 0x01, 0x05, 0x00, 0x00, 0x01, b'O', b'E',
+            b'M', 0x07, 0x00,
+            0x00, // OEM-specific type with a control character in its string
+            0x7F, 0x04, 0x21, 0x00, 0x00, 0x00, // End-of-Table
+        ]);
+        let data = SMBiosData::new(table, Some(SMBiosVersion::new(3, 2, 0)));
+
+        assert_eq!(
+            DmiDecodeTable::new(&data).to_string(),
+            "SMBIOS 3.2.0 present.\n\
+             \n\
+             Handle 0x0020, DMI type 221, 12 bytes\n\
+             OEM-specific Type\n\
+             \tHeader and Data:\n\
+             \t\tDD 0C 20 00 01 01 00 01 05 00 00 01\n\
+             \tStrings:\n\
+             \t\tOEM.\n\
+             \n\
+             Handle 0x0021, DMI type 127, 4 bytes\n\
+             End Of Table\n\
+             \n"
+        );
+    }
+
+    #[test]
+    fn test_memory_size() {
+        assert_eq!(memory_size(64), "64 kB");
+        assert_eq!(memory_size(16320), "16320 kB");
+        assert_eq!(memory_size(16384), "16 MB");
+        assert_eq!(memory_size(32 * 1024 * 1024), "32 GB");
+    }
+}
@@ -77,4 +77,19 @@ mod tests {
             _ => panic!("Expected data!"),
         }
     }
+
+    #[test]
+    fn test_to_bytes_round_trip() {
+        let path = Path::new("./tests/jeffgerlap_3_2_0.dat");
+        let raw = read(path).unwrap();
+        let table_data = load_smbios_data_from_file(path).unwrap();
+
+        // The file has an 8 byte Windows header preceding the table
+        let bytes = table_data.to_bytes();
+        assert_eq!(bytes, raw[8..].to_vec());
+
+        let reloaded = SMBiosData::from_vec_and_version(bytes, None);
+        assert_eq!(reloaded.iter().count(), table_data.iter().count());
+        assert_eq!(reloaded.to_bytes(), table_data.to_bytes());
+    }
 }
@@ -4,29 +4,55 @@
 //!
 //! This library focuses on the tasks involved with reading and interpreting
 //! BIOS data.
+//!
+//! # Features
+//!
+//! - `std` (default): file and platform loading, entry point parsing and
+//!   [std::error::Error] implementations.
+//! - `alloc`: structure parsing, field accessors and enum decoders for
+//!   `no_std` environments such as UEFI applications. Build with
+//!   `--no-default-features --features alloc`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![deny(rust_2018_idioms)]
 
+#[cfg(not(feature = "alloc"))]
+compile_error!("smbios-lib requires the \"alloc\" feature (enabled by \"std\")");
+
+extern crate alloc;
+
 mod core;
+mod dmidecode;
+#[cfg(feature = "std")]
 mod file_io;
+#[cfg(feature = "std")]
 mod macos;
+mod prelude;
 mod structs;
+#[cfg(feature = "std")]
 mod unix;
+#[cfg(feature = "std")]
 mod windows;
 
 pub use structs::*;
 
 pub use crate::core::*;
+pub use dmidecode::*;
+#[cfg(feature = "std")]
 pub use file_io::*;
 
 #[cfg(target_family = "windows")]
 pub use windows::{load_windows_smbios_data, raw_smbios_from_device, table_load_from_device};
 
+#[cfg(feature = "std")]
 pub use windows::WinSMBiosData;
 
-#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+#[cfg(all(
+    feature = "std",
+    any(target_os = "linux", target_os = "android", target_os = "freebsd")
+))]
 pub use unix::*;
 
-#[cfg(any(target_os = "macos", target_os = "ios"))]
+#[cfg(all(feature = "std", any(target_os = "macos", target_os = "ios")))]
 pub use macos::*;
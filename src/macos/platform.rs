@@ -48,76 +48,63 @@ impl Drop for AppleSMBiosService {
     }
 }
 
-fn try_load_macos_entry_point() -> Result<SMBiosEntryPoint32, Error> {
-    let service = AppleSMBiosService::try_init()?;
-
-    unsafe {
-        let smbios_entry_point_name = CString::new("SMBIOS-EPS").expect("CString::new failed");
-
-        let option_bits: IOOptionBits = 0;
-        let data_ref = IORegistryEntryCreateCFProperty(
-            service.service_handle,
-            CFSTR(smbios_entry_point_name.as_ptr()),
-            kCFAllocatorDefault,
-            option_bits,
-        ) as CFDataRef;
-
-        if data_ref.is_null() {
-            return Err(Error::new(
-                ErrorKind::NotFound,
-                "SMBIOS-EPS entry point is unreachable",
-            ));
-        }
-
-        if !data_ref.is_null() {
-            CFRelease(data_ref.as_void_ptr());
-        }
+/// Copies the bytes of an IORegistry data property and releases the property
+///
+/// The bytes must be copied before the property is released because
+/// `CFDataGetBytePtr` points into the memory owned by the property.
+unsafe fn copy_and_release_data(data_ref: CFDataRef) -> Vec<u8> {
+    let data_ptr = CFDataGetBytePtr(data_ref);
+    let data_length = CFDataGetLength(data_ref);
+    let mut data: Vec<u8> = Vec::with_capacity(data_length as usize);
 
-        let data_ptr = CFDataGetBytePtr(data_ref);
-        let data_length = CFDataGetLength(data_ref);
-        let mut entry_point = Vec::with_capacity(data_length as usize);
+    std::ptr::copy(data_ptr, data.as_mut_ptr(), data_length as usize);
+    data.set_len(data_length as usize);
 
-        std::ptr::copy(data_ptr, entry_point.as_mut_ptr(), data_length as usize);
-        entry_point.set_len(data_length as usize);
+    CFRelease(data_ref.as_void_ptr());
 
-        SMBiosEntryPoint32::try_from(entry_point)
-    }
+    data
 }
 
-fn try_load_macos_table() -> Result<Vec<u8>, Error> {
+fn try_load_macos_property(name: &str) -> Result<Vec<u8>, Error> {
     let service = AppleSMBiosService::try_init()?;
 
     unsafe {
-        let smbios_table_name = CString::new("SMBIOS").expect("CString::new failed");
+        let property_name = CString::new(name).expect("CString::new failed");
 
         let option_bits: IOOptionBits = 0;
         let data_ref = IORegistryEntryCreateCFProperty(
             service.service_handle,
-            CFSTR(smbios_table_name.as_ptr()),
+            CFSTR(property_name.as_ptr()),
             kCFAllocatorDefault,
             option_bits,
         ) as CFDataRef;
 
         if data_ref.is_null() {
-            return Err(Error::new(ErrorKind::NotFound, "SMBIOS is unreachable"));
-        }
-
-        if !data_ref.is_null() {
-            CFRelease(data_ref.as_void_ptr());
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("{} is unreachable", name),
+            ));
         }
 
-        let data_ptr = CFDataGetBytePtr(data_ref);
-        let data_length = CFDataGetLength(data_ref);
-        let mut table: Vec<u8> = Vec::with_capacity(data_length as usize);
+        Ok(copy_and_release_data(data_ref))
+    }
+}
 
-        std::ptr::copy(data_ptr, table.as_mut_ptr(), data_length as usize);
-        table.set_len(data_length as usize);
+fn try_load_macos_entry_point() -> Result<SMBiosEntryPoint, Error> {
+    SMBiosEntryPoint::try_from(try_load_macos_property("SMBIOS-EPS")?)
+}
 
-        Ok(table)
-    }
+fn try_load_macos_table() -> Result<Vec<u8>, Error> {
+    try_load_macos_property("SMBIOS")
 }
 
 /// Loads SMBIOS table data ([SMBiosData]) from the device
+///
+/// Reads the `SMBIOS-EPS` (entry point) and `SMBIOS` (structure table)
+/// properties of the `AppleSMBIOS` IOService from the IORegistry. This
+/// requires the IOKit framework. Macs without SMBIOS firmware (such as
+/// Apple silicon) do not publish the service and return
+/// [ErrorKind::NotFound].
 pub fn table_load_from_device() -> Result<SMBiosData, Error> {
     let entry_point = try_load_macos_entry_point()?;
 
@@ -204,6 +204,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let output_option = "o";
     let string_option = "s";
     let json_option = "j";
+    let dmidecode_option = "d";
 
     let args: Vec<String> = std::env::args().collect();
     let mut opts = getopts::Options::new();
@@ -216,6 +217,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "KEYWORD",
     );
     opts.optflag(json_option, "", "output in json format");
+    opts.optflag(dmidecode_option, "", "output in dmidecode text format");
 
     let matches = opts.parse(&args[1..])?;
 
@@ -223,6 +225,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         && !matches.opt_present(output_option)
         && !matches.opt_present(string_option)
         && !matches.opt_present(json_option)
+        && !matches.opt_present(dmidecode_option)
     {
         println!("table_data: {:#?}", table_load_from_device()?);
         return Ok(());
@@ -258,6 +261,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("{}", output)
         }
     }
+    if matches.opt_present(dmidecode_option) {
+        let smbios_data = table_load_from_device()?;
+        print!("{}", DmiDecodeTable::new(&smbios_data));
+    }
 
     Ok(())
 }
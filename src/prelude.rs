@@ -0,0 +1,11 @@
+//! Items from `alloc` which the standard library prelude provides.
+//!
+//! Modules using these items import this prelude so that they also build
+//! without the standard library.
+
+pub use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
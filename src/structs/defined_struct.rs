@@ -1,8 +1,9 @@
 //! [DefinedStruct] and [DefinedStructTable] perform downcast operations
 //! via into() and into_iter() trait functions for [UndefinedStruct].
 
+use crate::prelude::*;
+use core::iter::FromIterator;
 use serde::Serialize;
-use std::iter::FromIterator;
 
 use crate::core::UndefinedStruct;
 
@@ -315,7 +316,7 @@ impl<'a> DefinedStructTable<'a> {
 
 impl<'a> IntoIterator for DefinedStructTable<'a> {
     type Item = DefinedStruct<'a>;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -1,4 +1,4 @@
-use crate::core::UndefinedStruct;
+use crate::core::{Handle, Header, SMBiosStructError, UndefinedStruct};
 
 /// # SMBIOS Structure
 ///
@@ -9,9 +9,36 @@ pub trait SMBiosStruct<'a> {
     /// Example: System Information (Type 1) this is set to 1.
     const STRUCT_TYPE: u8;
 
+    /// The minimum length of the structure's formatted section
+    ///
+    /// This is the length defined by the earliest SMBIOS version describing
+    /// the structure type.
+    const MIN_LENGTH: u8 = Header::SIZE as u8;
+
     /// Creates a new instance of the implementing SMBIOS type
     fn new(parts: &'a UndefinedStruct) -> Self;
 
     /// Contains the standard parts/sections of the implementing SMBIOS type.
     fn parts(&self) -> &'a UndefinedStruct;
+
+    /// The structure type read from the structure's [Header]
+    fn struct_type(&self) -> u8 {
+        self.parts().header.struct_type()
+    }
+
+    /// The length of the structure's formatted section read from the structure's [Header]
+    fn length(&self) -> u8 {
+        self.parts().header.length()
+    }
+
+    /// The handle of the structure read from the structure's [Header]
+    fn handle(&self) -> Handle {
+        self.parts().header.handle()
+    }
+
+    /// Validates the structure's header length against [SMBiosStruct::MIN_LENGTH]
+    /// and checks that the string-set terminator is present.
+    fn validate(&self) -> Result<(), SMBiosStructError> {
+        self.parts().validate(Self::MIN_LENGTH)
+    }
 }
@@ -1,7 +1,8 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
+use crate::prelude::*;
 use crate::structs::SMBiosStruct;
+use core::fmt;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Additional Information Entry contained within [SMBiosAdditionalInformation]
 pub struct AdditionalInformationEntry<'a> {
@@ -59,13 +60,15 @@ impl<'a> AdditionalInformationEntry<'a> {
         const VALUE_RELATIVE_OFFSET: usize = 5usize;
         let value_offset = self.entry_offset + VALUE_RELATIVE_OFFSET;
 
-        match self.entry_length() {
-            Some(entry_length) => {
-                let value_size = entry_length as usize - VALUE_RELATIVE_OFFSET;
-                self.additional_information
-                    .parts()
-                    .get_field_data(value_offset, value_offset + value_size)
-            }
+        // An entry_length shorter than the fixed fields is malformed and has no value
+        match self
+            .entry_length()
+            .and_then(|entry_length| (entry_length as usize).checked_sub(VALUE_RELATIVE_OFFSET))
+        {
+            Some(value_size) => self
+                .additional_information
+                .parts()
+                .get_field_data(value_offset, value_offset + value_size),
             None => None,
         }
     }
@@ -73,7 +76,7 @@ impl<'a> AdditionalInformationEntry<'a> {
 
 impl fmt::Debug for AdditionalInformationEntry<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosAdditionalInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<AdditionalInformationEntry<'_>>())
             .field("entry_length", &self.entry_length())
             .field("referenced_handle", &self.referenced_handle())
             .field("referenced_offset", &self.referenced_offset())
@@ -215,6 +218,7 @@ pub struct SMBiosAdditionalInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosAdditionalInformation<'a> {
     const STRUCT_TYPE: u8 = 40u8;
+    const MIN_LENGTH: u8 = 0x05;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -239,7 +243,7 @@ impl<'a> SMBiosAdditionalInformation<'a> {
 
 impl fmt::Debug for SMBiosAdditionalInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosAdditionalInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosAdditionalInformation<'_>>())
             .field("header", &self.parts.header)
             .field("number_of_entries", &self.number_of_entries())
             .field("entry_iterator", &self.entry_iterator())
@@ -264,6 +268,7 @@ impl Serialize for SMBiosAdditionalInformation<'_> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_additional_information() {
         let additional_information_bytes = vec![
@@ -325,4 +330,52 @@ mod tests {
 
         println!("additional_information: {:?}", additional_information);
     }
+
+    #[test]
+    fn test_entries_with_differing_value_lengths() {
+        let additional_information_bytes = vec![
+            0x28u8, 0x13, 0x03, 0x01, // header (offsets 0-3)
+            0x02, // 2 additional entries (offset 4)
+            0x06, 0x10, 0x00, 0x05, 0x01, 0xAB, // 1-byte value (offsets 5-0x0A)
+            0x08, 0x20, 0x00, 0x0C, 0x02, 0x01, 0x02,
+            0x03, // 3-byte value (offsets 0x0B-0x12)
+            b'O', b'n', b'e', 0x00, // string 1
+            b'T', b'w', b'o', 0x00, // string 2
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&additional_information_bytes);
+        let additional_information = SMBiosAdditionalInformation::new(&parts);
+
+        let entries: Vec<AdditionalInformationEntry<'_>> =
+            additional_information.entry_iterator().collect();
+        assert_eq!(entries.len(), 2);
+
+        assert_eq!(entries[0].entry_length(), Some(6));
+        assert_eq!(*entries[0].referenced_handle().unwrap(), 0x0010);
+        assert_eq!(entries[0].referenced_offset(), Some(0x05));
+        assert_eq!(entries[0].string().to_string(), "One".to_string());
+        assert_eq!(entries[0].value(), Some(&[0xABu8][..]));
+
+        assert_eq!(entries[1].entry_length(), Some(8));
+        assert_eq!(*entries[1].referenced_handle().unwrap(), 0x0020);
+        assert_eq!(entries[1].referenced_offset(), Some(0x0C));
+        assert_eq!(entries[1].string().to_string(), "Two".to_string());
+        assert_eq!(entries[1].value(), Some(&[0x01u8, 0x02, 0x03][..]));
+
+        // An entry shorter than its fixed fields has no value rather than underflowing
+        let additional_information_bytes = vec![
+            0x28u8, 0x09, 0x04, 0x01, // header (offsets 0-3)
+            0x01, // 1 additional entry (offset 4)
+            0x04, 0x10, 0x00, 0x05, // truncated entry (offsets 5-8)
+            0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&additional_information_bytes);
+        let additional_information = SMBiosAdditionalInformation::new(&parts);
+
+        let entry = additional_information.entry_iterator().next().unwrap();
+        assert_eq!(entry.entry_length(), Some(4));
+        assert!(entry.value().is_none());
+    }
 }
@@ -1,8 +1,9 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::prelude::*;
+use crate::{SMBiosData, SMBiosStruct, SMBiosSystemChassisInformation};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Baseboard (or Module) Information (Type 2)
 ///
@@ -15,6 +16,7 @@ pub struct SMBiosBaseboardInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBaseboardInformation<'a> {
     const STRUCT_TYPE: u8 = 2u8;
+    const MIN_LENGTH: u8 = 0x08;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -68,6 +70,14 @@ impl<'a> SMBiosBaseboardInformation<'a> {
         self.parts.get_field_handle(0x0B)
     }
 
+    /// The chassis referenced by [SMBiosBaseboardInformation::chassis_handle]
+    pub fn chassis_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosSystemChassisInformation<'b>> {
+        data.resolve(self.chassis_handle()?)
+    }
+
     /// Type of baseboard.
     pub fn board_type(&self) -> Option<BoardTypeData> {
         self.parts
@@ -88,7 +98,7 @@ impl<'a> SMBiosBaseboardInformation<'a> {
 
 impl fmt::Debug for SMBiosBaseboardInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosBaseboardInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosBaseboardInformation<'_>>())
             .field("header", &self.parts.header)
             .field("manufacturer", &self.manufacturer())
             .field("product", &self.product())
@@ -140,6 +150,7 @@ impl Serialize for SMBiosBaseboardInformation<'_> {
 }
 
 /// # Board Type Data
+#[derive(Clone, Copy)]
 pub struct BoardTypeData {
     /// Raw value
     ///
@@ -154,7 +165,7 @@ pub struct BoardTypeData {
 
 impl fmt::Debug for BoardTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<BoardTypeData>())
+        fmt.debug_struct(core::any::type_name::<BoardTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -177,7 +188,7 @@ impl fmt::Display for BoardTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             BoardType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -215,7 +226,7 @@ impl From<u8> for BoardTypeData {
 }
 
 /// # Board Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BoardType {
     /// Unknown
     Unknown,
@@ -247,6 +258,27 @@ pub enum BoardType {
     None,
 }
 
+impl fmt::Display for BoardType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BoardType::Unknown => "Unknown",
+            BoardType::Other => "Other",
+            BoardType::ServerBlade => "Server Blade",
+            BoardType::ConnectivitySwitch => "Connectivity Switch",
+            BoardType::SystemManagementModule => "System Management Module",
+            BoardType::ProcessorModule => "Processor Module",
+            BoardType::IOModule => "I/O Module",
+            BoardType::MemoryModule => "Memory Module",
+            BoardType::Daughterboard => "Daughter Board",
+            BoardType::Motherboard => "Motherboard",
+            BoardType::ProcessorMemoryModule => "Processor+Memory Module",
+            BoardType::ProcessorIOModule => "Processor+I/O Module",
+            BoardType::InterconnectBoard => "Interconnect Board",
+            BoardType::None => "Undefined",
+        })
+    }
+}
+
 /// # Baseboard Features
 #[derive(PartialEq, Eq)]
 pub struct BaseboardFeatures {
@@ -302,7 +334,7 @@ impl BaseboardFeatures {
 
 impl fmt::Debug for BaseboardFeatures {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<BaseboardFeatures>())
+        fmt.debug_struct(core::any::type_name::<BaseboardFeatures>())
             .field("raw", &self.raw)
             .field("hosting_board", &self.hosting_board())
             .field("requires_daughterboard", &self.requires_daughterboard())
@@ -424,6 +456,7 @@ impl<'a> Serialize for ObjectHandleIterator<'a> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_baseboard_information() {
         let baseboard_information_bytes = vec![
@@ -513,4 +546,75 @@ mod tests {
         // debug print test
         println!("baseboard_information: {:?}", baseboard_information);
     }
+
+    #[test]
+    fn test_feature_flags_and_truncated_handles() {
+        let baseboard_information_bytes = vec![
+            // struct_type(2), length(0x13), handle(0x11)
+            0x02, 0x13, 0x11, 0x00,
+            // no strings, feature_flags(0x1D), chassis_handle(0x03), board_type(0x0B),
+            // number_of_contained_object_handles(3) but room for only 2
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x1D, 0x00, 0x03, 0x00, 0x0B, 0x03,
+            // handle[0] == 0x0020, handle[1] == 0x0021
+            0x20, 0x00, 0x21, 0x00, // end of structure
+            0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&baseboard_information_bytes);
+        let baseboard_information = SMBiosBaseboardInformation::new(&parts);
+
+        let features = baseboard_information.feature_flags().unwrap();
+        assert!(features.hosting_board());
+        assert!(!features.requires_daughterboard());
+        assert!(features.is_removable());
+        assert!(features.is_replaceable());
+        assert!(features.is_hot_swappable());
+
+        assert_eq!(
+            *baseboard_information.board_type().unwrap(),
+            BoardType::ProcessorMemoryModule
+        );
+
+        // The declared count is larger than the structure; only the
+        // handles within the structure's length are returned.
+        let handles: Vec<u16> = baseboard_information
+            .contained_object_handle_iterator()
+            .map(|handle| *handle)
+            .collect();
+        assert_eq!(handles, vec![0x0020, 0x0021]);
+    }
+
+    #[test]
+    fn test_chassis_handle_resolved() {
+        use crate::StructureBuilder;
+
+        let mut table_data =
+            StructureBuilder::new(SMBiosSystemChassisInformation::STRUCT_TYPE, 0x0300)
+                .string("LENOVO")
+                .byte(0x23) // Tower
+                .build();
+        for (handle, chassis_handle) in
+            [(0x0200, 0x0300), (0x0201, 0x0200), (0x0202, 0x1234)].iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosBaseboardInformation::STRUCT_TYPE, *handle)
+                    .string("LENOVO")
+                    .bytes(&[0, 0, 0, 0, 0x09, 0])
+                    .handle(Handle(*chassis_handle))
+                    .byte(0x0A)
+                    .byte(0)
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let baseboards = data.collect::<SMBiosBaseboardInformation<'_>>();
+
+        let chassis = baseboards[0].chassis_handle_resolved(&data).unwrap();
+        assert_eq!(chassis.handle(), Handle(0x0300));
+        assert_eq!(chassis.manufacturer().to_string(), "LENOVO");
+
+        // References to a structure of another type or a missing handle
+        assert!(baseboards[1].chassis_handle_resolved(&data).is_none());
+        assert!(baseboards[2].chassis_handle_resolved(&data).is_none());
+    }
 }
@@ -1,8 +1,8 @@
 use crate::core::{strings::*, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{SMBiosStruct, SMBiosVersion};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// #  BIOS Information (Type 0)
 ///
@@ -15,6 +15,7 @@ pub struct SMBiosInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosInformation<'a> {
     const STRUCT_TYPE: u8 = 0u8;
+    const MIN_LENGTH: u8 = 0x12;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -90,6 +91,14 @@ impl<'a> SMBiosInformation<'a> {
             .map(|raw| BiosCharacteristics::from(raw))
     }
 
+    /// BIOS characteristics as the raw 8-byte field
+    ///
+    /// Bits 31:0 are decoded by [SMBiosInformation::characteristics], bits
+    /// 47:32 are reserved for the BIOS vendor and bits 63:48 for the system vendor.
+    pub fn raw_characteristics(&self) -> Option<u64> {
+        self.parts.get_field_qword(0xA)
+    }
+
     /// BIOS vendor reserved characteristics
     pub fn bios_vendor_reserved_characteristics(&self) -> Option<u16> {
         self.parts.get_field_word(0xE)
@@ -190,15 +199,34 @@ impl<'a> SMBiosInformation<'a> {
     /// represented as 0010h. A 48 GB device set
     /// would be represented as
     /// 0100_0000_0011_0000b or 4030h.
+    ///
+    /// None when the table version predates SMBIOS 3.1, which added this field.
     pub fn extended_rom_size(&self) -> Option<RomSize> {
+        if self.parts.version_predates(SMBiosVersion::new(3, 1, 0)) {
+            return None;
+        }
+
         self.parts
             .get_field_word(0x18)
             .map(|raw| RomSize::from(raw))
     }
+
+    /// BIOS ROM size in bytes
+    ///
+    /// Resolves [SMBiosInformation::rom_size] and, when it is
+    /// [RomSize::SeeExtendedRomSize], [SMBiosInformation::extended_rom_size].
+    /// None is returned if the size is redirected to an extended size which is
+    /// not present (pre-3.1 structures) or uses a reserved unit.
+    pub fn rom_size_bytes(&self) -> Option<u64> {
+        match self.rom_size()? {
+            RomSize::SeeExtendedRomSize => self.extended_rom_size()?.bytes(),
+            rom_size => rom_size.bytes(),
+        }
+    }
 }
 
 /// # BIOS ROM size
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum RomSize {
     /// Size of this rom in bytes
     Kilobytes(u16),
@@ -218,6 +246,31 @@ pub enum RomSize {
     SeeExtendedRomSize,
 }
 
+impl fmt::Display for RomSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RomSize::Kilobytes(size) => write!(f, "{} kB", size),
+            RomSize::Megabytes(size) => write!(f, "{} MB", size),
+            RomSize::Gigabytes(size) => write!(f, "{} GB", size),
+            RomSize::Undefined(raw) => write!(f, "Undefined ({:#06X})", raw),
+            RomSize::SeeExtendedRomSize => f.write_str("See Extended BIOS ROM Size"),
+        }
+    }
+}
+
+impl RomSize {
+    /// The size in bytes, or None for [RomSize::Undefined] and
+    /// [RomSize::SeeExtendedRomSize]
+    pub fn bytes(&self) -> Option<u64> {
+        match *self {
+            RomSize::Kilobytes(size) => Some(size as u64 * 1024),
+            RomSize::Megabytes(size) => Some(size as u64 * 1024 * 1024),
+            RomSize::Gigabytes(size) => Some(size as u64 * 1024 * 1024 * 1024),
+            RomSize::Undefined(_) | RomSize::SeeExtendedRomSize => None,
+        }
+    }
+}
+
 impl From<u16> for RomSize {
     fn from(raw: u16) -> Self {
         // Bits 15:14 Unit
@@ -252,7 +305,7 @@ impl From<u8> for RomSize {
 
 impl fmt::Debug for SMBiosInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosInformation<'_>>())
             .field("header", &self.parts.header)
             .field("vendor", &self.vendor())
             .field("version", &self.version())
@@ -522,7 +575,7 @@ impl BiosCharacteristics {
 
 impl fmt::Debug for BiosCharacteristics {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<BiosCharacteristics>())
+        fmt.debug_struct(core::any::type_name::<BiosCharacteristics>())
             .field("raw", &self.raw)
             .field("unknown", &self.unknown())
             .field(
@@ -730,7 +783,7 @@ impl BiosCharacteristicsExtension0 {
 
 impl fmt::Debug for BiosCharacteristicsExtension0 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<BiosCharacteristicsExtension0>())
+        fmt.debug_struct(core::any::type_name::<BiosCharacteristicsExtension0>())
             .field("raw", &self.raw)
             .field("acpi_is_supported", &self.acpi_is_supported())
             .field("usb_legacy_is_supported", &self.usb_legacy_is_supported())
@@ -863,7 +916,7 @@ impl BiosCharacteristicsExtension1 {
 
 impl fmt::Debug for BiosCharacteristicsExtension1 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<BiosCharacteristicsExtension1>())
+        fmt.debug_struct(core::any::type_name::<BiosCharacteristicsExtension1>())
             .field("raw", &self.raw)
             .field(
                 "bios_boot_specification_is_supported",
@@ -939,7 +992,10 @@ impl Serialize for BiosCharacteristicsExtension1 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::{DefinedStruct, SMBiosData};
 
+    #[cfg(feature = "std")]
     #[test]
     fn unit_test() {
         // BIOS Information structure is sensitive to BIOS specification versions
@@ -1046,4 +1102,166 @@ mod tests {
         let test_struct = SMBiosInformation::new(&parts);
         assert_eq!(test_struct.rom_size(), Some(RomSize::Kilobytes(16320)))
     }
+
+    #[test]
+    fn test_extended_rom_size_units() {
+        // A 16 MB device is 0010h
+        assert_eq!(RomSize::from(0x0010u16), RomSize::Megabytes(16));
+        // A 48 GB device is 4030h
+        assert_eq!(RomSize::from(0x4030u16), RomSize::Gigabytes(48));
+        // Bits 15:14 of 10b and 11b are reserved
+        assert_eq!(RomSize::from(0x8010u16), RomSize::Undefined(0x8010));
+        assert_eq!(RomSize::from(0xC010u16), RomSize::Undefined(0xC010));
+        // Legacy size byte: 64K * (n+1)
+        assert_eq!(RomSize::from(0x00u8), RomSize::Kilobytes(64));
+        assert_eq!(RomSize::from(0xFFu8), RomSize::SeeExtendedRomSize);
+    }
+
+    #[test]
+    fn test_extended_rom_size_version_gate() {
+        // 3.1 and later BIOS Information structure including _extended_rom_size()_
+        let table = vec![
+            0x00, 0x1A, 0x00, 0x00, 0x01, 0x02, 0x00, 0xF0, 0x03, 0xFF, 0x80, 0x98, 0x8B, 0x3F,
+            0x01, 0x00, 0x11, 0x00, 0x03, 0x0D, 0x00, 0x21, 0x11, 0x2D, 0x10, 0x00, 0x4C, 0x45,
+            0x4E, 0x4F, 0x56, 0x4F, 0x00, 0x53, 0x30, 0x33, 0x4B, 0x54, 0x33, 0x33, 0x41, 0x00,
+            0x30, 0x38, 0x2F, 0x30, 0x36, 0x2F, 0x32, 0x30, 0x31, 0x39, 0x00, 0x00, // Type 0
+            0x7F, 0x04, 0x01, 0x00, 0x00, 0x00, // End-of-Table
+        ];
+
+        let mut data =
+            SMBiosData::from_vec_and_version(table.clone(), Some(SMBiosVersion::new(3, 4, 0)));
+        let test_struct = data.first::<SMBiosInformation<'_>>().unwrap();
+        assert_eq!(
+            test_struct.extended_rom_size(),
+            Some(RomSize::Megabytes(16))
+        );
+        assert_eq!(test_struct.rom_size_bytes(), Some(16 * 1024 * 1024));
+
+        // The bytes are present but a 2.0 table predates the field
+        data.set_version(Some(SMBiosVersion::new(2, 0, 0)));
+        let test_struct = data.first::<SMBiosInformation<'_>>().unwrap();
+        assert_eq!(test_struct.extended_rom_size(), None);
+        assert_eq!(test_struct.rom_size_bytes(), None);
+        match data.iter().next().unwrap().defined_struct() {
+            DefinedStruct::Information(bios) => assert_eq!(bios.extended_rom_size(), None),
+            _ => panic!("expected BIOS Information"),
+        }
+
+        // 3.1 is the first version with the field
+        data.set_version(Some(SMBiosVersion::new(3, 0, 0)));
+        assert_eq!(
+            data.first::<SMBiosInformation<'_>>()
+                .unwrap()
+                .extended_rom_size(),
+            None
+        );
+        data.set_version(Some(SMBiosVersion::new(3, 1, 0)));
+        assert_eq!(
+            data.first::<SMBiosInformation<'_>>()
+                .unwrap()
+                .extended_rom_size(),
+            Some(RomSize::Megabytes(16))
+        );
+
+        // An unknown version does not hide the field
+        let data = SMBiosData::from_vec_and_version(table, None);
+        assert_eq!(
+            data.first::<SMBiosInformation<'_>>()
+                .unwrap()
+                .extended_rom_size(),
+            Some(RomSize::Megabytes(16))
+        );
+    }
+
+    #[test]
+    fn test_characteristics_flags() {
+        // Characteristics of a Lenovo desktop: 0011_0001_3F8B_9880h
+        let struct_type0 = vec![
+            0x00, 0x14, 0x00, 0x00, 0x01, 0x02, 0x00, 0xF0, 0x03, 0xFF, 0x80, 0x98, 0x8B, 0x3F,
+            0x01, 0x00, 0x11, 0x00, 0x03, 0x0D, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type0);
+        let test_struct = SMBiosInformation::new(&parts);
+
+        assert_eq!(
+            test_struct.raw_characteristics(),
+            Some(0x0011_0001_3F8B_9880)
+        );
+
+        let characteristics = test_struct.characteristics().unwrap();
+        assert!(!characteristics.unknown());
+        assert!(!characteristics.isa_supported());
+        assert!(characteristics.pci_supported());
+        assert!(!characteristics.plug_and_play_supported());
+        assert!(characteristics.bios_upgradeable());
+        assert!(characteristics.bios_shadowing_allowed());
+        assert!(characteristics.boot_from_cdsupported());
+        assert!(characteristics.selectable_boot_supported());
+        assert!(characteristics.edd_specification_supported());
+
+        let extension0 = test_struct.characteristics_extension0().unwrap();
+        assert!(extension0.acpi_is_supported());
+        assert!(extension0.usb_legacy_is_supported());
+        assert!(!extension0.agp_is_supported());
+
+        let extension1 = test_struct.characteristics_extension1().unwrap();
+        assert!(extension1.bios_boot_specification_is_supported());
+        assert!(!extension1.fkey_initiated_network_boot_is_supported());
+        assert!(extension1.targeted_content_distribution_is_supported());
+        assert!(extension1.uefi_specification_is_supported());
+        assert!(!extension1.smbios_table_describes_avirtual_machine());
+    }
+
+    #[test]
+    fn test_rom_size_bytes() {
+        let struct_type0 = |rom_size: u8, extended_rom_size: Option<u16>| {
+            let mut raw = vec![
+                0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x00, rom_size, 0x80, 0x98, 0x8B,
+                0x3F, 0x01, 0x00, 0x11, 0x00, 0x03, 0x0D, 0x00, 0x21, 0x11, 0x2D,
+            ];
+            if let Some(extended_rom_size) = extended_rom_size {
+                raw[1] = 0x1A;
+                raw.extend_from_slice(&extended_rom_size.to_le_bytes());
+            }
+            raw.extend_from_slice(&[0x00, 0x00]);
+            UndefinedStruct::new(&raw)
+        };
+
+        // The size byte reaches 64K * 255 (0xFE); 16 MB and above is 0xFF
+        let parts = struct_type0(0xFE, None);
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.rom_size_bytes(), Some(16_711_680));
+
+        let parts = struct_type0(0x00, Some(0x0000));
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.rom_size_bytes(), Some(64 * 1024));
+
+        // 16 MB and 32 MB in megabytes
+        let parts = struct_type0(0xFF, Some(0x0010));
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.rom_size_bytes(), Some(16 * 1024 * 1024));
+
+        let parts = struct_type0(0xFF, Some(0x0020));
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(
+            test_struct.extended_rom_size(),
+            Some(RomSize::Megabytes(32))
+        );
+        assert_eq!(test_struct.rom_size_bytes(), Some(32 * 1024 * 1024));
+
+        // 48 GB in gigabytes
+        let parts = struct_type0(0xFF, Some(0x4030));
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.rom_size_bytes(), Some(48 * 1024 * 1024 * 1024));
+
+        // Reserved unit, and a redirect without the extended field
+        let parts = struct_type0(0xFF, Some(0x8010));
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.rom_size_bytes(), None);
+
+        let parts = struct_type0(0xFF, None);
+        let test_struct = SMBiosInformation::new(&parts);
+        assert_eq!(test_struct.rom_size_bytes(), None);
+    }
 }
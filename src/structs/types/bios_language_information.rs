@@ -1,7 +1,7 @@
 use crate::{strings::*, SMBiosStruct, UndefinedStruct};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # BIOS Language Information (Type 13)
 ///
@@ -16,6 +16,7 @@ pub struct SMBiosBiosLanguageInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBiosLanguageInformation<'a> {
     const STRUCT_TYPE: u8 = 13u8;
+    const MIN_LENGTH: u8 = 0x16;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -48,14 +49,20 @@ impl<'a> SMBiosBiosLanguageInformation<'a> {
     }
 
     /// Iterable collection of the installable languages.
-    pub fn installable_langauges(&self) -> &SMBiosStringSet {
+    pub fn installable_languages(&self) -> &SMBiosStringSet {
         &self.parts.strings
     }
+
+    /// Iterable collection of the installable languages.
+    #[deprecated(note = "use installable_languages")]
+    pub fn installable_langauges(&self) -> &SMBiosStringSet {
+        self.installable_languages()
+    }
 }
 
 impl fmt::Debug for SMBiosBiosLanguageInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosBiosLanguageInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosBiosLanguageInformation<'_>>())
             .field("header", &self.parts.header)
             .field(
                 "number_of_installable_languages",
@@ -63,7 +70,7 @@ impl fmt::Debug for SMBiosBiosLanguageInformation<'_> {
             )
             .field("flags", &self.flags())
             .field("current_language", &self.current_language())
-            .field("installable_languages", &self.installable_langauges())
+            .field("installable_languages", &self.installable_languages())
             .finish()
     }
 }
@@ -81,13 +88,13 @@ impl Serialize for SMBiosBiosLanguageInformation<'_> {
         )?;
         state.serialize_field("flags", &self.flags())?;
         state.serialize_field("current_language", &self.current_language())?;
-        state.serialize_field("installable_languages", &self.installable_langauges())?;
+        state.serialize_field("installable_languages", &self.installable_languages())?;
         state.end()
     }
 }
 
 /// # Language Format
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LanguageFormat {
     /// Language strings use the abbreviated format.
     ///
@@ -99,6 +106,15 @@ pub enum LanguageFormat {
     Long,
 }
 
+impl fmt::Display for LanguageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LanguageFormat::Abbreviated => "Abbreviated",
+            LanguageFormat::Long => "Long",
+        })
+    }
+}
+
 /// # BIOS Language Flags
 #[derive(PartialEq, Eq)]
 pub struct BiosLanguageFlags {
@@ -133,7 +149,7 @@ impl BiosLanguageFlags {
 
 impl fmt::Debug for BiosLanguageFlags {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<BiosLanguageFlags>())
+        fmt.debug_struct(core::any::type_name::<BiosLanguageFlags>())
             .field("raw", &self.raw)
             .field("language_format", &self.language_format())
             .finish()
@@ -155,7 +171,9 @@ impl Serialize for BiosLanguageFlags {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_bios_language_information() {
         let bios_language_information_bytes = vec![
@@ -197,7 +215,7 @@ mod tests {
 
         // installable_languages tests
         let mut string_iterator = bios_language_information
-            .installable_langauges()
+            .installable_languages()
             .into_iter();
         let first_string = string_iterator.next().expect("has a first string").ok();
         assert_eq!(first_string, Some("en|US|iso8859-1".to_string()));
@@ -210,4 +228,38 @@ mod tests {
         // debug print test
         println!("bios_language_information: {:?}", bios_language_information);
     }
+
+    #[test]
+    fn test_current_language_index() {
+        let bios_language_information_bytes = vec![
+            0x0Du8, 0x16, 0x22, 0x00,
+            // number_of_installable_languages: Some(3), flags: Some(1) (abbreviated), current_language: 3
+            0x03, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x03, // "enUS", "frCA", "deDE"
+            b'e', b'n', b'U', b'S', 0x00, b'f', b'r', b'C', b'A', 0x00, b'd', b'e', b'D', b'E',
+            0x00, // end of structure
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&bios_language_information_bytes);
+        let bios_language_information = SMBiosBiosLanguageInformation::new(&parts);
+
+        assert_eq!(
+            bios_language_information.flags().unwrap().language_format(),
+            LanguageFormat::Abbreviated
+        );
+
+        // The 1-based index at 0x15 selects the third string
+        assert_eq!(
+            bios_language_information.current_language().to_string(),
+            "deDE".to_string()
+        );
+
+        let languages: Vec<String> = bios_language_information
+            .installable_languages()
+            .into_iter()
+            .filter_map(|language| language.ok())
+            .collect();
+        assert_eq!(languages, vec!["enUS", "frCA", "deDE"]);
+    }
 }
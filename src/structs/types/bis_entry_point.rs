@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 // The BIS (Boot Integrity Services) Entry Point structure is not defined in the SMBIOS DMTF document.
 // bisapi037.pdf, section 3.1.3
@@ -39,6 +39,7 @@ pub struct SMBiosBisEntryPoint<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBisEntryPoint<'a> {
     const STRUCT_TYPE: u8 = 31u8;
+    const MIN_LENGTH: u8 = 0x1C;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -97,7 +98,7 @@ impl<'a> SMBiosBisEntryPoint<'a> {
 
 impl fmt::Debug for SMBiosBisEntryPoint<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosBisEntryPoint<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosBisEntryPoint<'_>>())
             .field("header", &self.parts.header)
             .field("checksum", &self.checksum())
             .field("bis_entry_16", &self.bis_entry_16())
@@ -119,3 +120,37 @@ impl Serialize for SMBiosBisEntryPoint<'_> {
         state.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::DefinedStruct;
+
+    #[test]
+    fn unit_test() {
+        let struct_type31 = vec![
+            0x1F, 0x1C, 0x3C, 0x00, 0x44, 0x00, 0x00, 0x00, 0x34, 0x12, 0x00, 0xF0, 0x00, 0x00,
+            0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type31);
+        assert_eq!(parts.header.struct_type(), SMBiosBisEntryPoint::STRUCT_TYPE);
+
+        let test_struct = match DefinedStruct::from(&parts) {
+            DefinedStruct::BisEntryPoint(bis_entry_point) => bis_entry_point,
+            _ => panic!("Type 31 must be recognized as a BIS entry point"),
+        };
+
+        assert_eq!(test_struct.checksum(), Some(0x44));
+        assert_eq!(test_struct.bis_entry_16(), Some(0xF000_1234));
+        assert_eq!(test_struct.bis_entry_32(), Some(0x000F_0000));
+
+        // The checksum makes the formatted area sum to zero
+        let sum = struct_type31[..0x1C]
+            .iter()
+            .fold(0u8, |acc, b| acc.wrapping_add(*b));
+        assert_eq!(sum, 0);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Built-in Pointing Device (Type 21)
 ///
@@ -19,6 +19,7 @@ pub struct SMBiosBuiltInPointingDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBuiltInPointingDevice<'a> {
     const STRUCT_TYPE: u8 = 21u8;
+    const MIN_LENGTH: u8 = 0x07;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -53,7 +54,7 @@ impl<'a> SMBiosBuiltInPointingDevice<'a> {
 
 impl fmt::Debug for SMBiosBuiltInPointingDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosBuiltInPointingDevice<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosBuiltInPointingDevice<'_>>())
             .field("header", &self.parts.header)
             .field("device_type", &self.device_type())
             .field("interface", &self.interface())
@@ -91,7 +92,7 @@ pub struct PointingDeviceTypeData {
 
 impl fmt::Debug for PointingDeviceTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<PointingDeviceTypeData>())
+        fmt.debug_struct(core::any::type_name::<PointingDeviceTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -114,7 +115,7 @@ impl fmt::Display for PointingDeviceTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             PointingDeviceType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -128,7 +129,7 @@ impl Deref for PointingDeviceTypeData {
 }
 
 /// # Built-in Pointing Device Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PointingDeviceType {
     /// Other
     Other,
@@ -152,6 +153,23 @@ pub enum PointingDeviceType {
     None,
 }
 
+impl fmt::Display for PointingDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PointingDeviceType::Other => "Other",
+            PointingDeviceType::Unknown => "Unknown",
+            PointingDeviceType::Mouse => "Mouse",
+            PointingDeviceType::TrackBall => "Track Ball",
+            PointingDeviceType::TrackPoint => "Track Point",
+            PointingDeviceType::GlidePoint => "Glide Point",
+            PointingDeviceType::TouchPad => "Touch Pad",
+            PointingDeviceType::TouchScreen => "Touch Screen",
+            PointingDeviceType::OpticalSensor => "Optical Sensor",
+            PointingDeviceType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for PointingDeviceTypeData {
     fn from(raw: u8) -> Self {
         PointingDeviceTypeData {
@@ -187,7 +205,7 @@ pub struct PointingDeviceInterfaceData {
 
 impl fmt::Debug for PointingDeviceInterfaceData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<PointingDeviceInterfaceData>())
+        fmt.debug_struct(core::any::type_name::<PointingDeviceInterfaceData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -206,6 +224,15 @@ impl Serialize for PointingDeviceInterfaceData {
     }
 }
 
+impl fmt::Display for PointingDeviceInterfaceData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            PointingDeviceInterface::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{}", &self.value),
+        }
+    }
+}
+
 impl Deref for PointingDeviceInterfaceData {
     type Target = PointingDeviceInterface;
 
@@ -215,7 +242,7 @@ impl Deref for PointingDeviceInterfaceData {
 }
 
 /// # Built-in Pointing Device Interface
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PointingDeviceInterface {
     /// Other field
     Other,
@@ -251,6 +278,27 @@ pub enum PointingDeviceInterface {
     None,
 }
 
+impl fmt::Display for PointingDeviceInterface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PointingDeviceInterface::Other => "Other",
+            PointingDeviceInterface::Unknown => "Unknown",
+            PointingDeviceInterface::Serial => "Serial",
+            PointingDeviceInterface::PS2 => "PS/2",
+            PointingDeviceInterface::Infrared => "Infrared",
+            PointingDeviceInterface::HpHil => "HP-HIL",
+            PointingDeviceInterface::BusMouse => "Bus mouse",
+            PointingDeviceInterface::Adb => "ADB (Apple Desktop Bus)",
+            PointingDeviceInterface::BusMouseDB9 => "Bus mouse DB-9",
+            PointingDeviceInterface::BusMouseMicroDin => "Bus mouse micro-DIN",
+            PointingDeviceInterface::USB => "USB",
+            PointingDeviceInterface::I2C => "I2C",
+            PointingDeviceInterface::SPI => "SPI",
+            PointingDeviceInterface::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for PointingDeviceInterfaceData {
     fn from(raw: u8) -> Self {
         PointingDeviceInterfaceData {
@@ -278,6 +326,7 @@ impl From<u8> for PointingDeviceInterfaceData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -296,4 +345,28 @@ mod tests {
         );
         assert_eq!(test_struct.number_of_buttons(), Some(3));
     }
+
+    #[test]
+    fn test_usb_touchpad() {
+        let struct_type21 = vec![0x15, 0x07, 0x32, 0x00, 0x07, 0xA2, 0x02, 0x00, 0x00];
+
+        let parts = UndefinedStruct::new(&struct_type21);
+        let test_struct = SMBiosBuiltInPointingDevice::new(&parts);
+
+        let device_type = test_struct.device_type().unwrap();
+        assert_eq!(*device_type, PointingDeviceType::TouchPad);
+        assert_eq!(device_type.raw, 0x07);
+        let interface = test_struct.interface().unwrap();
+        assert_eq!(*interface, PointingDeviceInterface::USB);
+        assert_eq!(interface.to_string(), "USB".to_string());
+        assert_eq!(test_struct.number_of_buttons(), Some(2));
+
+        // Values unknown to this library fall back to None with the raw value
+        let device_type = PointingDeviceTypeData::from(0x42);
+        assert_eq!(*device_type, PointingDeviceType::None);
+        assert_eq!(device_type.to_string(), "66".to_string());
+        let interface = PointingDeviceInterfaceData::from(0x42);
+        assert_eq!(*interface, PointingDeviceInterface::None);
+        assert_eq!(interface.to_string(), "66".to_string());
+    }
 }
@@ -1,8 +1,8 @@
 use crate::core::{strings::*, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Cache Information (Type 7)
 ///
@@ -20,6 +20,7 @@ pub struct SMBiosCacheInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosCacheInformation<'a> {
     const STRUCT_TYPE: u8 = 7u8;
+    const MIN_LENGTH: u8 = 0x0F;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -111,11 +112,29 @@ impl<'a> SMBiosCacheInformation<'a> {
             .get_field_dword(0x17)
             .map(|raw| CacheMemorySize::from(raw))
     }
+
+    /// Maximum size that can be installed, resolved from
+    /// 'maximum_cache_size' or, when it is FFFFh, 'maximum_cache_size_2'
+    pub fn effective_maximum_cache_size(&self) -> Option<CacheMemorySize> {
+        match self.maximum_cache_size()? {
+            CacheMemorySize::SeeCacheSize2 => self.maximum_cache_size_2(),
+            size => Some(size),
+        }
+    }
+
+    /// Installed size, resolved from 'installed_size' or,
+    /// when it is FFFFh, 'installed_cache_size_2'
+    pub fn effective_installed_size(&self) -> Option<CacheMemorySize> {
+        match self.installed_size()? {
+            CacheMemorySize::SeeCacheSize2 => self.installed_cache_size_2(),
+            size => Some(size),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosCacheInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosCacheInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosCacheInformation<'_>>())
             .field("header", &self.parts.header)
             .field("socket_designation", &self.socket_designation())
             .field("cache_configuration", &self.cache_configuration())
@@ -157,7 +176,7 @@ impl Serialize for SMBiosCacheInformation<'_> {
 }
 
 /// # Maximum memory capacity, in kilobytes, for this cache item
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CacheMemorySize {
     /// Maximum memory capacity in Kilobytes
     Kilobytes(u64),
@@ -165,6 +184,15 @@ pub enum CacheMemorySize {
     SeeCacheSize2,
 }
 
+impl fmt::Display for CacheMemorySize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CacheMemorySize::Kilobytes(size) => write!(f, "{} kB", size),
+            CacheMemorySize::SeeCacheSize2 => f.write_str("See Cache Size 2"),
+        }
+    }
+}
+
 impl From<u16> for CacheMemorySize {
     fn from(raw: u16) -> Self {
         match raw {
@@ -203,7 +231,7 @@ pub struct CacheAssociativityData {
 
 impl fmt::Debug for CacheAssociativityData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<CacheAssociativityData>())
+        fmt.debug_struct(core::any::type_name::<CacheAssociativityData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -231,7 +259,7 @@ impl Deref for CacheAssociativityData {
 }
 
 /// # Cache Associativity
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CacheAssociativity {
     /// Other
     Other = 0x01,
@@ -265,6 +293,28 @@ pub enum CacheAssociativity {
     None,
 }
 
+impl fmt::Display for CacheAssociativity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CacheAssociativity::Other => "Other",
+            CacheAssociativity::Unknown => "Unknown",
+            CacheAssociativity::DirectMapped => "Direct Mapped",
+            CacheAssociativity::SetAssociative2Way => "2-way Set-Associative",
+            CacheAssociativity::SetAssociative4Way => "4-way Set-Associative",
+            CacheAssociativity::FullyAssociative => "Fully Associative",
+            CacheAssociativity::SetAssociative8Way => "8-way Set-Associative",
+            CacheAssociativity::SetAssociative16Way => "16-way Set-Associative",
+            CacheAssociativity::SetAssociative12Way => "12-way Set-Associative",
+            CacheAssociativity::SetAssociative24Way => "24-way Set-Associative",
+            CacheAssociativity::SetAssociative32Way => "32-way Set-Associative",
+            CacheAssociativity::SetAssociative48Way => "48-way Set-Associative",
+            CacheAssociativity::SetAssociative64Way => "64-way Set-Associative",
+            CacheAssociativity::SetAssociative20Way => "20-way Set-Associative",
+            CacheAssociativity::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for CacheAssociativityData {
     fn from(raw: u8) -> Self {
         CacheAssociativityData {
@@ -305,7 +355,7 @@ pub struct SystemCacheTypeData {
 
 impl fmt::Debug for SystemCacheTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SystemCacheTypeData>())
+        fmt.debug_struct(core::any::type_name::<SystemCacheTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -328,7 +378,7 @@ impl fmt::Display for SystemCacheTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             SystemCacheType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -342,7 +392,7 @@ impl Deref for SystemCacheTypeData {
 }
 
 /// # System Cache Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SystemCacheType {
     /// Other
     Other,
@@ -358,6 +408,19 @@ pub enum SystemCacheType {
     None,
 }
 
+impl fmt::Display for SystemCacheType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SystemCacheType::Other => "Other",
+            SystemCacheType::Unknown => "Unknown",
+            SystemCacheType::Instruction => "Instruction",
+            SystemCacheType::Data => "Data",
+            SystemCacheType::Unified => "Unified",
+            SystemCacheType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for SystemCacheTypeData {
     fn from(raw: u8) -> Self {
         SystemCacheTypeData {
@@ -389,7 +452,7 @@ pub struct ErrorCorrectionTypeData {
 
 impl fmt::Debug for ErrorCorrectionTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ErrorCorrectionTypeData>())
+        fmt.debug_struct(core::any::type_name::<ErrorCorrectionTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -412,7 +475,7 @@ impl fmt::Display for ErrorCorrectionTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ErrorCorrectionType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -426,7 +489,7 @@ impl Deref for ErrorCorrectionTypeData {
 }
 
 /// # System Cache Error Correction Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ErrorCorrectionType {
     /// Other
     Other = 0x01,
@@ -444,6 +507,20 @@ pub enum ErrorCorrectionType {
     None,
 }
 
+impl fmt::Display for ErrorCorrectionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorCorrectionType::Other => "Other",
+            ErrorCorrectionType::Unknown => "Unknown",
+            ErrorCorrectionType::NoCorrection => "None (No Correction)",
+            ErrorCorrectionType::Parity => "Parity",
+            ErrorCorrectionType::SingleBitEcc => "Single-bit ECC",
+            ErrorCorrectionType::MultiBitEcc => "Multi-bit ECC",
+            ErrorCorrectionType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ErrorCorrectionTypeData {
     fn from(raw: u8) -> Self {
         ErrorCorrectionTypeData {
@@ -521,7 +598,7 @@ impl SramTypes {
 
 impl fmt::Debug for SramTypes {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SramTypes>())
+        fmt.debug_struct(core::any::type_name::<SramTypes>())
             .field("raw", &self.raw)
             .field("other", &self.other())
             .field("unknown", &self.unknown())
@@ -634,7 +711,7 @@ impl CacheConfiguaration {
 
 impl fmt::Debug for CacheConfiguaration {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<CacheConfiguaration>())
+        fmt.debug_struct(core::any::type_name::<CacheConfiguaration>())
             .field("raw", &self.raw)
             .field("cache_level", &self.cache_level())
             .field("cache_socketed", &self.cache_socketed())
@@ -661,7 +738,7 @@ impl Serialize for CacheConfiguaration {
     }
 }
 /// # System Cache Location (relative to the CPU module)
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CacheLocation {
     /// Internal to the CPU
     Internal,
@@ -673,8 +750,19 @@ pub enum CacheLocation {
     Unknown,
 }
 
+impl fmt::Display for CacheLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CacheLocation::Internal => "Internal to the CPU",
+            CacheLocation::External => "External to the CPU",
+            CacheLocation::Reserved => "Reserved",
+            CacheLocation::Unknown => "Location Unknown",
+        })
+    }
+}
+
 /// # System Cache Operational Mode
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CacheOperationalMode {
     /// Write Through
     WriteThrough = 0x00,
@@ -686,10 +774,24 @@ pub enum CacheOperationalMode {
     Unknown = 0x03,
 }
 
+impl fmt::Display for CacheOperationalMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CacheOperationalMode::WriteThrough => "Write Through",
+            CacheOperationalMode::WriteBack => "Write Back",
+            CacheOperationalMode::VariesWithMemoryAddress => "Varies with Memory Address",
+            CacheOperationalMode::Unknown => "Unknown Operational Mode",
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::StructureBuilder;
 
+    #[cfg(feature = "std")]
     #[test]
     fn unit_test() {
         let struct_type7 = vec![
@@ -736,4 +838,96 @@ mod tests {
             CacheMemorySize::Kilobytes(2u64.pow(37) - 64)
         );
     }
+
+    #[test]
+    fn test_effective_cache_size() {
+        // Maximum and installed sizes are FFFFh, the 2 fields hold 2048 * 64K
+        let struct_type7 = vec![
+            0x07, 0x1B, 0x05, 0x00, 0x01, 0x82, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0x10, 0x00, 0x10,
+            0x00, 0x00, 0x06, 0x05, 0x08, 0x00, 0x08, 0x00, 0x80, 0x00, 0x08, 0x00, 0x80, b'L',
+            b'3', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type7);
+        let test_struct = SMBiosCacheInformation::new(&parts);
+
+        assert_eq!(
+            test_struct.maximum_cache_size(),
+            Some(CacheMemorySize::SeeCacheSize2)
+        );
+        assert_eq!(
+            test_struct.effective_maximum_cache_size(),
+            Some(CacheMemorySize::Kilobytes(131072))
+        );
+        assert_eq!(
+            test_struct.effective_installed_size(),
+            Some(CacheMemorySize::Kilobytes(131072))
+        );
+
+        // Without the redirect the 16 bit field is used
+        let struct_type7 = vec![
+            0x07, 0x1B, 0x03, 0x00, 0x01, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0x10, 0x00, 0x10,
+            0x00, 0x01, 0x06, 0x05, 0x07, 0x80, 0x01, 0x00, 0x00, 0x80, 0x01, 0x00, 0x00, 0x4C,
+            0x31, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type7);
+        let test_struct = SMBiosCacheInformation::new(&parts);
+        assert_eq!(
+            test_struct.effective_installed_size(),
+            Some(CacheMemorySize::Kilobytes(384))
+        );
+    }
+
+    #[test]
+    fn test_associativity_and_sram_type() {
+        // 8-way set-associative, unified, synchronous L2 cache
+        let struct_type7 = StructureBuilder::new(SMBiosCacheInformation::STRUCT_TYPE, 0x0004)
+            .string("L2") // 0x04 Socket Designation
+            .word(0x0181) // 0x05 Cache Configuration
+            .word(0x0200) // 0x07 Maximum Cache Size
+            .word(0x0200) // 0x09 Installed Size
+            .word(0x0022) // 0x0B Supported SRAM Type
+            .word(0x0020) // 0x0D Current SRAM Type
+            .byte(0x00) // 0x0F Cache Speed
+            .byte(0x05) // 0x10 Error Correction Type
+            .byte(0x05) // 0x11 System Cache Type
+            .byte(0x07) // 0x12 Associativity
+            .dword(0x0000_0200) // 0x13 Maximum Cache Size 2
+            .dword(0x0000_0200) // 0x17 Installed Cache Size 2
+            .build();
+
+        let parts = UndefinedStruct::new(&struct_type7);
+        let test_struct = SMBiosCacheInformation::new(&parts);
+
+        assert_eq!(test_struct.cache_configuration().unwrap().cache_level(), 2);
+        assert_eq!(
+            test_struct.associativity().unwrap().value,
+            CacheAssociativity::SetAssociative8Way
+        );
+        assert_eq!(
+            test_struct.system_cache_type().unwrap().value,
+            SystemCacheType::Unified
+        );
+        assert_eq!(
+            test_struct.error_correction_type().unwrap().value,
+            ErrorCorrectionType::SingleBitEcc
+        );
+
+        let supported = test_struct.supported_sram_type().unwrap();
+        assert!(supported.unknown());
+        assert!(supported.synchronous());
+        assert!(!supported.asynchronous());
+
+        let current = test_struct.current_sram_type().unwrap();
+        assert_eq!(*current, 0x0020);
+        assert!(current.synchronous());
+        assert!(!current.unknown());
+        assert!(!current.burst());
+
+        // Values beyond the specification fall back to None with the raw value kept
+        let associativity = CacheAssociativityData::from(0x20);
+        assert_eq!(associativity.value, CacheAssociativity::None);
+        assert_eq!(associativity.raw, 0x20);
+    }
 }
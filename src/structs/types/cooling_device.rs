@@ -1,7 +1,7 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{SMBiosData, SMBiosStruct, SMBiosTemperatureProbe};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Cooling Device (Type 27)
 ///
@@ -16,6 +16,7 @@ pub struct SMBiosCoolingDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosCoolingDevice<'a> {
     const STRUCT_TYPE: u8 = 27u8;
+    const MIN_LENGTH: u8 = 0x0C;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -35,6 +36,14 @@ impl<'a> SMBiosCoolingDevice<'a> {
         self.parts.get_field_handle(0x04)
     }
 
+    /// The temperature probe referenced by [SMBiosCoolingDevice::temperature_probe_handle]
+    pub fn temperature_probe_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosTemperatureProbe<'b>> {
+        data.resolve(self.temperature_probe_handle()?)
+    }
+
     /// Cooling device type and status.
     pub fn device_type_and_status(&self) -> Option<CoolingDeviceTypeAndStatus> {
         self.parts
@@ -79,7 +88,7 @@ impl<'a> SMBiosCoolingDevice<'a> {
 }
 
 /// # Rotational Speed
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum RotationalSpeed {
     /// Revolutions per minute (RPM)
     Rpm(u16),
@@ -87,6 +96,15 @@ pub enum RotationalSpeed {
     Unknown,
 }
 
+impl fmt::Display for RotationalSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RotationalSpeed::Rpm(rpm) => write!(f, "{} rpm", rpm),
+            RotationalSpeed::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for RotationalSpeed {
     fn from(raw: u16) -> Self {
         match raw {
@@ -98,7 +116,7 @@ impl From<u16> for RotationalSpeed {
 
 impl fmt::Debug for SMBiosCoolingDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosCoolingDevice<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosCoolingDevice<'_>>())
             .field("header", &self.parts.header)
             .field("temperature_probe_handle", &self.temperature_probe_handle())
             .field("device_type_and_status", &self.device_type_and_status())
@@ -145,7 +163,7 @@ pub struct CoolingDeviceTypeAndStatus {
 
 impl fmt::Debug for CoolingDeviceTypeAndStatus {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<CoolingDeviceTypeAndStatus>())
+        fmt.debug_struct(core::any::type_name::<CoolingDeviceTypeAndStatus>())
             .field("raw", &self.raw)
             .field("device_status", &self.device_status)
             .field("device_type", &self.device_type)
@@ -167,7 +185,7 @@ impl Serialize for CoolingDeviceTypeAndStatus {
 }
 
 /// # Cooling Device Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CoolingDeviceStatus {
     /// Other
     Other,
@@ -185,8 +203,22 @@ pub enum CoolingDeviceStatus {
     None,
 }
 
+impl fmt::Display for CoolingDeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CoolingDeviceStatus::Other => "Other",
+            CoolingDeviceStatus::Unknown => "Unknown",
+            CoolingDeviceStatus::OK => "OK",
+            CoolingDeviceStatus::NonCritical => "Non-critical",
+            CoolingDeviceStatus::Critical => "Critical",
+            CoolingDeviceStatus::NonRecoverable => "Non-recoverable",
+            CoolingDeviceStatus::None => "Undefined",
+        })
+    }
+}
+
 /// # Cooling Device Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CoolingDeviceType {
     /// Other
     Other,
@@ -214,6 +246,25 @@ pub enum CoolingDeviceType {
     None,
 }
 
+impl fmt::Display for CoolingDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CoolingDeviceType::Other => "Other",
+            CoolingDeviceType::Unknown => "Unknown",
+            CoolingDeviceType::Fan => "Fan",
+            CoolingDeviceType::CentrifugalBlower => "Centrifugal Blower",
+            CoolingDeviceType::ChipFan => "Chip Fan",
+            CoolingDeviceType::CabinetFan => "Cabinet Fan",
+            CoolingDeviceType::PowerSupplyFan => "Power Supply Fan",
+            CoolingDeviceType::HeatPipe => "Heat Pipe",
+            CoolingDeviceType::IntegratedRefrigeration => "Integrated Refrigeration",
+            CoolingDeviceType::ActiveCooling => "Active Cooling",
+            CoolingDeviceType::PassiveCooling => "Passive Cooling",
+            CoolingDeviceType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for CoolingDeviceTypeAndStatus {
     fn from(raw: u8) -> Self {
         CoolingDeviceTypeAndStatus {
@@ -248,6 +299,8 @@ impl From<u8> for CoolingDeviceTypeAndStatus {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::StructureBuilder;
 
     #[test]
     fn unit_test() {
@@ -282,4 +335,69 @@ mod tests {
             "Cooling Dev 1".to_string()
         );
     }
+
+    #[test]
+    fn test_pre_2_7_fan() {
+        // Length 0Eh: nominal speed present, description (2.7+) absent
+        let struct_type27 = vec![
+            0x1B, 0x0E, 0x2E, 0x00, 0xFF, 0xFF, 0x83, 0x00, 0x78, 0x56, 0x34, 0x12, 0xB0, 0x0F,
+            0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type27);
+        let test_struct = SMBiosCoolingDevice::new(&parts);
+
+        assert_eq!(*test_struct.temperature_probe_handle().unwrap(), 0xFFFF);
+        let device_type_and_status = test_struct.device_type_and_status().unwrap();
+        assert_eq!(
+            device_type_and_status.device_status,
+            CoolingDeviceStatus::NonCritical
+        );
+        assert_eq!(device_type_and_status.device_type, CoolingDeviceType::Fan);
+        assert_eq!(test_struct.cooling_unit_group(), Some(0));
+        assert_eq!(test_struct.oem_defined(), Some(0x1234_5678));
+        match test_struct.nominal_speed().unwrap() {
+            RotationalSpeed::Rpm(rpm) => assert_eq!(rpm, 4016),
+            RotationalSpeed::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.description().err() {
+            Some(SMBiosStringError::FieldOutOfBounds) => (),
+            other => panic!("expected FieldOutOfBounds, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_temperature_probe_handle_resolved() {
+        let mut table_data = StructureBuilder::new(SMBiosTemperatureProbe::STRUCT_TYPE, 0x1C00)
+            .string("CPU Thermal Probe")
+            .byte(0x63) // Processor, OK
+            .build();
+        for (handle, probe_handle) in [(0x1B00, 0x1C00), (0x1B01, 0x1B00), (0x1B02, 0x1234)].iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosCoolingDevice::STRUCT_TYPE, *handle)
+                    .handle(Handle(*probe_handle))
+                    .byte(0x67) // Fan, OK
+                    .byte(0)
+                    .dword(0)
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let cooling_devices = data.collect::<SMBiosCoolingDevice<'_>>();
+
+        let probe = cooling_devices[0]
+            .temperature_probe_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(probe.handle(), Handle(0x1C00));
+        assert_eq!(probe.description().to_string(), "CPU Thermal Probe");
+
+        // References to a structure of another type or a missing handle
+        assert!(cooling_devices[1]
+            .temperature_probe_handle_resolved(&data)
+            .is_none());
+        assert!(cooling_devices[2]
+            .temperature_probe_handle_resolved(&data)
+            .is_none());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::SMBiosStruct;
 use crate::{strings::*, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Electrical Current Probe (Type 29)
 ///
@@ -16,6 +16,7 @@ pub struct SMBiosElectricalCurrentProbe<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosElectricalCurrentProbe<'a> {
     const STRUCT_TYPE: u8 = 29u8;
+    const MIN_LENGTH: u8 = 0x14;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -89,7 +90,7 @@ impl<'a> SMBiosElectricalCurrentProbe<'a> {
 
 impl fmt::Debug for SMBiosElectricalCurrentProbe<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosElectricalCurrentProbe<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosElectricalCurrentProbe<'_>>())
             .field("header", &self.parts.header)
             .field("description", &self.description())
             .field("location_and_status", &self.location_and_status())
@@ -142,7 +143,7 @@ pub struct CurrentProbeLocationAndStatus {
 
 impl fmt::Debug for CurrentProbeLocationAndStatus {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<CurrentProbeLocationAndStatus>())
+        fmt.debug_struct(core::any::type_name::<CurrentProbeLocationAndStatus>())
             .field("raw", &self.raw)
             .field("status", &self.status)
             .field("location", &self.location)
@@ -164,7 +165,7 @@ impl Serialize for CurrentProbeLocationAndStatus {
 }
 
 /// # Electrical Current Probe Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CurrentProbeStatus {
     /// Other
     Other,
@@ -182,8 +183,39 @@ pub enum CurrentProbeStatus {
     None,
 }
 
+impl fmt::Display for CurrentProbeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CurrentProbeStatus::Other => "Other",
+            CurrentProbeStatus::Unknown => "Unknown",
+            CurrentProbeStatus::OK => "OK",
+            CurrentProbeStatus::NonCritical => "Non-critical",
+            CurrentProbeStatus::Critical => "Critical",
+            CurrentProbeStatus::NonRecoverable => "Non-recoverable",
+            CurrentProbeStatus::None => "Undefined",
+        })
+    }
+}
+
+impl CurrentProbeStatus {
+    /// The SMBIOS enumeration value of the status, the inverse of decoding
+    ///
+    /// Returns None for [CurrentProbeStatus::None] which has no enumeration value.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            CurrentProbeStatus::Other => Some(1),
+            CurrentProbeStatus::Unknown => Some(2),
+            CurrentProbeStatus::OK => Some(3),
+            CurrentProbeStatus::NonCritical => Some(4),
+            CurrentProbeStatus::Critical => Some(5),
+            CurrentProbeStatus::NonRecoverable => Some(6),
+            CurrentProbeStatus::None => None,
+        }
+    }
+}
+
 /// # Electrical Current Probe Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CurrentProbeLocation {
     /// Other
     Other,
@@ -211,6 +243,47 @@ pub enum CurrentProbeLocation {
     None,
 }
 
+impl fmt::Display for CurrentProbeLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CurrentProbeLocation::Other => "Other",
+            CurrentProbeLocation::Unknown => "Unknown",
+            CurrentProbeLocation::Processor => "Processor",
+            CurrentProbeLocation::Disk => "Disk",
+            CurrentProbeLocation::PeripheralBay => "Peripheral Bay",
+            CurrentProbeLocation::SystemManagementModule => "System Management Module",
+            CurrentProbeLocation::Motherboard => "Motherboard",
+            CurrentProbeLocation::MemoryModule => "Memory Module",
+            CurrentProbeLocation::ProcessorModule => "Processor Module",
+            CurrentProbeLocation::PowerUnit => "Power Unit",
+            CurrentProbeLocation::AddInCard => "Add-in Card",
+            CurrentProbeLocation::None => "Undefined",
+        })
+    }
+}
+
+impl CurrentProbeLocation {
+    /// The SMBIOS enumeration value of the location, the inverse of decoding
+    ///
+    /// Returns None for [CurrentProbeLocation::None] which has no enumeration value.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            CurrentProbeLocation::Other => Some(1),
+            CurrentProbeLocation::Unknown => Some(2),
+            CurrentProbeLocation::Processor => Some(3),
+            CurrentProbeLocation::Disk => Some(4),
+            CurrentProbeLocation::PeripheralBay => Some(5),
+            CurrentProbeLocation::SystemManagementModule => Some(6),
+            CurrentProbeLocation::Motherboard => Some(7),
+            CurrentProbeLocation::MemoryModule => Some(8),
+            CurrentProbeLocation::ProcessorModule => Some(9),
+            CurrentProbeLocation::PowerUnit => Some(10),
+            CurrentProbeLocation::AddInCard => Some(11),
+            CurrentProbeLocation::None => None,
+        }
+    }
+}
+
 impl From<u8> for CurrentProbeLocationAndStatus {
     fn from(raw: u8) -> Self {
         CurrentProbeLocationAndStatus {
@@ -243,7 +316,7 @@ impl From<u8> for CurrentProbeLocationAndStatus {
 }
 
 /// # Probe Amperage
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ProbeAmperage {
     /// Amperage in milliamps
     Milliamps(u16),
@@ -251,6 +324,17 @@ pub enum ProbeAmperage {
     Unknown,
 }
 
+impl fmt::Display for ProbeAmperage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ProbeAmperage::Milliamps(milliamps) => {
+                write!(f, "{:.3} A", milliamps as i16 as f32 / 1000.0)
+            }
+            ProbeAmperage::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for ProbeAmperage {
     fn from(raw: u16) -> Self {
         match raw {
@@ -261,7 +345,7 @@ impl From<u16> for ProbeAmperage {
 }
 
 /// # Current Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum CurrentProbeResolution {
     /// Resolution for the probe's reading in tenths of milliamps
     TenthsOfMilliamps(u16),
@@ -269,6 +353,17 @@ pub enum CurrentProbeResolution {
     Unknown,
 }
 
+impl fmt::Display for CurrentProbeResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CurrentProbeResolution::TenthsOfMilliamps(resolution) => {
+                write!(f, "{:.1} mA", resolution as f32 / 10.0)
+            }
+            CurrentProbeResolution::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for CurrentProbeResolution {
     fn from(raw: u16) -> Self {
         match raw {
@@ -279,7 +374,7 @@ impl From<u16> for CurrentProbeResolution {
 }
 
 /// # Current Probe Accuracy
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum CurrentProbeAccuracy {
     /// Accuracy for the probe's reading in 1/100th of a percent
     OneOneHundredthPercent(u16),
@@ -287,6 +382,17 @@ pub enum CurrentProbeAccuracy {
     Unknown,
 }
 
+impl fmt::Display for CurrentProbeAccuracy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CurrentProbeAccuracy::OneOneHundredthPercent(accuracy) => {
+                write!(f, "{:.2}%", accuracy as f32 / 100.0)
+            }
+            CurrentProbeAccuracy::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for CurrentProbeAccuracy {
     fn from(raw: u16) -> Self {
         match raw {
@@ -299,6 +405,7 @@ impl From<u16> for CurrentProbeAccuracy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -347,4 +454,121 @@ mod tests {
             ProbeAmperage::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_known_readings() {
+        let struct_type29 = vec![
+            0x1D, 0x16, 0x34, 0x00, 0x01, 0x67, 0xE8, 0x03, 0x0A, 0x00, 0x05, 0x00, 0x14, 0x00,
+            0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF4, 0x01, b'A', b'B', b'C', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type29);
+        let test_struct = SMBiosElectricalCurrentProbe::new(&parts);
+
+        match test_struct.maximum_value().unwrap() {
+            ProbeAmperage::Milliamps(milliamps) => assert_eq!(milliamps, 1000),
+            ProbeAmperage::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.minimum_value().unwrap() {
+            ProbeAmperage::Milliamps(milliamps) => assert_eq!(milliamps, 10),
+            ProbeAmperage::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.resolution().unwrap() {
+            CurrentProbeResolution::TenthsOfMilliamps(tenths) => assert_eq!(tenths, 5),
+            CurrentProbeResolution::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.tolerance().unwrap() {
+            ProbeAmperage::Milliamps(milliamps) => assert_eq!(milliamps, 20),
+            ProbeAmperage::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.accuracy().unwrap() {
+            CurrentProbeAccuracy::OneOneHundredthPercent(accuracy) => assert_eq!(accuracy, 100),
+            CurrentProbeAccuracy::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.nominal_value().unwrap() {
+            ProbeAmperage::Milliamps(milliamps) => assert_eq!(milliamps, 500),
+            ProbeAmperage::Unknown => panic!("expected a reading"),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_serialize() {
+        let struct_type29 = vec![
+            0x1D, 0x16, 0x33, 0x00, 0x01, 0x67, 0xE8, 0x03, 0x00, 0x80, 0x00, 0x80, 0x00, 0x80,
+            0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, b'A', b'B', b'C', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type29);
+        let test_struct = SMBiosElectricalCurrentProbe::new(&parts);
+
+        let json = serde_json::to_value(&test_struct).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "header": {"struct_type": 29, "length": 22, "handle": 51},
+                "description": "ABC",
+                "location_and_status": {"raw": 103, "status": "OK", "location": "Motherboard"},
+                "maximum_value": {"Milliamps": 1000},
+                "minimum_value": "Unknown",
+                "resolution": "Unknown",
+                "tolerance": "Unknown",
+                "accuracy": "Unknown",
+                "oem_defined": 0,
+                "nominal_value": "Unknown"
+            })
+        );
+    }
+
+    #[test]
+    fn test_code_round_trip() {
+        // Every status and location decoded from a raw byte encodes back to itself
+        for raw in 0..=u8::MAX {
+            let decoded = CurrentProbeLocationAndStatus::from(raw);
+            if decoded.status != CurrentProbeStatus::None {
+                let code = decoded.status.code().unwrap();
+                assert_eq!(
+                    CurrentProbeLocationAndStatus::from(code << 5).status,
+                    decoded.status
+                );
+            }
+            if decoded.location != CurrentProbeLocation::None {
+                let code = decoded.location.code().unwrap();
+                assert_eq!(
+                    CurrentProbeLocationAndStatus::from(code).location,
+                    decoded.location
+                );
+            }
+        }
+        assert_eq!(CurrentProbeStatus::None.code(), None);
+        assert_eq!(CurrentProbeLocation::None.code(), None);
+
+        let decoded = CurrentProbeLocationAndStatus::from(0x67);
+        assert_eq!(
+            decoded.status.code().unwrap() << 5 | decoded.location.code().unwrap(),
+            0x67
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let location_and_status = CurrentProbeLocationAndStatus::from(0b110_01011);
+        let status = location_and_status.status;
+        assert_eq!(status, CurrentProbeStatus::NonRecoverable);
+        assert_eq!(status.to_string(), "Non-recoverable");
+        assert_eq!(location_and_status.location.to_string(), "Add-in Card");
+        assert_eq!(CurrentProbeStatus::NonCritical.to_string(), "Non-critical");
+        assert_eq!(CurrentProbeLocation::None.to_string(), "Undefined");
+
+        assert_eq!(ProbeAmperage::Milliamps(1500).to_string(), "1.500 A");
+        assert_eq!(ProbeAmperage::Unknown.to_string(), "Unknown");
+        assert_eq!(
+            CurrentProbeResolution::TenthsOfMilliamps(5).to_string(),
+            "0.5 mA"
+        );
+        assert_eq!(
+            CurrentProbeAccuracy::OneOneHundredthPercent(150).to_string(),
+            "1.50%"
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # End-of-Table (Type 127)
 ///
@@ -35,7 +35,7 @@ impl<'a> SMBiosEndOfTable<'a> {}
 
 impl fmt::Debug for SMBiosEndOfTable<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosEndOfTable<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosEndOfTable<'_>>())
             .field("header", &self.parts.header)
             .finish()
     }
@@ -51,3 +51,59 @@ impl Serialize for SMBiosEndOfTable<'_> {
         state.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{DefinedStruct, SMBiosInactive, UndefinedStructTable};
+
+    #[test]
+    fn unit_test() {
+        let struct_type127 = vec![0x7F, 0x04, 0xFF, 0xFE, 0x00, 0x00];
+
+        let parts = UndefinedStruct::new(&struct_type127);
+        let test_struct = parts.as_type::<SMBiosEndOfTable<'_>>().unwrap();
+        assert_eq!(*test_struct.parts().header.handle(), 0xFEFF);
+        assert!(parts.as_type::<SMBiosInactive<'_>>().is_none());
+
+        match parts.defined_struct() {
+            DefinedStruct::EndOfTable(_) => (),
+            _ => panic!("Type 127 must downcast to End-of-Table"),
+        }
+    }
+
+    #[test]
+    fn test_table_classification() {
+        let table_bytes = vec![
+            0x7E, 0x04, 0x00, 0x01, 0x00, 0x00, // Inactive (Type 126)
+            0x7F, 0x04, 0x01, 0x01, 0x00, 0x00, // End-of-Table (Type 127)
+            0x01, 0x04, 0x02, 0x01, 0x00, 0x00, // Padding beyond the end of the table
+        ];
+
+        let table = UndefinedStructTable::from(table_bytes);
+        assert_eq!(table.iter().count(), 2);
+
+        let mut defined = table.iter().map(|parts| parts.defined_struct());
+        match defined.next() {
+            Some(DefinedStruct::Inactive(inactive)) => {
+                assert_eq!(*inactive.parts().header.handle(), 0x0100)
+            }
+            _ => panic!("Type 126 must downcast to Inactive"),
+        }
+        match defined.next() {
+            Some(DefinedStruct::EndOfTable(end_of_table)) => {
+                assert_eq!(*end_of_table.parts().header.handle(), 0x0101)
+            }
+            _ => panic!("Type 127 must downcast to End-of-Table"),
+        }
+        assert!(defined.next().is_none());
+
+        // The defined struct iterator stops at End-of-Table
+        assert_eq!(table.defined_struct_iter::<SMBiosInactive<'_>>().count(), 1);
+        assert_eq!(
+            table.defined_struct_iter::<SMBiosEndOfTable<'_>>().count(),
+            0
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Firmware Inventory Information (Type 45)
 ///
@@ -26,6 +27,7 @@ pub struct SMBiosFirmwareInventoryInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosFirmwareInventoryInformation<'a> {
     const STRUCT_TYPE: u8 = 45u8;
+    const MIN_LENGTH: u8 = 0x18;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -134,7 +136,7 @@ impl<'a> SMBiosFirmwareInventoryInformation<'a> {
 
 impl fmt::Debug for SMBiosFirmwareInventoryInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosFirmwareInventoryInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosFirmwareInventoryInformation<'_>>())
             .field("header", &self.parts.header)
             .field("firmware_component_name", &self.firmware_component_name())
             .field("firmware_version", &self.firmware_version())
@@ -196,7 +198,7 @@ impl Serialize for SMBiosFirmwareInventoryInformation<'_> {
 }
 
 /// # Size of Image in Bytes
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum FirmwareImageSize {
     /// Image Size is Unknown
     Unknown,
@@ -204,6 +206,15 @@ pub enum FirmwareImageSize {
     Bytes(u64),
 }
 
+impl fmt::Display for FirmwareImageSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FirmwareImageSize::Unknown => f.write_str("Unknown"),
+            FirmwareImageSize::Bytes(size) => write!(f, "{} bytes", size),
+        }
+    }
+}
+
 impl From<u64> for FirmwareImageSize {
     fn from(raw: u64) -> Self {
         match raw {
@@ -228,7 +239,7 @@ pub struct VersionFormatData {
 
 impl fmt::Debug for VersionFormatData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<VersionFormatData>())
+        fmt.debug_struct(core::any::type_name::<VersionFormatData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -251,7 +262,7 @@ impl fmt::Display for VersionFormatData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             VersionFormat::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -283,7 +294,7 @@ impl From<u8> for VersionFormatData {
 }
 
 /// # Version Format of [SMBiosFirmwareInventoryInformation]
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VersionFormat {
     /// The format is a free-form string that is implementation specific.
     ///
@@ -310,6 +321,19 @@ pub enum VersionFormat {
     None,
 }
 
+impl fmt::Display for VersionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VersionFormat::FreeForm => "Free-form",
+            VersionFormat::MajorMinor => "MAJOR.MINOR",
+            VersionFormat::HexidecimalString32 => "32-bit hexadecimal string",
+            VersionFormat::HexidecimalString64 => "64-bit hexadecimal string",
+            VersionFormat::VendorOemSpecific => "BIOS Vendor/OEM-specific",
+            VersionFormat::None => "Undefined",
+        })
+    }
+}
+
 /// # Firmware Id Format Data of [SMBiosFirmwareInventoryInformation].
 pub struct FirmwareIdFormatData {
     /// Raw value
@@ -325,7 +349,7 @@ pub struct FirmwareIdFormatData {
 
 impl fmt::Debug for FirmwareIdFormatData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<FirmwareIdFormatData>())
+        fmt.debug_struct(core::any::type_name::<FirmwareIdFormatData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -348,7 +372,7 @@ impl fmt::Display for FirmwareIdFormatData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             FirmwareIdFormat::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -378,7 +402,7 @@ impl From<u8> for FirmwareIdFormatData {
 }
 
 /// # Firmware Id Format of [SMBiosFirmwareInventoryInformation]
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum FirmwareIdFormat {
     /// The format is a free-form string that is implementation specific.
     ///
@@ -396,6 +420,17 @@ pub enum FirmwareIdFormat {
     None,
 }
 
+impl fmt::Display for FirmwareIdFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FirmwareIdFormat::FreeForm => "Free-form",
+            FirmwareIdFormat::UefiGuid => "UEFI GUID",
+            FirmwareIdFormat::VendorOemSpecific => "BIOS Vendor/OEM-specific",
+            FirmwareIdFormat::None => "Undefined",
+        })
+    }
+}
+
 /// # Firmware Inventory Characteristics of [SMBiosFirmwareInventoryInformation]
 #[derive(PartialEq, Eq)]
 pub struct FirmwareInventoryCharacteristics {
@@ -440,7 +475,7 @@ impl FirmwareInventoryCharacteristics {
 
 impl fmt::Debug for FirmwareInventoryCharacteristics {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<FirmwareInventoryCharacteristics>())
+        fmt.debug_struct(core::any::type_name::<FirmwareInventoryCharacteristics>())
             .field("raw", &self.raw)
             .field("updatable", &self.updatable())
             .field("write_protect", &self.write_protect())
@@ -476,7 +511,7 @@ pub struct FirmwareInventoryStateInformationData {
 
 impl fmt::Debug for FirmwareInventoryStateInformationData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<FirmwareInventoryStateInformationData>())
+        fmt.debug_struct(core::any::type_name::<FirmwareInventoryStateInformationData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -499,7 +534,7 @@ impl fmt::Display for FirmwareInventoryStateInformationData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             FirmwareInventoryStateInformation::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -532,7 +567,7 @@ impl From<u8> for FirmwareInventoryStateInformationData {
 }
 
 /// # Firmware Inventory State Information of [SMBiosFirmwareInventoryInformation]
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum FirmwareInventoryStateInformation {
     /// Other
     Other,
@@ -567,6 +602,22 @@ pub enum FirmwareInventoryStateInformation {
     None,
 }
 
+impl fmt::Display for FirmwareInventoryStateInformation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FirmwareInventoryStateInformation::Other => "Other",
+            FirmwareInventoryStateInformation::Unknown => "Unknown",
+            FirmwareInventoryStateInformation::Disabled => "Disabled",
+            FirmwareInventoryStateInformation::Enabled => "Enabled",
+            FirmwareInventoryStateInformation::Absent => "Absent",
+            FirmwareInventoryStateInformation::StandbyOffline => "Standby Offline",
+            FirmwareInventoryStateInformation::StandbySpare => "Standby Spare",
+            FirmwareInventoryStateInformation::UnavailableOffline => "Unavailable Offline",
+            FirmwareInventoryStateInformation::None => "Undefined",
+        })
+    }
+}
+
 /// # Associated Component Handle Iterator
 ///
 /// Iterates over the associated component handles contained within the [SMBiosFirmwareInventoryInformation] structure
@@ -656,7 +707,7 @@ impl<'a> Serialize for AssociatedComponentHandleIterator<'a> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
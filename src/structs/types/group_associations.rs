@@ -1,7 +1,8 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::fmt;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Group Associations (Type 14)
 ///
@@ -18,6 +19,7 @@ pub struct SMBiosGroupAssociations<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosGroupAssociations<'a> {
     const STRUCT_TYPE: u8 = 14u8;
+    const MIN_LENGTH: u8 = 0x05;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -59,7 +61,7 @@ impl<'a> SMBiosGroupAssociations<'a> {
 
 impl fmt::Debug for SMBiosGroupAssociations<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosGroupAssociations<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosGroupAssociations<'_>>())
             .field("header", &self.parts.header)
             .field("group_name", &self.group_name())
             .field("number_of_items", &self.number_of_items())
@@ -120,7 +122,7 @@ impl<'a> GroupAssociationItem<'a> {
 
 impl fmt::Debug for GroupAssociationItem<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<GroupAssociationItem<'_>>())
+        fmt.debug_struct(core::any::type_name::<GroupAssociationItem<'_>>())
             .field("struct_type", &self.struct_type())
             .field("item_handle", &self.item_handle())
             .finish()
@@ -232,6 +234,7 @@ impl<'a> Serialize for GroupAssociationItemIterator<'a> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn unit_test() {
         let struct_type14 = vec![
@@ -254,4 +257,64 @@ mod tests {
         assert_eq!(first_item.struct_type(), Some(221));
         assert_eq!(*first_item.item_handle().unwrap(), 91);
     }
+
+    #[test]
+    fn test_multiple_items() {
+        let struct_type14 = vec![
+            0x0E, 0x0E, 0x60, 0x00, 0x01, 0x04, 0x00, 0x04, 0x04, 0x01, 0x04, 0x07, 0x07, 0x00,
+            b'C', b'P', b'U', b's', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type14);
+        let test_struct = SMBiosGroupAssociations::new(&parts);
+
+        assert_eq!(test_struct.group_name().to_string(), "CPUs".to_string());
+        assert_eq!(test_struct.number_of_items(), Some(3));
+
+        let items: Vec<(Option<u8>, Option<u16>)> = test_struct
+            .item_iterator()
+            .map(|item| (item.struct_type(), item.item_handle().map(|handle| *handle)))
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                (Some(4), Some(0x0400)),
+                (Some(4), Some(0x0401)),
+                (Some(7), Some(0x0007))
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_group_name() {
+        // The group name references the second string of the string-set
+        let build = |group_name: u8| {
+            crate::StructureBuilder::new(SMBiosGroupAssociations::STRUCT_TYPE, 0x0061)
+                .string_ref(group_name) // 0x04 Group Name
+                .byte(0x04) // 0x05 Item Type: Processor
+                .handle(Handle(0x0400)) // 0x06 Item Handle
+                .add_string("OEM")
+                .add_string("CPUs")
+                .build()
+        };
+        let struct_type14 = build(2);
+
+        let parts = UndefinedStruct::new(&struct_type14);
+        let test_struct = SMBiosGroupAssociations::new(&parts);
+
+        assert_eq!(parts.get_field_byte(0x04), Some(2));
+        assert_eq!(test_struct.group_name().to_string(), "CPUs");
+        assert_eq!(test_struct.group_name().ok(), Some("CPUs".to_string()));
+        assert_eq!(parts.get_field_string_ref(0x04), Some("CPUs"));
+
+        let json = serde_json::to_value(&test_struct).unwrap();
+        assert_eq!(json["group_name"], "CPUs");
+
+        // A reference outside the string-set does not resolve
+        let struct_type14 = build(3);
+        let parts = UndefinedStruct::new(&struct_type14);
+        let test_struct = SMBiosGroupAssociations::new(&parts);
+        assert!(test_struct.group_name().is_err());
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Hardware Security (Type 24)
 ///
@@ -15,6 +15,7 @@ pub struct SMBiosHardwareSecurity<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosHardwareSecurity<'a> {
     const STRUCT_TYPE: u8 = 24u8;
+    const MIN_LENGTH: u8 = 0x05;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -36,7 +37,7 @@ impl<'a> SMBiosHardwareSecurity<'a> {
 
 impl fmt::Debug for SMBiosHardwareSecurity<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosHardwareSecurity<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosHardwareSecurity<'_>>())
             .field("header", &self.parts.header)
             .field(
                 "hardware_security_settings",
@@ -78,7 +79,7 @@ pub struct HardwareSecuritySettings {
 
 impl fmt::Debug for HardwareSecuritySettings {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<HardwareSecuritySettings>())
+        fmt.debug_struct(core::any::type_name::<HardwareSecuritySettings>())
             .field("raw", &self.raw)
             .field("power_on_password_status", &self.power_on_password_status)
             .field("keyboard_password_status", &self.keyboard_password_status)
@@ -110,7 +111,7 @@ impl Serialize for HardwareSecuritySettings {
 }
 
 /// # Hardware Security Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HardwareSecurityStatus {
     /// Disabled
     Disabled,
@@ -122,6 +123,17 @@ pub enum HardwareSecurityStatus {
     Unknown,
 }
 
+impl fmt::Display for HardwareSecurityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HardwareSecurityStatus::Disabled => "Disabled",
+            HardwareSecurityStatus::Enabled => "Enabled",
+            HardwareSecurityStatus::NotImplemented => "Not implemented",
+            HardwareSecurityStatus::Unknown => "Unknown status",
+        })
+    }
+}
+
 impl From<u8> for HardwareSecuritySettings {
     fn from(raw: u8) -> Self {
         HardwareSecuritySettings {
@@ -157,9 +169,11 @@ impl From<u8> for HardwareSecuritySettings {
         }
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -173,4 +187,51 @@ mod tests {
             Some(HardwareSecuritySettings::from(22))
         );
     }
+
+    #[test]
+    fn test_mixed_states() {
+        // 0x16: power-on disabled, keyboard enabled, administrator enabled,
+        // front panel reset not implemented
+        let struct_type24 = vec![0x18, 0x05, 0x24, 0x00, 0x16, 0x00, 0x00];
+
+        let parts = UndefinedStruct::new(&struct_type24);
+        let test_struct = SMBiosHardwareSecurity::new(&parts);
+
+        let settings = test_struct.hardware_security_settings().unwrap();
+        assert_eq!(
+            settings.power_on_password_status,
+            HardwareSecurityStatus::Disabled
+        );
+        assert_eq!(
+            settings.keyboard_password_status,
+            HardwareSecurityStatus::Enabled
+        );
+        assert_eq!(
+            settings.administrator_password_status,
+            HardwareSecurityStatus::Enabled
+        );
+        assert_eq!(
+            settings.front_panel_reset_status,
+            HardwareSecurityStatus::NotImplemented
+        );
+
+        // 0xE4: one of each state, highest bits first
+        let settings = HardwareSecuritySettings::from(0xE4);
+        assert_eq!(
+            settings.power_on_password_status,
+            HardwareSecurityStatus::Unknown
+        );
+        assert_eq!(
+            settings.keyboard_password_status,
+            HardwareSecurityStatus::NotImplemented
+        );
+        assert_eq!(
+            settings.administrator_password_status,
+            HardwareSecurityStatus::Enabled
+        );
+        assert_eq!(
+            settings.front_panel_reset_status,
+            HardwareSecurityStatus::Disabled
+        );
+    }
 }
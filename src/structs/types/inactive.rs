@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Inactive (Type 126)
 ///
@@ -31,7 +31,7 @@ impl<'a> SMBiosInactive<'a> {}
 
 impl fmt::Debug for SMBiosInactive<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosInactive<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosInactive<'_>>())
             .field("header", &self.parts.header)
             .finish()
     }
@@ -1,7 +1,7 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # IPMI Device Information (Type 38)
 ///
@@ -17,6 +17,7 @@ pub struct SMBiosIpmiDeviceInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosIpmiDeviceInformation<'a> {
     const STRUCT_TYPE: u8 = 38u8;
+    const MIN_LENGTH: u8 = 0x10;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -40,6 +41,16 @@ impl<'a> SMBiosIpmiDeviceInformation<'a> {
         self.parts.get_field_byte(0x05)
     }
 
+    /// Major IPMI specification revision (bits 7:4 of 'ipmi_specification_revision')
+    pub fn ipmi_specification_revision_major(&self) -> Option<u8> {
+        self.ipmi_specification_revision().map(|raw| raw >> 4)
+    }
+
+    /// Minor IPMI specification revision (bits 3:0 of 'ipmi_specification_revision')
+    pub fn ipmi_specification_revision_minor(&self) -> Option<u8> {
+        self.ipmi_specification_revision().map(|raw| raw & 0x0F)
+    }
+
     /// Slave address on the I2C bus of this BMC
     pub fn i2c_target_address(&self) -> Option<u8> {
         self.parts.get_field_byte(0x06)
@@ -74,11 +85,36 @@ impl<'a> SMBiosIpmiDeviceInformation<'a> {
     pub fn interrupt_number(&self) -> Option<u8> {
         self.parts.get_field_byte(0x11)
     }
+
+    /// Base address of the BMC, resolved from 'base_address' and the
+    /// LS-bit in 'base_address_modifier'
+    ///
+    /// Bit 0 of 'base_address' selects I/O space or memory-mapped and is
+    /// replaced by the LS-bit for the address itself. For SSIF the field
+    /// holds the SMBus slave address instead.
+    pub fn effective_base_address(&self) -> Option<IpmiBaseAddress> {
+        let base_address = self.base_address()?;
+        if *self.interface_type()? == IpmiInterfaceType::SMBusSystemInterface {
+            return Some(IpmiBaseAddress::SMBusSlaveAddress(
+                (base_address as u8) >> 1,
+            ));
+        }
+
+        let ls_address_bit = match self.base_address_modifier() {
+            Some(modifier) if modifier.ls_address_bit == AddressBit::One => 1,
+            _ => 0,
+        };
+        let address = (base_address & !1) | ls_address_bit;
+        match base_address & 1 {
+            1 => Some(IpmiBaseAddress::Io(address)),
+            _ => Some(IpmiBaseAddress::MemoryMapped(address)),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosIpmiDeviceInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosIpmiDeviceInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosIpmiDeviceInformation<'_>>())
             .field("header", &self.parts.header)
             .field("interface_type", &self.interface_type())
             .field(
@@ -115,7 +151,7 @@ impl Serialize for SMBiosIpmiDeviceInformation<'_> {
     }
 }
 
-/// # Electrical Current Probe Location and Status
+/// # Base Address Modifier
 #[derive(PartialEq, Eq)]
 pub struct BaseAddressModifier {
     /// Raw value
@@ -137,7 +173,7 @@ pub struct BaseAddressModifier {
 
 impl fmt::Debug for BaseAddressModifier {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<BaseAddressModifier>())
+        fmt.debug_struct(core::any::type_name::<BaseAddressModifier>())
             .field("raw", &self.raw)
             .field("register_spacing", &self.register_spacing)
             .field("ls_address_bit", &self.ls_address_bit)
@@ -164,8 +200,31 @@ impl Serialize for BaseAddressModifier {
     }
 }
 
+/// # IPMI Base Address
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IpmiBaseAddress {
+    /// Address in I/O space
+    Io(u64),
+    /// Memory-mapped address
+    MemoryMapped(u64),
+    /// SMBus slave address (SSIF)
+    SMBusSlaveAddress(u8),
+}
+
+impl fmt::Display for IpmiBaseAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            IpmiBaseAddress::Io(address) => write!(f, "{:#018X} (I/O)", address),
+            IpmiBaseAddress::MemoryMapped(address) => {
+                write!(f, "{:#018X} (Memory-mapped)", address)
+            }
+            IpmiBaseAddress::SMBusSlaveAddress(address) => write!(f, "{:#04X} (SMBus)", address),
+        }
+    }
+}
+
 /// # Register Spacing
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum RegisterSpacing {
     /// Interface registers are on successive byte boundaries.
     BoundaryByte,
@@ -177,8 +236,19 @@ pub enum RegisterSpacing {
     None,
 }
 
+impl fmt::Display for RegisterSpacing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RegisterSpacing::BoundaryByte => "Successive byte boundaries",
+            RegisterSpacing::Boundary32Bit => "32-bit boundaries",
+            RegisterSpacing::Boundary16Bit => "16-byte boundaries",
+            RegisterSpacing::None => "Undefined",
+        })
+    }
+}
+
 /// # LS-Bit for Addresses
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum AddressBit {
     /// Address bit 0 = 0b
     Zero,
@@ -186,11 +256,20 @@ pub enum AddressBit {
     One,
 }
 
+impl fmt::Display for AddressBit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AddressBit::Zero => "0",
+            AddressBit::One => "1",
+        })
+    }
+}
+
 /// # Interrupt Info
 ///
 /// Identifies the type and polarity of the interrupt
 /// associated with the IPMI system interface, if any
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum InterruptInfo {
     /// Interrupt information specified
     Specified,
@@ -198,8 +277,17 @@ pub enum InterruptInfo {
     NotSpecified,
 }
 
+impl fmt::Display for InterruptInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InterruptInfo::Specified => "Interrupt information specified",
+            InterruptInfo::NotSpecified => "Interrupt information not specified",
+        })
+    }
+}
+
 /// # Interrupt Polarity
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum InterruptPolarity {
     /// active high
     ActiveHigh,
@@ -207,8 +295,17 @@ pub enum InterruptPolarity {
     ActiveLow,
 }
 
+impl fmt::Display for InterruptPolarity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InterruptPolarity::ActiveHigh => "Active high",
+            InterruptPolarity::ActiveLow => "Active low",
+        })
+    }
+}
+
 /// # Interrupt Trigger Mode
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum InterruptTriggerMode {
     /// level
     Level,
@@ -216,6 +313,15 @@ pub enum InterruptTriggerMode {
     Edge,
 }
 
+impl fmt::Display for InterruptTriggerMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InterruptTriggerMode::Level => "Level",
+            InterruptTriggerMode::Edge => "Edge",
+        })
+    }
+}
+
 impl From<u8> for BaseAddressModifier {
     fn from(raw: u8) -> Self {
         BaseAddressModifier {
@@ -251,7 +357,7 @@ impl From<u8> for BaseAddressModifier {
 }
 
 /// # Baseboard Management Controller (BMC) interface type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum IpmiInterfaceType {
     /// Unknown
     Unknown,
@@ -267,6 +373,21 @@ pub enum IpmiInterfaceType {
     None,
 }
 
+impl fmt::Display for IpmiInterfaceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IpmiInterfaceType::Unknown => "Unknown",
+            IpmiInterfaceType::KeyboardControllerStyle => "KCS: Keyboard Controller Style",
+            IpmiInterfaceType::ServerManagementInterfaceChip => {
+                "SMIC: Server Management Interface Chip"
+            }
+            IpmiInterfaceType::BlockTransfer => "BT: Block Transfer",
+            IpmiInterfaceType::SMBusSystemInterface => "SSIF: SMBus System Interface",
+            IpmiInterfaceType::None => "Undefined",
+        })
+    }
+}
+
 /// # Baseboard Management Controller (BMC) interface type data
 pub struct IpmiInterfaceTypeData {
     /// Raw value
@@ -282,7 +403,7 @@ pub struct IpmiInterfaceTypeData {
 
 impl fmt::Debug for IpmiInterfaceTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<IpmiInterfaceType>())
+        fmt.debug_struct(core::any::type_name::<IpmiInterfaceType>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -305,7 +426,7 @@ impl fmt::Display for IpmiInterfaceTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             IpmiInterfaceType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -337,6 +458,7 @@ impl From<u8> for IpmiInterfaceTypeData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -375,4 +497,98 @@ mod tests {
             InterruptTriggerMode::Edge
         );
     }
+
+    #[test]
+    fn test_kcs_io_base_address() {
+        // KCS, IPMI 2.0, I/O base address 0xCA2 (stored as 0xCA3 with the
+        // LS-bit of the address in the modifier, here 0), byte spacing
+        let struct_type38 = vec![
+            0x26, 0x12, 0x25, 0x00, 0x01, 0x20, 0x20, 0xFF, 0xA3, 0x0C, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type38);
+        let test_struct = SMBiosIpmiDeviceInformation::new(&parts);
+
+        assert_eq!(test_struct.ipmi_specification_revision_major(), Some(2));
+        assert_eq!(test_struct.ipmi_specification_revision_minor(), Some(0));
+        assert_eq!(
+            test_struct.effective_base_address(),
+            Some(IpmiBaseAddress::Io(0xCA2))
+        );
+
+        let base_address_modifier = test_struct.base_address_modifier().unwrap();
+        assert_eq!(
+            base_address_modifier.register_spacing,
+            RegisterSpacing::BoundaryByte
+        );
+        assert_eq!(base_address_modifier.ls_address_bit, AddressBit::Zero);
+        assert_eq!(test_struct.interrupt_number(), Some(0));
+
+        // Memory-mapped with the LS-bit set in the modifier
+        let struct_type38 = vec![
+            0x26,
+            0x12,
+            0x26,
+            0x00,
+            0x03,
+            0x15,
+            0x20,
+            0xFF,
+            0x00,
+            0x00,
+            0xC0,
+            0xFE,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0b0101_1011,
+            0x0A,
+            0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type38);
+        let test_struct = SMBiosIpmiDeviceInformation::new(&parts);
+
+        assert_eq!(test_struct.ipmi_specification_revision_major(), Some(1));
+        assert_eq!(test_struct.ipmi_specification_revision_minor(), Some(5));
+        assert_eq!(
+            test_struct.effective_base_address(),
+            Some(IpmiBaseAddress::MemoryMapped(0xFEC0_0001))
+        );
+        let base_address_modifier = test_struct.base_address_modifier().unwrap();
+        assert_eq!(
+            base_address_modifier.register_spacing,
+            RegisterSpacing::Boundary32Bit
+        );
+        assert_eq!(
+            base_address_modifier.interrupt_info,
+            InterruptInfo::Specified
+        );
+        assert_eq!(
+            base_address_modifier.interrupt_polarity,
+            InterruptPolarity::ActiveHigh
+        );
+        assert_eq!(
+            base_address_modifier.interrupt_trigger_mode,
+            InterruptTriggerMode::Level
+        );
+        assert_eq!(test_struct.interrupt_number(), Some(0x0A));
+
+        // SSIF holds the SMBus slave address
+        let struct_type38 = vec![
+            0x26, 0x10, 0x27, 0x00, 0x04, 0x20, 0x20, 0xFF, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type38);
+        let test_struct = SMBiosIpmiDeviceInformation::new(&parts);
+
+        assert_eq!(
+            test_struct.effective_base_address(),
+            Some(IpmiBaseAddress::SMBusSlaveAddress(0x10))
+        );
+    }
 }
@@ -1,7 +1,8 @@
+use crate::prelude::*;
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Management Controller Host Interface (Type 42)
 ///
@@ -25,6 +26,7 @@ pub struct SMBiosManagementControllerHostInterface<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementControllerHostInterface<'a> {
     const STRUCT_TYPE: u8 = 42u8;
+    const MIN_LENGTH: u8 = 0x06;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -93,7 +95,7 @@ impl<'a> SMBiosManagementControllerHostInterface<'a> {
 
 impl fmt::Debug for SMBiosManagementControllerHostInterface<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<
+        fmt.debug_struct(core::any::type_name::<
             SMBiosManagementControllerHostInterface<'_>,
         >())
         .field("header", &self.parts.header)
@@ -147,7 +149,7 @@ impl Serialize for SMBiosManagementControllerHostInterface<'_> {
 /// 40h: Network Host Interface - Refer to [DSP0270](https://www.dmtf.org/sites/default/files/DSP0270_1.0.1.pdf) for the definition and details of the Network Host Interface type
 /// F0h: OEM-defined
 /// All others: Reserved
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HostInterfaceType {
     /// KCS: Keyboard Controller Style
     ///
@@ -177,6 +179,23 @@ pub enum HostInterfaceType {
     None,
 }
 
+impl fmt::Display for HostInterfaceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HostInterfaceType::KeyboardControllerStyle => "KCS: Keyboard Controller Style",
+            HostInterfaceType::Uart8250 => "8250 UART Register Compatible",
+            HostInterfaceType::Uart16450 => "16450 UART Register Compatible",
+            HostInterfaceType::Uart16550 => "16550/16550A UART Register Compatible",
+            HostInterfaceType::Uart16650 => "16650/16650A UART Register Compatible",
+            HostInterfaceType::Uart16750 => "16750/16750A UART Register Compatible",
+            HostInterfaceType::Uart16850 => "16850/16850A UART Register Compatible",
+            HostInterfaceType::NetworkHostInterface => "Redfish Network Host Interface",
+            HostInterfaceType::OemDefined => "OEM Defined",
+            HostInterfaceType::None => "Undefined",
+        })
+    }
+}
+
 /// # Management Controller Host Interface Type Data
 pub struct HostInterfaceTypeData {
     /// Raw value
@@ -192,7 +211,7 @@ pub struct HostInterfaceTypeData {
 
 impl fmt::Debug for HostInterfaceTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<HostInterfaceType>())
+        fmt.debug_struct(core::any::type_name::<HostInterfaceType>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -215,7 +234,7 @@ impl fmt::Display for HostInterfaceTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             HostInterfaceType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -255,7 +274,7 @@ impl From<u8> for HostInterfaceTypeData {
 }
 
 /// # Management Controller Host Interface - Protocol Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HostProtocolType {
     /// IPMI: Intelligent Platform Management Interface
     ///
@@ -275,6 +294,18 @@ pub enum HostProtocolType {
     None,
 }
 
+impl fmt::Display for HostProtocolType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HostProtocolType::Ipmi => "IPMI: Intelligent Platform Management Interface",
+            HostProtocolType::Mctp => "MCTP: Management Component Transport Protocol",
+            HostProtocolType::RedfishOverIP => "Redfish over IP",
+            HostProtocolType::OemDefined => "OEM Defined",
+            HostProtocolType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for HostProtocolType {
     fn from(raw: u8) -> Self {
         match raw {
@@ -311,7 +342,7 @@ impl From<u8> for HostProtocolTypeData {
 
 impl fmt::Debug for HostProtocolTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<HostProtocolTypeData>())
+        fmt.debug_struct(core::any::type_name::<HostProtocolTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -334,7 +365,7 @@ impl fmt::Display for HostProtocolTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             HostProtocolType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -396,7 +427,7 @@ impl<'a> ProtocolRecord<'a> {
 
 impl fmt::Debug for ProtocolRecord<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProtocolRecord<'_>>())
+        fmt.debug_struct(core::any::type_name::<ProtocolRecord<'_>>())
             .field("protocol_type", &self.protocol_type())
             .field(
                 "protocol_type_specific_data_length",
@@ -573,4 +604,85 @@ mod tests {
         assert_eq!(*second.protocol_type().unwrap(), HostProtocolType::Ipmi);
         assert!(iterator.next().is_none());
     }
+
+    #[test]
+    fn test_redfish_over_ip() {
+        // Redfish over IP protocol-specific data (DSP0270)
+        let mut redfish_over_ip = Vec::new();
+        // service UUID
+        redfish_over_ip.extend_from_slice(&[0x11; 16]);
+        // host IP assignment type (static), host IP address format (IPv4)
+        redfish_over_ip.extend_from_slice(&[0x01, 0x01]);
+        // host IP address and mask
+        redfish_over_ip.extend_from_slice(&[169, 254, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        redfish_over_ip.extend_from_slice(&[255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        // service IP discovery type (static), service IP address format (IPv4)
+        redfish_over_ip.extend_from_slice(&[0x01, 0x01]);
+        // service IP address and mask
+        redfish_over_ip.extend_from_slice(&[169, 254, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        redfish_over_ip.extend_from_slice(&[255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        // service IP port (443), service VLAN ID (0)
+        redfish_over_ip.extend_from_slice(&[0xBB, 0x01, 0x00, 0x00, 0x00, 0x00]);
+        // service hostname length and hostname
+        redfish_over_ip.push(5);
+        redfish_over_ip.extend_from_slice(b"bmc01");
+
+        let specific_data_length = redfish_over_ip.len() as u8;
+        let mut struct_type42 = vec![
+            // header; length is patched below
+            42u8,
+            0x00,
+            0x25,
+            0x00,
+            // Network Host Interface, 9 bytes of interface specific data:
+            // PCI/PCIe device type, vendor, device, subsystem vendor and subsystem IDs
+            0x40,
+            0x09,
+            0x02,
+            0x86,
+            0x80,
+            0x34,
+            0x12,
+            0x86,
+            0x80,
+            0x78,
+            0x56,
+            // one protocol record: Redfish over IP
+            0x01,
+            0x04,
+            specific_data_length,
+        ];
+        struct_type42.extend_from_slice(&redfish_over_ip);
+        struct_type42[1] = struct_type42.len() as u8;
+        struct_type42.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type42);
+        let test_struct = SMBiosManagementControllerHostInterface::new(&parts);
+
+        assert_eq!(
+            *test_struct.interface_type().unwrap(),
+            HostInterfaceType::NetworkHostInterface
+        );
+        assert_eq!(test_struct.interface_type_specific_data_length(), Some(9));
+        assert_eq!(
+            test_struct.interface_type_specific_data(),
+            Some(&[0x02u8, 0x86, 0x80, 0x34, 0x12, 0x86, 0x80, 0x78, 0x56] as &[u8])
+        );
+        assert_eq!(test_struct.number_of_protocol_records(), Some(1));
+
+        let records: Vec<ProtocolRecord<'_>> = test_struct.protocol_record_iterator().collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            *records[0].protocol_type().unwrap(),
+            HostProtocolType::RedfishOverIP
+        );
+        assert_eq!(
+            records[0].protocol_type_specific_data_length(),
+            Some(specific_data_length)
+        );
+        assert_eq!(
+            records[0].protocol_type_specific_data(),
+            Some(redfish_over_ip.as_slice())
+        );
+    }
 }
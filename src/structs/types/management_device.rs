@@ -1,8 +1,8 @@
 use crate::core::{strings::*, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Management Device (Type 34)
 ///
@@ -17,6 +17,7 @@ pub struct SMBiosManagementDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementDevice<'a> {
     const STRUCT_TYPE: u8 = 34u8;
+    const MIN_LENGTH: u8 = 0x0B;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -55,7 +56,7 @@ impl<'a> SMBiosManagementDevice<'a> {
 
 impl fmt::Debug for SMBiosManagementDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosManagementDevice<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosManagementDevice<'_>>())
             .field("header", &self.parts.header)
             .field("description", &self.description())
             .field("device_type", &self.device_type())
@@ -95,7 +96,7 @@ pub struct ManagementDeviceTypeData {
 
 impl fmt::Debug for ManagementDeviceTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ManagementDeviceTypeData>())
+        fmt.debug_struct(core::any::type_name::<ManagementDeviceTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -118,7 +119,7 @@ impl fmt::Display for ManagementDeviceTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ManagementDeviceType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -132,7 +133,7 @@ impl Deref for ManagementDeviceTypeData {
 }
 
 /// # Management Device - Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ManagementDeviceType {
     /// Other
     Other,
@@ -164,6 +165,27 @@ pub enum ManagementDeviceType {
     None,
 }
 
+impl fmt::Display for ManagementDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ManagementDeviceType::Other => "Other",
+            ManagementDeviceType::Unknown => "Unknown",
+            ManagementDeviceType::NationalSemiconductorLM75 => "National Semiconductor LM75",
+            ManagementDeviceType::NationalSemiconductorLM78 => "National Semiconductor LM78",
+            ManagementDeviceType::NationalSemiconductorLM79 => "National Semiconductor LM79",
+            ManagementDeviceType::NationalSemiconductorLM80 => "National Semiconductor LM80",
+            ManagementDeviceType::NationalSemiconductorLM81 => "National Semiconductor LM81",
+            ManagementDeviceType::AnalogDevicesADM9240 => "Analog Devices ADM9240",
+            ManagementDeviceType::DallasSemiconductorDS1780 => "Dallas Semiconductor DS1780",
+            ManagementDeviceType::Maxim1617 => "Maxim 1617",
+            ManagementDeviceType::GenesysGL518SM => "Genesys GL518SM",
+            ManagementDeviceType::WinbondW83781D => "Winbond W83781D",
+            ManagementDeviceType::HoltekHT82H791 => "Holtek HT82H791",
+            ManagementDeviceType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ManagementDeviceTypeData {
     fn from(raw: u8) -> Self {
         ManagementDeviceTypeData {
@@ -203,7 +225,7 @@ pub struct ManagementDeviceAddressTypeData {
 
 impl fmt::Debug for ManagementDeviceAddressTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ManagementDeviceAddressTypeData>())
+        fmt.debug_struct(core::any::type_name::<ManagementDeviceAddressTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -226,7 +248,7 @@ impl fmt::Display for ManagementDeviceAddressTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ManagementDeviceAddressType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -240,7 +262,7 @@ impl Deref for ManagementDeviceAddressTypeData {
 }
 
 /// # Management Device — Address Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ManagementDeviceAddressType {
     /// Other
     Other,
@@ -256,6 +278,19 @@ pub enum ManagementDeviceAddressType {
     None,
 }
 
+impl fmt::Display for ManagementDeviceAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ManagementDeviceAddressType::Other => "Other",
+            ManagementDeviceAddressType::Unknown => "Unknown",
+            ManagementDeviceAddressType::IOPort => "I/O Port",
+            ManagementDeviceAddressType::Memory => "Memory",
+            ManagementDeviceAddressType::SMBus => "SM Bus",
+            ManagementDeviceAddressType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ManagementDeviceAddressTypeData {
     fn from(raw: u8) -> Self {
         ManagementDeviceAddressTypeData {
@@ -275,6 +310,7 @@ impl From<u8> for ManagementDeviceAddressTypeData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -1,7 +1,9 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{
+    SMBiosData, SMBiosManagementDevice, SMBiosManagementDeviceThresholdData, SMBiosStruct,
+};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Management Device Component (Type 35)
 ///
@@ -17,6 +19,7 @@ pub struct SMBiosManagementDeviceComponent<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementDeviceComponent<'a> {
     const STRUCT_TYPE: u8 = 35u8;
+    const MIN_LENGTH: u8 = 0x0B;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -38,6 +41,14 @@ impl<'a> SMBiosManagementDeviceComponent<'a> {
         self.parts.get_field_handle(0x05)
     }
 
+    /// The management device referenced by [SMBiosManagementDeviceComponent::management_device_handle]
+    pub fn management_device_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosManagementDevice<'b>> {
+        data.resolve(self.management_device_handle()?)
+    }
+
     /// Handle, or instance number, of the probe or cooling device that defines this component
     pub fn component_handle(&self) -> Option<Handle> {
         self.parts.get_field_handle(0x07)
@@ -50,11 +61,19 @@ impl<'a> SMBiosManagementDeviceComponent<'a> {
     pub fn threshold_handle(&self) -> Option<Handle> {
         self.parts.get_field_handle(0x09)
     }
+
+    /// The threshold data referenced by [SMBiosManagementDeviceComponent::threshold_handle]
+    pub fn threshold_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosManagementDeviceThresholdData<'b>> {
+        data.resolve(self.threshold_handle()?)
+    }
 }
 
 impl fmt::Debug for SMBiosManagementDeviceComponent<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosManagementDeviceComponent<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosManagementDeviceComponent<'_>>())
             .field("header", &self.parts.header)
             .field("description", &self.description())
             .field("management_device_handle", &self.management_device_handle())
@@ -82,6 +101,11 @@ impl Serialize for SMBiosManagementDeviceComponent<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::{
+        ManagementDeviceType, SMBiosManagementDevice, SMBiosManagementDeviceThresholdData,
+        SMBiosTemperatureProbe, StructureBuilder, UndefinedStructTable,
+    };
 
     #[test]
     fn unit_test() {
@@ -101,4 +125,102 @@ mod tests {
         assert_eq!(*test_struct.component_handle().unwrap(), 39);
         assert_eq!(*test_struct.threshold_handle().unwrap(), 40);
     }
+
+    #[test]
+    fn test_handle_cross_references() {
+        let mut table_data = vec![
+            // Type 34 management device, handle 0x0026
+            0x22, 0x0B, 0x26, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x00, 0x03, b'L', b'M', b'7',
+            b'8', 0x00, 0x00, // Type 28 temperature probe, handle 0x0027
+            0x1C, 0x16, 0x27, 0x00, 0x00, 0x63, 0xE8, 0x03, 0x00, 0x00, 0x7D, 0x00, 0x0A, 0x00,
+            0x32, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0x01, 0x00, 0x00,
+            // Type 36 threshold data, handle 0x0028
+            0x24, 0x10, 0x28, 0x00, 0x00, 0x80, 0x52, 0x03, 0x00, 0x80, 0x84, 0x03, 0x00, 0x80,
+            0xB6, 0x03, 0x00, 0x00,
+        ];
+        // Type 35 component, handle 0x0029, referencing the three above
+        table_data.extend_from_slice(&[
+            0x23, 0x0B, 0x29, 0x00, 0x01, 0x26, 0x00, 0x27, 0x00, 0x28, 0x00, b'C', b'P', b'U',
+            0x00, 0x00,
+        ]);
+        let table = UndefinedStructTable::from(table_data);
+
+        let component = table
+            .first::<SMBiosManagementDeviceComponent<'_>>()
+            .unwrap();
+
+        let management_device = table
+            .find_by_handle(&component.management_device_handle().unwrap())
+            .and_then(|parts| parts.as_type::<SMBiosManagementDevice<'_>>())
+            .unwrap();
+        assert_eq!(
+            *management_device.device_type().unwrap(),
+            ManagementDeviceType::NationalSemiconductorLM78
+        );
+
+        assert!(table
+            .find_by_handle(&component.component_handle().unwrap())
+            .and_then(|parts| parts.as_type::<SMBiosTemperatureProbe<'_>>())
+            .is_some());
+
+        let threshold_data = table
+            .find_by_handle(&component.threshold_handle().unwrap())
+            .and_then(|parts| parts.as_type::<SMBiosManagementDeviceThresholdData<'_>>())
+            .unwrap();
+        assert_eq!(threshold_data.known_upper_threshold_critical(), Some(900));
+    }
+
+    #[test]
+    fn test_handles_resolved() {
+        let mut table_data = StructureBuilder::new(SMBiosManagementDevice::STRUCT_TYPE, 0x2200)
+            .string("LM78")
+            .byte(0x03) // National Semiconductor LM78
+            .dword(0x0000_0000)
+            .byte(0x03) // I/O Port
+            .build();
+        table_data.extend(
+            StructureBuilder::new(SMBiosManagementDeviceThresholdData::STRUCT_TYPE, 0x2400)
+                .word(0x8000)
+                .word(0x8000)
+                .word(0x8000)
+                .word(0x8000)
+                .word(0x8000)
+                .word(0x8000)
+                .build(),
+        );
+
+        // A device and threshold data which resolve, handles of another
+        // structure type, and missing handles
+        for (handle, management_device, threshold) in [
+            (0x2300, 0x2200, 0x2400),
+            (0x2301, 0x2400, 0x2200),
+            (0x2302, 0x1234, 0x1235),
+        ]
+        .iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosManagementDeviceComponent::STRUCT_TYPE, *handle)
+                    .string("Component")
+                    .handle(Handle(*management_device))
+                    .handle(Handle(0xFFFF))
+                    .handle(Handle(*threshold))
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let components = data.collect::<SMBiosManagementDeviceComponent<'_>>();
+
+        let management_device = components[0]
+            .management_device_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(management_device.handle(), Handle(0x2200));
+        assert_eq!(management_device.description().to_string(), "LM78");
+        let threshold = components[0].threshold_handle_resolved(&data).unwrap();
+        assert_eq!(threshold.handle(), Handle(0x2400));
+
+        for component in &components[1..] {
+            assert!(component.management_device_handle_resolved(&data).is_none());
+            assert!(component.threshold_handle_resolved(&data).is_none());
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Management Device Threshold Data (Type 36)
 ///
@@ -21,6 +21,7 @@ pub struct SMBiosManagementDeviceThresholdData<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementDeviceThresholdData<'a> {
     const STRUCT_TYPE: u8 = 36u8;
+    const MIN_LENGTH: u8 = 0x10;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -61,31 +62,70 @@ impl<'a> SMBiosManagementDeviceThresholdData<'a> {
     pub fn upper_threshold_non_recoverable(&self) -> Option<u16> {
         self.parts.get_field_word(0x0E)
     }
+
+    /// 'lower_threshold_non_critical', or None when it is unavailable (8000h)
+    pub fn known_lower_threshold_non_critical(&self) -> Option<u16> {
+        self.lower_threshold_non_critical().and_then(Self::known)
+    }
+
+    /// 'upper_threshold_non_critical', or None when it is unavailable (8000h)
+    pub fn known_upper_threshold_non_critical(&self) -> Option<u16> {
+        self.upper_threshold_non_critical().and_then(Self::known)
+    }
+
+    /// 'lower_threshold_critical', or None when it is unavailable (8000h)
+    pub fn known_lower_threshold_critical(&self) -> Option<u16> {
+        self.lower_threshold_critical().and_then(Self::known)
+    }
+
+    /// 'upper_threshold_critical', or None when it is unavailable (8000h)
+    pub fn known_upper_threshold_critical(&self) -> Option<u16> {
+        self.upper_threshold_critical().and_then(Self::known)
+    }
+
+    /// 'lower_threshold_non_recoverable', or None when it is unavailable (8000h)
+    pub fn known_lower_threshold_non_recoverable(&self) -> Option<u16> {
+        self.lower_threshold_non_recoverable().and_then(Self::known)
+    }
+
+    /// 'upper_threshold_non_recoverable', or None when it is unavailable (8000h)
+    pub fn known_upper_threshold_non_recoverable(&self) -> Option<u16> {
+        self.upper_threshold_non_recoverable().and_then(Self::known)
+    }
+
+    fn known(raw: u16) -> Option<u16> {
+        match raw {
+            0x8000 => None,
+            _ => Some(raw),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosManagementDeviceThresholdData<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosManagementDeviceThresholdData<'_>>())
-            .field("header", &self.parts.header)
-            .field(
-                "lower_threshold_non_critical",
-                &self.lower_threshold_non_critical(),
-            )
-            .field(
-                "upper_threshold_non_critical",
-                &self.upper_threshold_non_critical(),
-            )
-            .field("lower_threshold_critical", &self.lower_threshold_critical())
-            .field("upper_threshold_critical", &self.upper_threshold_critical())
-            .field(
-                "lower_threshold_non_recoverable",
-                &self.lower_threshold_non_recoverable(),
-            )
-            .field(
-                "upper_threshold_non_recoverable",
-                &self.upper_threshold_non_recoverable(),
-            )
-            .finish()
+        fmt.debug_struct(core::any::type_name::<
+            SMBiosManagementDeviceThresholdData<'_>,
+        >())
+        .field("header", &self.parts.header)
+        .field(
+            "lower_threshold_non_critical",
+            &self.lower_threshold_non_critical(),
+        )
+        .field(
+            "upper_threshold_non_critical",
+            &self.upper_threshold_non_critical(),
+        )
+        .field("lower_threshold_critical", &self.lower_threshold_critical())
+        .field("upper_threshold_critical", &self.upper_threshold_critical())
+        .field(
+            "lower_threshold_non_recoverable",
+            &self.lower_threshold_non_recoverable(),
+        )
+        .field(
+            "upper_threshold_non_recoverable",
+            &self.upper_threshold_non_recoverable(),
+        )
+        .finish()
     }
 }
 
@@ -121,6 +161,7 @@ impl Serialize for SMBiosManagementDeviceThresholdData<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -139,4 +180,27 @@ mod tests {
         assert_eq!(test_struct.lower_threshold_non_recoverable(), Some(5));
         assert_eq!(test_struct.upper_threshold_non_recoverable(), Some(6));
     }
+
+    #[test]
+    fn test_unavailable_thresholds() {
+        // Only the upper thresholds are available
+        let struct_type36 = vec![
+            0x24, 0x10, 0x29, 0x00, 0x00, 0x80, 0x52, 0x03, 0x00, 0x80, 0x84, 0x03, 0x00, 0x80,
+            0xB6, 0x03, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type36);
+        let test_struct = SMBiosManagementDeviceThresholdData::new(&parts);
+
+        assert_eq!(test_struct.lower_threshold_non_critical(), Some(0x8000));
+        assert_eq!(test_struct.known_lower_threshold_non_critical(), None);
+        assert_eq!(test_struct.known_upper_threshold_non_critical(), Some(850));
+        assert_eq!(test_struct.known_lower_threshold_critical(), None);
+        assert_eq!(test_struct.known_upper_threshold_critical(), Some(900));
+        assert_eq!(test_struct.known_lower_threshold_non_recoverable(), None);
+        assert_eq!(
+            test_struct.known_upper_threshold_non_recoverable(),
+            Some(950)
+        );
+    }
 }
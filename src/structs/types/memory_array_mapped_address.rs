@@ -1,7 +1,7 @@
 use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{SMBiosData, SMBiosPhysicalMemoryArray, SMBiosStruct};
+use core::{fmt, ops::RangeInclusive};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Memory Array Mapped Address (Type 19)
 ///
@@ -18,6 +18,7 @@ pub struct SMBiosMemoryArrayMappedAddress<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryArrayMappedAddress<'a> {
     const STRUCT_TYPE: u8 = 19u8;
+    const MIN_LENGTH: u8 = 0x0F;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -64,6 +65,14 @@ impl<'a> SMBiosMemoryArrayMappedAddress<'a> {
         self.parts.get_field_handle(0xC)
     }
 
+    /// The physical memory array referenced by [SMBiosMemoryArrayMappedAddress::physical_memory_array_handle]
+    pub fn physical_memory_array_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosPhysicalMemoryArray<'b>> {
+        data.resolve(self.physical_memory_array_handle()?)
+    }
+
     /// Number of Memory Devices that form a single row
     /// of memory for the address partition defined by this
     /// structure
@@ -95,11 +104,26 @@ impl<'a> SMBiosMemoryArrayMappedAddress<'a> {
     pub fn extended_ending_address(&self) -> Option<u64> {
         self.parts.get_field_qword(0x17)
     }
+
+    /// Physical address range, in bytes, mapped to the specified
+    /// Physical Memory Array
+    ///
+    /// Resolved from 'starting_address' and 'ending_address' (in kilobytes)
+    /// or, when both are FFFF FFFFh, from 'extended_starting_address' and
+    /// 'extended_ending_address'.
+    pub fn range(&self) -> Option<RangeInclusive<u64>> {
+        match (self.starting_address()?, self.ending_address()?) {
+            (0xFFFF_FFFF, 0xFFFF_FFFF) => {
+                Some(self.extended_starting_address()?..=self.extended_ending_address()?)
+            }
+            (start, end) => Some((start as u64) << 10..=((end as u64) << 10) + 0x3FF),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryArrayMappedAddress<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryArrayMappedAddress<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryArrayMappedAddress<'_>>())
             .field("header", &self.parts.header)
             .field("starting_address", &self.starting_address())
             .field("ending_address", &self.ending_address())
@@ -143,6 +167,8 @@ impl Serialize for SMBiosMemoryArrayMappedAddress<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::StructureBuilder;
 
     #[test]
     fn unit_test() {
@@ -162,4 +188,86 @@ mod tests {
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
     }
+
+    #[test]
+    fn test_range() {
+        // Kilobyte encoding: 0 to 16 GiB
+        let struct_type19 = vec![
+            0x13, 0x1F, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x3E, 0x00,
+            0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type19);
+        let test_struct = SMBiosMemoryArrayMappedAddress::new(&parts);
+
+        assert_eq!(test_struct.range(), Some(0..=0x3_FFFF_FFFF));
+
+        // Extended encoding: 4 TiB to 8 TiB
+        let struct_type19 = vec![
+            0x13, 0x1F, 0x40, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3E, 0x00,
+            0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            0x07, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type19);
+        let test_struct = SMBiosMemoryArrayMappedAddress::new(&parts);
+
+        assert_eq!(
+            test_struct.extended_starting_address(),
+            Some(0x400_0000_0000)
+        );
+        assert_eq!(test_struct.extended_ending_address(), Some(0x7FF_FFFF_FFFF));
+        assert_eq!(test_struct.range(), Some(0x400_0000_0000..=0x7FF_FFFF_FFFF));
+
+        // Pre-2.7 structure without the extended fields
+        let struct_type19 = vec![
+            0x13, 0x0F, 0x41, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3E, 0x00,
+            0x01, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type19);
+        let test_struct = SMBiosMemoryArrayMappedAddress::new(&parts);
+
+        assert_eq!(test_struct.range(), None);
+    }
+
+    #[test]
+    fn test_physical_memory_array_handle_resolved() {
+        let mut table_data = StructureBuilder::new(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x1000)
+            .byte(0x03) // System board or motherboard
+            .byte(0x03) // System memory
+            .byte(0x03) // None
+            .dword(0x0100_0000)
+            .word(0xFFFE)
+            .word(2)
+            .build();
+        for (handle, array_handle) in [(0x1300, 0x1000), (0x1301, 0x1300), (0x1302, 0x1234)].iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosMemoryArrayMappedAddress::STRUCT_TYPE, *handle)
+                    .dword(0x0000_0000)
+                    .dword(0x00FF_FFFF)
+                    .handle(Handle(*array_handle))
+                    .byte(2)
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let mapped_addresses = data.collect::<SMBiosMemoryArrayMappedAddress<'_>>();
+
+        let array = mapped_addresses[0]
+            .physical_memory_array_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(array.handle(), Handle(0x1000));
+        assert_eq!(array.number_of_memory_devices(), Some(2));
+
+        // References to a structure of another type or a missing handle
+        assert!(mapped_addresses[1]
+            .physical_memory_array_handle_resolved(&data)
+            .is_none());
+        assert!(mapped_addresses[2]
+            .physical_memory_array_handle_resolved(&data)
+            .is_none());
+    }
 }
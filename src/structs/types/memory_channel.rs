@@ -1,8 +1,9 @@
 use crate::core::{Handle, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Memory Channel (Type 37)
 ///
@@ -21,6 +22,7 @@ pub struct SMBiosMemoryChannel<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryChannel<'a> {
     const STRUCT_TYPE: u8 = 37u8;
+    const MIN_LENGTH: u8 = 0x07;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -63,7 +65,7 @@ impl<'a> SMBiosMemoryChannel<'a> {
 
 impl fmt::Debug for SMBiosMemoryChannel<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryChannel<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryChannel<'_>>())
             .field("header", &self.parts.header)
             .field("channel_type", &self.channel_type())
             .field("maximum_channel_load", &self.maximum_channel_load())
@@ -109,7 +111,7 @@ pub struct MemoryChannelTypeData {
 
 impl fmt::Debug for MemoryChannelTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryChannelTypeData>())
+        fmt.debug_struct(core::any::type_name::<MemoryChannelTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -132,7 +134,7 @@ impl fmt::Display for MemoryChannelTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             MemoryChannelType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -146,7 +148,7 @@ impl Deref for MemoryChannelTypeData {
 }
 
 /// # Memory Channel — Channel Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryChannelType {
     /// Other,
     Other,
@@ -160,6 +162,18 @@ pub enum MemoryChannelType {
     None,
 }
 
+impl fmt::Display for MemoryChannelType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryChannelType::Other => "Other",
+            MemoryChannelType::Unknown => "Unknown",
+            MemoryChannelType::RamBus => "RamBus",
+            MemoryChannelType::SyncLink => "SyncLink",
+            MemoryChannelType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryChannelTypeData {
     fn from(raw: u8) -> Self {
         MemoryChannelTypeData {
@@ -209,7 +223,7 @@ impl<'a> LoadHandlePair<'a> {
 
 impl fmt::Debug for LoadHandlePair<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<LoadHandlePair<'_>>())
+        fmt.debug_struct(core::any::type_name::<LoadHandlePair<'_>>())
             .field("load", &self.load())
             .field("handle", &self.handle())
             .finish()
@@ -347,4 +361,33 @@ mod tests {
         assert_eq!(*second.handle().unwrap(), 4);
         assert!(iterator.next().is_none());
     }
+
+    #[test]
+    fn test_device_count_exceeds_length() {
+        // Claims three devices but only carries two load/handle pairs
+        let struct_type37 = vec![
+            37u8, 0x0D, 0x40, 0x00, 0x04, 0x20, 0x03, 0x10, 0x11, 0x00, 0x08, 0x12, 0x00, 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type37);
+        let test_struct = SMBiosMemoryChannel::new(&parts);
+
+        assert_eq!(
+            *test_struct.channel_type().unwrap(),
+            MemoryChannelType::SyncLink
+        );
+        assert_eq!(test_struct.memory_device_count(), Some(3));
+
+        let iterator = test_struct.load_handle_pairs_iterator();
+        let pairs: Vec<LoadHandlePair<'_>> = (&iterator).into_iter().collect();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[0].load(), Some(0x10));
+        assert_eq!(*pairs[0].handle().unwrap(), 0x11);
+        assert_eq!(pairs[1].load(), Some(0x08));
+        assert_eq!(*pairs[1].handle().unwrap(), 0x12);
+
+        // The iterator resets once exhausted and can be walked again
+        assert_eq!(iterator.into_iter().count(), 2);
+    }
 }
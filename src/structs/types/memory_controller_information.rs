@@ -1,8 +1,9 @@
 use crate::core::{Handle, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Memory Controller Information (Type 5, Obsolete)
 ///
@@ -18,6 +19,7 @@ pub struct SMBiosMemoryControllerInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryControllerInformation<'a> {
     const STRUCT_TYPE: u8 = 5u8;
+    const MIN_LENGTH: u8 = 0x0F;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -108,7 +110,7 @@ impl<'a> SMBiosMemoryControllerInformation<'a> {
 
 impl fmt::Debug for SMBiosMemoryControllerInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryControllerInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryControllerInformation<'_>>())
             .field("header", &self.parts.header)
             .field("error_detecting_method", &self.error_detecting_method())
             .field(
@@ -192,7 +194,7 @@ pub struct ErrorDetectingMethodData {
 
 impl fmt::Debug for ErrorDetectingMethodData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ErrorDetectingMethodData>())
+        fmt.debug_struct(core::any::type_name::<ErrorDetectingMethodData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -220,7 +222,7 @@ impl Deref for ErrorDetectingMethodData {
 }
 
 /// # Memory Controller Error Detecting Method
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ErrorDetectingMethod {
     /// Other
     Other,
@@ -242,6 +244,22 @@ pub enum ErrorDetectingMethod {
     None,
 }
 
+impl fmt::Display for ErrorDetectingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorDetectingMethod::Other => "Other",
+            ErrorDetectingMethod::Unknown => "Unknown",
+            ErrorDetectingMethod::NoErrorDetection => "No Error Detection",
+            ErrorDetectingMethod::Parity8Bit => "8-bit Parity",
+            ErrorDetectingMethod::Ecc32Bit => "32-bit ECC",
+            ErrorDetectingMethod::Ecc64Bit => "64-bit ECC",
+            ErrorDetectingMethod::Ecc128Bit => "128-bit ECC",
+            ErrorDetectingMethod::Crc => "CRC",
+            ErrorDetectingMethod::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ErrorDetectingMethodData {
     fn from(raw: u8) -> Self {
         ErrorDetectingMethodData {
@@ -319,7 +337,7 @@ impl ErrorCorrectingCapabilities {
 
 impl fmt::Debug for ErrorCorrectingCapabilities {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ErrorCorrectingCapabilities>())
+        fmt.debug_struct(core::any::type_name::<ErrorCorrectingCapabilities>())
             .field("raw", &self.raw)
             .field("other", &self.other())
             .field("unknown", &self.unknown())
@@ -375,7 +393,7 @@ pub struct InterleaveSupportData {
 
 impl fmt::Debug for InterleaveSupportData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<InterleaveSupportData>())
+        fmt.debug_struct(core::any::type_name::<InterleaveSupportData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -403,7 +421,7 @@ impl Deref for InterleaveSupportData {
 }
 
 /// # Memory Controller Information — Interleave Support
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum InterleaveSupport {
     /// Other
     Other,
@@ -423,6 +441,21 @@ pub enum InterleaveSupport {
     None,
 }
 
+impl fmt::Display for InterleaveSupport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InterleaveSupport::Other => "Other",
+            InterleaveSupport::Unknown => "Unknown",
+            InterleaveSupport::OneWay => "One-Way Interleave",
+            InterleaveSupport::TwoWay => "Two-Way Interleave",
+            InterleaveSupport::FourWay => "Four-Way Interleave",
+            InterleaveSupport::EightWay => "Eight-Way Interleave",
+            InterleaveSupport::SixteenWay => "Sixteen-Way Interleave",
+            InterleaveSupport::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for InterleaveSupportData {
     fn from(raw: u8) -> Self {
         InterleaveSupportData {
@@ -491,7 +524,7 @@ impl MemorySpeeds {
 
 impl fmt::Debug for MemorySpeeds {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemorySpeeds>())
+        fmt.debug_struct(core::any::type_name::<MemorySpeeds>())
             .field("raw", &self.raw)
             .field("other", &self.other())
             .field("unknown", &self.unknown())
@@ -598,7 +631,7 @@ impl MemoryTypes {
 
 impl fmt::Debug for MemoryTypes {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryTypes>())
+        fmt.debug_struct(core::any::type_name::<MemoryTypes>())
             .field("raw", &self.raw)
             .field("other", &self.other())
             .field("unknown", &self.unknown())
@@ -677,7 +710,7 @@ impl ModuleVoltage {
 
 impl fmt::Debug for ModuleVoltage {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ModuleVoltage>())
+        fmt.debug_struct(core::any::type_name::<ModuleVoltage>())
             .field("raw", &self.raw)
             .field("volts_5", &self.volts_5())
             .field("volts_3_3", &self.volts_3_3())
@@ -1,8 +1,8 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{SMBiosData, SMBiosPhysicalMemoryArray, SMBiosStruct};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Memory Device (Type 17)
 ///
@@ -17,6 +17,7 @@ pub struct SMBiosMemoryDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryDevice<'a> {
     const STRUCT_TYPE: u8 = 17u8;
+    const MIN_LENGTH: u8 = 0x15;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -34,6 +35,14 @@ impl<'a> SMBiosMemoryDevice<'a> {
         self.parts.get_field_handle(0x04)
     }
 
+    /// The physical memory array referenced by [SMBiosMemoryDevice::physical_memory_array_handle]
+    pub fn physical_memory_array_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosPhysicalMemoryArray<'b>> {
+        data.resolve(self.physical_memory_array_handle()?)
+    }
+
     /// Handle, or instance number, associated with any
     /// error that was previously detected for the device
     /// If the system does not provide the error information
@@ -166,6 +175,29 @@ impl<'a> SMBiosMemoryDevice<'a> {
             .map(|raw| MemorySizeExtended::from(raw))
     }
 
+    /// Size of the memory device in bytes
+    ///
+    /// Normalizes the KB/MB granularity of the Size field and follows
+    /// the Size field to the Extended Size field when its value is 7FFFh.
+    /// A device which is not installed has a size of 0 bytes.
+    pub fn size_in_bytes(&self) -> Option<MemoryIndicatedSize> {
+        const KILOBYTE: u64 = 1024u64;
+        const MEGABYTE: u64 = 1024u64 * 1024u64;
+
+        match self.size()? {
+            MemorySize::NotInstalled => Some(MemoryIndicatedSize::Bytes(0)),
+            MemorySize::Unknown => Some(MemoryIndicatedSize::Unknown),
+            MemorySize::Kilobytes(size) => Some(MemoryIndicatedSize::Bytes(size as u64 * KILOBYTE)),
+            MemorySize::Megabytes(size) => Some(MemoryIndicatedSize::Bytes(size as u64 * MEGABYTE)),
+            MemorySize::SeeExtendedSize => match self.extended_size()? {
+                MemorySizeExtended::Megabytes(size) => {
+                    Some(MemoryIndicatedSize::Bytes(size as u64 * MEGABYTE))
+                }
+                MemorySizeExtended::SeeSize => Some(MemoryIndicatedSize::Unknown),
+            },
+        }
+    }
+
     /// Identifies the configured speed of the memory
     /// device, in megatransfers per second (MT/s). See
     /// 7.18.4 for details.
@@ -233,6 +265,22 @@ impl<'a> SMBiosMemoryDevice<'a> {
         self.parts.get_field_word(0x30)
     }
 
+    /// The module manufacturer ID decoded as a [JedecManufacturerId]
+    ///
+    /// Returns None when the ID is 0 (unknown).
+    pub fn module_manufacturer_jedec_id(&self) -> Option<JedecManufacturerId> {
+        self.module_manufacturer_id()
+            .and_then(JedecManufacturerId::known)
+    }
+
+    /// The memory subsystem controller manufacturer ID decoded as a [JedecManufacturerId]
+    ///
+    /// Returns None when the ID is 0 (unknown).
+    pub fn memory_subsystem_controller_manufacturer_jedec_id(&self) -> Option<JedecManufacturerId> {
+        self.memory_subsystem_controller_manufacturer_id()
+            .and_then(JedecManufacturerId::known)
+    }
+
     /// The two-byte memory subsystem controller product ID found in the SPD of this memory device; LSB first.
     pub fn memory_subsystem_controller_product_id(&self) -> Option<u16> {
         self.parts.get_field_word(0x32)
@@ -311,11 +359,45 @@ impl<'a> SMBiosMemoryDevice<'a> {
             .get_field_dword(0x58)
             .map(|raw| MemorySpeedExtended::from(raw))
     }
+
+    /// The maximum capable speed of the device in MT/s
+    ///
+    /// Resolves 'speed', following the FFFFh redirect to 'extended_speed'.
+    /// Returns None when the speed is unknown or the redirect target is
+    /// absent or 0.
+    pub fn speed_mts(&self) -> Option<u32> {
+        resolve_speed(self.speed()?, || self.extended_speed())
+    }
+
+    /// The configured speed of the device in MT/s
+    ///
+    /// Resolves 'configured_memory_speed', following the FFFFh redirect to
+    /// 'extended_configured_memory_speed'. Returns None when the speed is
+    /// unknown or the redirect target is absent or 0.
+    pub fn configured_speed_mts(&self) -> Option<u32> {
+        resolve_speed(self.configured_memory_speed()?, || {
+            self.extended_configured_memory_speed()
+        })
+    }
+}
+
+fn resolve_speed<F>(speed: MemorySpeed, extended_speed: F) -> Option<u32>
+where
+    F: FnOnce() -> Option<MemorySpeedExtended>,
+{
+    match speed {
+        MemorySpeed::Unknown => None,
+        MemorySpeed::MTs(mts) => Some(mts as u32),
+        MemorySpeed::SeeExtendedSpeed => match extended_speed()? {
+            MemorySpeedExtended::MTs(mts) => Some(mts),
+            MemorySpeedExtended::SeeSpeed => None,
+        },
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryDevice<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryDevice<'_>>())
             .field("header", &self.parts.header)
             .field(
                 "physical_memory_array_handle",
@@ -454,7 +536,7 @@ pub struct MemoryDeviceTypeData {
 
 impl fmt::Debug for MemoryDeviceTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryDeviceTypeData>())
+        fmt.debug_struct(core::any::type_name::<MemoryDeviceTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -477,7 +559,7 @@ impl fmt::Display for MemoryDeviceTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             MemoryDeviceType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -491,7 +573,7 @@ impl Deref for MemoryDeviceTypeData {
 }
 
 /// # Memory Device -Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryDeviceType {
     /// Other
     Other,
@@ -561,6 +643,46 @@ pub enum MemoryDeviceType {
     None,
 }
 
+impl fmt::Display for MemoryDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryDeviceType::Other => "Other",
+            MemoryDeviceType::Unknown => "Unknown",
+            MemoryDeviceType::Dram => "DRAM",
+            MemoryDeviceType::Edram => "EDRAM",
+            MemoryDeviceType::Vram => "VRAM",
+            MemoryDeviceType::Sram => "SRAM",
+            MemoryDeviceType::Ram => "RAM",
+            MemoryDeviceType::Rom => "ROM",
+            MemoryDeviceType::Flash => "FLASH",
+            MemoryDeviceType::Eeprom => "EEPROM",
+            MemoryDeviceType::Feprom => "FEPROM",
+            MemoryDeviceType::Eprom => "EPROM",
+            MemoryDeviceType::Cdram => "CDRAM",
+            MemoryDeviceType::ThreeDram => "3DRAM",
+            MemoryDeviceType::Sdram => "SDRAM",
+            MemoryDeviceType::Sgram => "SGRAM",
+            MemoryDeviceType::Rdram => "RDRAM",
+            MemoryDeviceType::Ddr => "DDR",
+            MemoryDeviceType::Ddr2 => "DDR2",
+            MemoryDeviceType::Ddr2Fbdimm => "DDR2 FB-DIMM",
+            MemoryDeviceType::Ddr3 => "DDR3",
+            MemoryDeviceType::Fbd2 => "FBD2",
+            MemoryDeviceType::Ddr4 => "DDR4",
+            MemoryDeviceType::Lpddr => "LPDDR",
+            MemoryDeviceType::Lpddr2 => "LPDDR2",
+            MemoryDeviceType::Lpddr3 => "LPDDR3",
+            MemoryDeviceType::Lpddr4 => "LPDDR4",
+            MemoryDeviceType::LogicalNonVolatileDevice => "Logical non-volatile device",
+            MemoryDeviceType::Hbm => "HBM (High Bandwidth Memory)",
+            MemoryDeviceType::Hbm2 => "HBM2 (High Bandwidth Memory Generation 2)",
+            MemoryDeviceType::Ddr5 => "DDR5",
+            MemoryDeviceType::Lpddr5 => "LPDDR5",
+            MemoryDeviceType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryDeviceTypeData {
     fn from(raw: u8) -> Self {
         MemoryDeviceTypeData {
@@ -619,7 +741,7 @@ pub struct MemoryFormFactorData {
 
 impl fmt::Debug for MemoryFormFactorData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryFormFactorData>())
+        fmt.debug_struct(core::any::type_name::<MemoryFormFactorData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -647,7 +769,7 @@ impl Deref for MemoryFormFactorData {
 }
 
 /// # Memory Device — Form Factor
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryFormFactor {
     /// Other
     Other,
@@ -685,6 +807,30 @@ pub enum MemoryFormFactor {
     None,
 }
 
+impl fmt::Display for MemoryFormFactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryFormFactor::Other => "Other",
+            MemoryFormFactor::Unknown => "Unknown",
+            MemoryFormFactor::Simm => "SIMM",
+            MemoryFormFactor::Sip => "SIP",
+            MemoryFormFactor::Chip => "Chip",
+            MemoryFormFactor::Dip => "DIP",
+            MemoryFormFactor::Zip => "ZIP",
+            MemoryFormFactor::ProprietaryCard => "Proprietary Card",
+            MemoryFormFactor::Dimm => "DIMM",
+            MemoryFormFactor::Tsop => "TSOP",
+            MemoryFormFactor::RowOfChips => "Row of chips",
+            MemoryFormFactor::Rimm => "RIMM",
+            MemoryFormFactor::Sodimm => "SODIMM",
+            MemoryFormFactor::Srimm => "SRIMM",
+            MemoryFormFactor::Fbdimm => "FB-DIMM",
+            MemoryFormFactor::Die => "Die",
+            MemoryFormFactor::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryFormFactorData {
     fn from(raw: u8) -> Self {
         MemoryFormFactorData {
@@ -812,7 +958,7 @@ impl MemoryTypeDetails {
 
 impl fmt::Debug for MemoryTypeDetails {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryTypeDetails>())
+        fmt.debug_struct(core::any::type_name::<MemoryTypeDetails>())
             .field("raw", &self.raw)
             .field("other", &self.other())
             .field("unknown", &self.unknown())
@@ -874,7 +1020,7 @@ pub struct MemoryDeviceTechnologyData {
 
 impl fmt::Debug for MemoryDeviceTechnologyData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryDeviceTechnologyData>())
+        fmt.debug_struct(core::any::type_name::<MemoryDeviceTechnologyData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -902,7 +1048,7 @@ impl Deref for MemoryDeviceTechnologyData {
 }
 
 /// # Memory Device — Memory Technology
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryDeviceTechnology {
     /// Other
     Other,
@@ -922,6 +1068,23 @@ pub enum MemoryDeviceTechnology {
     None,
 }
 
+impl fmt::Display for MemoryDeviceTechnology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryDeviceTechnology::Other => "Other",
+            MemoryDeviceTechnology::Unknown => "Unknown",
+            MemoryDeviceTechnology::Dram => "DRAM",
+            MemoryDeviceTechnology::NvdimmN => "NVDIMM-N",
+            MemoryDeviceTechnology::NvdimmF => "NVDIMM-F",
+            MemoryDeviceTechnology::NvdimmP => "NVDIMM-P",
+            MemoryDeviceTechnology::IntelOptaneDcPersistentMemory => {
+                "Intel® Optane™ persistent memory"
+            }
+            MemoryDeviceTechnology::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryDeviceTechnologyData {
     fn from(raw: u8) -> Self {
         MemoryDeviceTechnologyData {
@@ -990,7 +1153,7 @@ impl MemoryOperatingModeCapabilities {
 
 impl fmt::Debug for MemoryOperatingModeCapabilities {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryOperatingModeCapabilities>())
+        fmt.debug_struct(core::any::type_name::<MemoryOperatingModeCapabilities>())
             .field("raw", &self.raw)
             .field("other", &self.other())
             .field("unknown", &self.unknown())
@@ -1030,7 +1193,7 @@ impl Serialize for MemoryOperatingModeCapabilities {
 }
 
 /// # Speed of Memory
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum MemorySpeed {
     /// Memory Speed is Unknown
     Unknown,
@@ -1040,6 +1203,16 @@ pub enum MemorySpeed {
     MTs(u16),
 }
 
+impl fmt::Display for MemorySpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MemorySpeed::Unknown => f.write_str("Unknown"),
+            MemorySpeed::SeeExtendedSpeed => f.write_str("See Extended Speed"),
+            MemorySpeed::MTs(speed) => write!(f, "{} MT/s", speed),
+        }
+    }
+}
+
 impl From<u16> for MemorySpeed {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1050,8 +1223,60 @@ impl From<u16> for MemorySpeed {
     }
 }
 
+/// # JEDEC Manufacturer ID
+///
+/// A manufacturer ID as found in the SPD and encoded per JEDEC JEP-106.
+/// The low byte holds the number of continuation codes (0x7F bytes) that
+/// precede the manufacturer code, selecting its bank; the high byte holds
+/// the manufacturer code within that bank. Bit 7 of each byte is an odd
+/// parity bit.
+///
+/// For example, 0xCE80 is bank 1 code 0xCE (Samsung) and 0x2C80 is bank 1
+/// code 0x2C (Micron).
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct JedecManufacturerId {
+    /// Raw value
+    pub raw: u16,
+}
+
+impl JedecManufacturerId {
+    /// Wraps `raw`, mapping 0 (unknown) to None
+    pub fn known(raw: u16) -> Option<Self> {
+        match raw {
+            0 => None,
+            _ => Some(JedecManufacturerId { raw }),
+        }
+    }
+
+    /// Number of continuation codes preceding the manufacturer code
+    pub fn continuation_codes(&self) -> u8 {
+        (self.raw & 0x007F) as u8
+    }
+
+    /// JEP-106 bank number (1 based): the number of continuation codes plus one
+    pub fn bank(&self) -> u8 {
+        self.continuation_codes() + 1
+    }
+
+    /// Manufacturer code within the bank, including its parity bit
+    pub fn manufacturer_code(&self) -> u8 {
+        (self.raw >> 8) as u8
+    }
+}
+
+impl fmt::Display for JedecManufacturerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Bank {}, Hex 0x{:02X}",
+            self.bank(),
+            self.manufacturer_code()
+        )
+    }
+}
+
 /// # Extended Speed of Memory
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemorySpeedExtended {
     /// Speed of memory in megatransfers per second (MT/s)
     MTs(u32),
@@ -1063,6 +1288,15 @@ pub enum MemorySpeedExtended {
     SeeSpeed,
 }
 
+impl fmt::Display for MemorySpeedExtended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MemorySpeedExtended::MTs(speed) => write!(f, "{} MT/s", speed),
+            MemorySpeedExtended::SeeSpeed => f.write_str("See Speed"),
+        }
+    }
+}
+
 /// Bit 31 is reserved for future use and must be set to 0
 /// Bits 30:0 represent the speed or configured memory speed of the device in MT/s.
 impl From<u32> for MemorySpeedExtended {
@@ -1076,7 +1310,7 @@ impl From<u32> for MemorySpeedExtended {
 }
 
 /// # Size of Memory
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemorySize {
     /// No Memory Device Installed in the Socket
     NotInstalled,
@@ -1090,6 +1324,18 @@ pub enum MemorySize {
     Megabytes(u16),
 }
 
+impl fmt::Display for MemorySize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MemorySize::NotInstalled => f.write_str("No Module Installed"),
+            MemorySize::Unknown => f.write_str("Unknown"),
+            MemorySize::SeeExtendedSize => f.write_str("See Extended Size"),
+            MemorySize::Kilobytes(size) => write!(f, "{} kB", size),
+            MemorySize::Megabytes(size) => write!(f, "{} MB", size),
+        }
+    }
+}
+
 /// If the value is 0, no memory device is installed in the
 /// socket; if the size is unknown, the field value is
 /// FFFFh. If the size is 32 GB-1 MB or greater, the
@@ -1117,7 +1363,7 @@ impl From<u16> for MemorySize {
 }
 
 /// # Extended Size of Memory
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemorySizeExtended {
     /// Size of Memory (MB)
     Megabytes(u32),
@@ -1128,6 +1374,15 @@ pub enum MemorySizeExtended {
     SeeSize,
 }
 
+impl fmt::Display for MemorySizeExtended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MemorySizeExtended::Megabytes(size) => write!(f, "{} MB", size),
+            MemorySizeExtended::SeeSize => f.write_str("See Size"),
+        }
+    }
+}
+
 /// Bit 31 is reserved for future use and must be set to 0.
 /// Bits 30:0 represent the size of the memory device in megabytes.
 impl From<u32> for MemorySizeExtended {
@@ -1141,7 +1396,7 @@ impl From<u32> for MemorySizeExtended {
 }
 
 /// # Size of Memory in Bytes
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryIndicatedSize {
     /// Memory Size is Unknown
     Unknown,
@@ -1149,6 +1404,15 @@ pub enum MemoryIndicatedSize {
     Bytes(u64),
 }
 
+impl fmt::Display for MemoryIndicatedSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MemoryIndicatedSize::Unknown => f.write_str("Unknown"),
+            MemoryIndicatedSize::Bytes(size) => write!(f, "{} bytes", size),
+        }
+    }
+}
+
 impl From<u64> for MemoryIndicatedSize {
     fn from(raw: u64) -> Self {
         match raw {
@@ -1161,6 +1425,8 @@ impl From<u64> for MemoryIndicatedSize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::StructureBuilder;
 
     #[test]
     fn test() {
@@ -1320,4 +1586,252 @@ mod tests {
             Some(MemorySpeedExtended::MTs(0x05060708))
         );
     }
+
+    #[test]
+    fn test_size_in_bytes() {
+        let mut struct_type17 = vec![
+            0x11, 0x20, 0x40, 0x00, 0x3E, 0x00, 0xFE, 0xFF, 0x48, 0x00, 0x40, 0x00, 0xFF, 0x7F,
+            0x09, 0x00, 0x01, 0x02, 0x1A, 0x80, 0x00, 0x6A, 0x0A, 0x03, 0x04, 0x05, 0x06, 0x01,
+            0x00, 0x80, 0x00, 0x00, b'D', b'I', b'M', b'M', 0x00, 0x00,
+        ];
+
+        // 7FFFh redirects to the extended size (32768 MB)
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.size(), Some(MemorySize::SeeExtendedSize));
+        assert_eq!(
+            test_struct.size_in_bytes(),
+            Some(MemoryIndicatedSize::Bytes(32768 * 1024 * 1024))
+        );
+
+        // Bit 15 set selects KB granularity: 8100h is 256 KB
+        struct_type17[0x0C] = 0x00;
+        struct_type17[0x0D] = 0x81;
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(
+            test_struct.size_in_bytes(),
+            Some(MemoryIndicatedSize::Bytes(256 * 1024))
+        );
+
+        // 0100h is 256 MB
+        struct_type17[0x0D] = 0x01;
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(
+            test_struct.size_in_bytes(),
+            Some(MemoryIndicatedSize::Bytes(256 * 1024 * 1024))
+        );
+
+        // Not installed
+        struct_type17[0x0D] = 0x00;
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(
+            test_struct.size_in_bytes(),
+            Some(MemoryIndicatedSize::Bytes(0))
+        );
+    }
+
+    #[test]
+    fn test_speed_mts() {
+        let build = |speed: u16, configured_speed: u16, extended: Option<(u32, u32)>| {
+            let mut raw = vec![0u8; 0x5C];
+            raw[0] = 0x11;
+            raw[1] = 0x5C;
+            raw[0x15..0x17].copy_from_slice(&speed.to_le_bytes());
+            raw[0x20..0x22].copy_from_slice(&configured_speed.to_le_bytes());
+            match extended {
+                Some((extended_speed, extended_configured_speed)) => {
+                    raw[0x54..0x58].copy_from_slice(&extended_speed.to_le_bytes());
+                    raw[0x58..0x5C].copy_from_slice(&extended_configured_speed.to_le_bytes());
+                }
+                // Truncate to an SMBIOS 3.2 structure without extended speeds
+                None => {
+                    raw.truncate(0x54);
+                    raw[1] = 0x54;
+                }
+            }
+            raw.extend_from_slice(&[0x00, 0x00]);
+            UndefinedStruct::new(&raw)
+        };
+
+        // Unknown
+        let parts = build(0x0000, 0x0000, Some((0, 0)));
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.speed_mts(), None);
+        assert_eq!(test_struct.configured_speed_mts(), None);
+
+        // In-range
+        let parts = build(3200, 2933, None);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.speed_mts(), Some(3200));
+        assert_eq!(test_struct.configured_speed_mts(), Some(2933));
+
+        // Extended redirect
+        let parts = build(0xFFFF, 0xFFFF, Some((70_000, 0x8001_1170)));
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.speed_mts(), Some(70_000));
+        assert_eq!(test_struct.configured_speed_mts(), Some(0x0001_1170));
+
+        // Extended redirect to a zero or absent extended field
+        let parts = build(0xFFFF, 0xFFFF, Some((0, 0)));
+        assert_eq!(SMBiosMemoryDevice::new(&parts).speed_mts(), None);
+        let parts = build(0xFFFF, 0xFFFF, None);
+        assert_eq!(SMBiosMemoryDevice::new(&parts).configured_speed_mts(), None);
+    }
+
+    #[test]
+    fn test_jedec_manufacturer_id() {
+        let mut raw = vec![0u8; 0x34];
+        raw[0] = 0x11;
+        raw[1] = 0x34;
+        // Samsung (bank 1, 0xCE), LSB first
+        raw[0x2C..0x2E].copy_from_slice(&[0x80, 0xCE]);
+        raw.extend_from_slice(&[0x00, 0x00]);
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+
+        assert_eq!(test_struct.module_manufacturer_id(), Some(0xCE80));
+        let samsung = test_struct.module_manufacturer_jedec_id().unwrap();
+        assert_eq!(samsung.continuation_codes(), 0);
+        assert_eq!(samsung.bank(), 1);
+        assert_eq!(samsung.manufacturer_code(), 0xCE);
+        assert_eq!(samsung.to_string(), "Bank 1, Hex 0xCE");
+        assert_eq!(
+            test_struct.memory_subsystem_controller_manufacturer_jedec_id(),
+            None
+        );
+
+        // Kingston: bank 2 (one continuation code, with parity), code 0x98
+        let kingston = JedecManufacturerId::known(0x9801).unwrap();
+        assert_eq!(kingston.continuation_codes(), 1);
+        assert_eq!(kingston.bank(), 2);
+        assert_eq!(kingston.manufacturer_code(), 0x98);
+    }
+
+    #[test]
+    fn test_form_factor_and_memory_type() {
+        let build = |form_factor: u8, memory_type: u8| {
+            let raw = StructureBuilder::new(SMBiosMemoryDevice::STRUCT_TYPE, 0x0000)
+                .bytes(&[0; 0x0A]) // 0x04..0x0E
+                .byte(form_factor) // 0x0E Form Factor
+                .bytes(&[0; 0x03]) // 0x0F..0x12
+                .byte(memory_type) // 0x12 Memory Type
+                .bytes(&[0; 0x15]) // 0x13..0x28
+                .build();
+            UndefinedStruct::new(&raw)
+        };
+
+        // DDR4 SODIMM
+        let parts = build(0x0D, 0x1A);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        let form_factor = test_struct.form_factor().unwrap();
+        assert_eq!(form_factor.value, MemoryFormFactor::Sodimm);
+        assert_eq!(form_factor.raw, 0x0D);
+        let memory_type = test_struct.memory_type().unwrap();
+        assert_eq!(memory_type.value, MemoryDeviceType::Ddr4);
+        assert_eq!(memory_type.raw, 0x1A);
+
+        // DDR5 DIMM
+        let parts = build(0x09, 0x22);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(
+            test_struct.form_factor().unwrap().value,
+            MemoryFormFactor::Dimm
+        );
+        assert_eq!(
+            test_struct.memory_type().unwrap().value,
+            MemoryDeviceType::Ddr5
+        );
+
+        // Values beyond the specification keep the raw byte
+        let parts = build(0x30, 0x40);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        let form_factor = test_struct.form_factor().unwrap();
+        assert_eq!(form_factor.value, MemoryFormFactor::None);
+        assert_eq!(form_factor.raw, 0x30);
+        let memory_type = test_struct.memory_type().unwrap();
+        assert_eq!(memory_type.value, MemoryDeviceType::None);
+        assert_eq!(memory_type.raw, 0x40);
+    }
+
+    #[test]
+    fn test_type_detail_and_operating_mode() {
+        // SMBIOS 3.2 registered ECC DDR4 DIMM: 72 bit total width, 64 bit data width
+        let raw = StructureBuilder::new(SMBiosMemoryDevice::STRUCT_TYPE, 0x0000)
+            .bytes(&[0; 0x04]) // 0x04..0x08
+            .word(72) // 0x08 Total Width
+            .word(64) // 0x0A Data Width
+            .word(0) // 0x0C Size
+            .byte(0x09) // 0x0E Form Factor: DIMM
+            .bytes(&[0; 0x03]) // 0x0F..0x12
+            .byte(0x1A) // 0x12 Memory Type: DDR4
+            .word(0x2080) // 0x13 Type Detail: Synchronous (bit 7) and Registered (bit 13)
+            .bytes(&[0; 0x13]) // 0x15..0x28
+            .byte(0x03) // 0x28 Memory Technology: DRAM
+            .word(0x0008) // 0x29 Memory Operating Mode Capability: volatile memory
+            .bytes(&[0; 0x29]) // 0x2B..0x54
+            .build();
+
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert!(test_struct.total_width().unwrap() > test_struct.data_width().unwrap());
+
+        let type_detail = test_struct.type_detail().unwrap();
+        assert_eq!(*type_detail, 0x2080);
+        assert!(type_detail.synchronous());
+        assert!(type_detail.registered());
+        assert!(!type_detail.unbuffered());
+        assert!(!type_detail.lrdimm());
+        assert!(!type_detail.unknown());
+
+        let operating_mode = test_struct.memory_operating_mode_capability().unwrap();
+        assert!(operating_mode.volatile_memory());
+        assert!(!operating_mode.byte_accessible_persistent_memory());
+        assert!(!operating_mode.block_accessible_persistent_memory());
+
+        // Unbuffered (bit 14) and LRDIMM (bit 15)
+        let type_detail = MemoryTypeDetails::from(0xC000);
+        assert!(type_detail.unbuffered());
+        assert!(type_detail.lrdimm());
+        assert!(!type_detail.registered());
+    }
+
+    #[test]
+    fn test_physical_memory_array_handle_resolved() {
+        let mut table_data = StructureBuilder::new(SMBiosPhysicalMemoryArray::STRUCT_TYPE, 0x1000)
+            .byte(0x03) // System board or motherboard
+            .byte(0x03) // System memory
+            .byte(0x03) // None
+            .dword(0x0100_0000)
+            .word(0xFFFE)
+            .word(2)
+            .build();
+        for (handle, array_handle) in [(0x1100, 0x1000), (0x1101, 0x1100), (0x1102, 0x1234)].iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosMemoryDevice::STRUCT_TYPE, *handle)
+                    .handle(Handle(*array_handle))
+                    .word(0xFFFE)
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let memory_devices = data.collect::<SMBiosMemoryDevice<'_>>();
+
+        let array = memory_devices[0]
+            .physical_memory_array_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(array.handle(), Handle(0x1000));
+        assert_eq!(array.number_of_memory_devices(), Some(2));
+
+        // References to a structure of another type or a missing handle
+        assert!(memory_devices[1]
+            .physical_memory_array_handle_resolved(&data)
+            .is_none());
+        assert!(memory_devices[2]
+            .physical_memory_array_handle_resolved(&data)
+            .is_none());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{SMBiosData, SMBiosMemoryArrayMappedAddress, SMBiosMemoryDevice, SMBiosStruct};
+use core::{fmt, ops::RangeInclusive};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Memory Device Mapped Address (Type 20)
 ///
@@ -16,6 +16,7 @@ pub struct SMBiosMemoryDeviceMappedAddress<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryDeviceMappedAddress<'a> {
     const STRUCT_TYPE: u8 = 20u8;
+    const MIN_LENGTH: u8 = 0x13;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -61,6 +62,14 @@ impl<'a> SMBiosMemoryDeviceMappedAddress<'a> {
         self.parts.get_field_handle(0xC)
     }
 
+    /// The memory device referenced by [SMBiosMemoryDeviceMappedAddress::memory_device_handle]
+    pub fn memory_device_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosMemoryDevice<'b>> {
+        data.resolve(self.memory_device_handle()?)
+    }
+
     /// Handle, or instance number, associated with the
     /// Memory Array Mapped Address structure to which
     /// this device address range is mapped
@@ -70,6 +79,14 @@ impl<'a> SMBiosMemoryDeviceMappedAddress<'a> {
         self.parts.get_field_handle(0xE)
     }
 
+    /// The memory array mapped address referenced by [SMBiosMemoryDeviceMappedAddress::memory_array_mapped_address_handle]
+    pub fn memory_array_mapped_address_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosMemoryArrayMappedAddress<'b>> {
+        data.resolve(self.memory_array_mapped_address_handle()?)
+    }
+
     /// Position of the referenced [super::SMBiosMemoryDevice] in a row
     /// of the address partition
     /// For example, if two 8-bit devices form a 16-bit row,
@@ -131,11 +148,26 @@ impl<'a> SMBiosMemoryDeviceMappedAddress<'a> {
     pub fn extended_ending_address(&self) -> Option<u64> {
         self.parts.get_field_qword(0x1B)
     }
+
+    /// Physical address range, in bytes, mapped to the referenced
+    /// [super::SMBiosMemoryDevice]
+    ///
+    /// Resolved from 'starting_address' and 'ending_address' (in kilobytes)
+    /// or, when both are FFFF FFFFh, from 'extended_starting_address' and
+    /// 'extended_ending_address'.
+    pub fn range(&self) -> Option<RangeInclusive<u64>> {
+        match (self.starting_address()?, self.ending_address()?) {
+            (0xFFFF_FFFF, 0xFFFF_FFFF) => {
+                Some(self.extended_starting_address()?..=self.extended_ending_address()?)
+            }
+            (start, end) => Some((start as u64) << 10..=((end as u64) << 10) + 0x3FF),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryDeviceMappedAddress<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryDeviceMappedAddress<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryDeviceMappedAddress<'_>>())
             .field("header", &self.parts.header)
             .field("starting_address", &self.starting_address())
             .field("ending_address", &self.ending_address())
@@ -185,6 +217,8 @@ impl Serialize for SMBiosMemoryDeviceMappedAddress<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::StructureBuilder;
 
     #[test]
     fn unit_test() {
@@ -210,4 +244,97 @@ mod tests {
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
     }
+
+    #[test]
+    fn test_extended_address() {
+        // 32 GiB device mapped above 4 TiB through the extended fields
+        let struct_type20 = vec![
+            0x14, 0x23, 0x42, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x40, 0x00,
+            0x3F, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0xFF,
+            0xFF, 0xFF, 0xFF, 0x07, 0x04, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type20);
+        let test_struct = SMBiosMemoryDeviceMappedAddress::new(&parts);
+
+        assert_eq!(test_struct.starting_address(), Some(0xFFFF_FFFF));
+        assert_eq!(test_struct.ending_address(), Some(0xFFFF_FFFF));
+        assert_eq!(test_struct.partition_row_position(), Some(0xFF));
+        assert_eq!(test_struct.interleave_position(), Some(0));
+        assert_eq!(test_struct.interleaved_data_depth(), Some(0));
+        assert_eq!(
+            test_struct.extended_starting_address(),
+            Some(0x400_0000_0000)
+        );
+        assert_eq!(test_struct.extended_ending_address(), Some(0x407_FFFF_FFFF));
+        assert_eq!(test_struct.range(), Some(0x400_0000_0000..=0x407_FFFF_FFFF));
+
+        // Kilobyte encoding from the existing sample: 0 to 8 GiB
+        let struct_type20 = vec![
+            0x14, 0x23, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x7F, 0x00, 0x40, 0x00,
+            0x3F, 0x00, 0x01, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type20);
+        let test_struct = SMBiosMemoryDeviceMappedAddress::new(&parts);
+
+        assert_eq!(test_struct.range(), Some(0..=0x1_FFFF_FFFF));
+    }
+
+    #[test]
+    fn test_handles_resolved() {
+        let mut table_data = StructureBuilder::new(SMBiosMemoryDevice::STRUCT_TYPE, 0x1100)
+            .handle(Handle(0x1000))
+            .word(0xFFFE)
+            .build();
+        table_data.extend(
+            StructureBuilder::new(SMBiosMemoryArrayMappedAddress::STRUCT_TYPE, 0x1300)
+                .dword(0x0000_0000)
+                .dword(0x00FF_FFFF)
+                .handle(Handle(0x1000))
+                .byte(1)
+                .build(),
+        );
+
+        // A device and mapped address which resolve, handles of another
+        // structure type, and missing handles
+        for (handle, memory_device, mapped_address) in [
+            (0x1400, 0x1100, 0x1300),
+            (0x1401, 0x1300, 0x1100),
+            (0x1402, 0x1234, 0x1235),
+        ]
+        .iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosMemoryDeviceMappedAddress::STRUCT_TYPE, *handle)
+                    .dword(0x0000_0000)
+                    .dword(0x00FF_FFFF)
+                    .handle(Handle(*memory_device))
+                    .handle(Handle(*mapped_address))
+                    .bytes(&[0xFF, 0xFF, 0xFF])
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let device_addresses = data.collect::<SMBiosMemoryDeviceMappedAddress<'_>>();
+
+        let memory_device = device_addresses[0]
+            .memory_device_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(memory_device.handle(), Handle(0x1100));
+        let mapped_address = device_addresses[0]
+            .memory_array_mapped_address_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(mapped_address.handle(), Handle(0x1300));
+
+        for device_address in &device_addresses[1..] {
+            assert!(device_address
+                .memory_device_handle_resolved(&data)
+                .is_none());
+            assert!(device_address
+                .memory_array_mapped_address_handle_resolved(&data)
+                .is_none());
+        }
+    }
 }
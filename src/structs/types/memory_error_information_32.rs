@@ -1,7 +1,7 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # 32-Bit Memory Error Information (Type 18)
 ///
@@ -16,6 +16,7 @@ pub struct SMBiosMemoryErrorInformation32<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryErrorInformation32<'a> {
     const STRUCT_TYPE: u8 = 18u8;
+    const MIN_LENGTH: u8 = 0x17;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -82,11 +83,35 @@ impl<'a> SMBiosMemoryErrorInformation32<'a> {
     pub fn error_resolution(&self) -> Option<u32> {
         self.parts.get_field_dword(0x13)
     }
+
+    /// 'vendor_syndrome', or None when it is unknown (0000 0000h)
+    pub fn known_vendor_syndrome(&self) -> Option<u32> {
+        self.vendor_syndrome()
+            .filter(|&syndrome| syndrome != 0x0000_0000)
+    }
+
+    /// 'memory_array_error_address', or None when it is unknown (8000 0000h)
+    pub fn known_memory_array_error_address(&self) -> Option<u32> {
+        self.memory_array_error_address()
+            .filter(|&address| address != 0x8000_0000)
+    }
+
+    /// 'device_error_address', or None when it is unknown (8000 0000h)
+    pub fn known_device_error_address(&self) -> Option<u32> {
+        self.device_error_address()
+            .filter(|&address| address != 0x8000_0000)
+    }
+
+    /// 'error_resolution', or None when it is unknown (8000 0000h)
+    pub fn known_error_resolution(&self) -> Option<u32> {
+        self.error_resolution()
+            .filter(|&resolution| resolution != 0x8000_0000)
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryErrorInformation32<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryErrorInformation32<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryErrorInformation32<'_>>())
             .field("header", &self.parts.header)
             .field("error_type", &self.error_type())
             .field("error_granularity", &self.error_granularity())
@@ -138,7 +163,7 @@ pub struct MemoryErrorTypeData {
 
 impl fmt::Debug for MemoryErrorTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryErrorTypeData>())
+        fmt.debug_struct(core::any::type_name::<MemoryErrorTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -161,7 +186,7 @@ impl fmt::Display for MemoryErrorTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             MemoryErrorType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -175,7 +200,7 @@ impl Deref for MemoryErrorTypeData {
 }
 
 /// # Memory Error - Error Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryErrorType {
     /// Other
     Other,
@@ -209,6 +234,28 @@ pub enum MemoryErrorType {
     None,
 }
 
+impl fmt::Display for MemoryErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryErrorType::Other => "Other",
+            MemoryErrorType::Unknown => "Unknown",
+            MemoryErrorType::OK => "OK",
+            MemoryErrorType::BadRead => "Bad read",
+            MemoryErrorType::ParityError => "Parity error",
+            MemoryErrorType::SingleBitError => "Single-bit error",
+            MemoryErrorType::DoubleBitError => "Double-bit error",
+            MemoryErrorType::MultiBitError => "Multi-bit error",
+            MemoryErrorType::NibbleError => "Nibble error",
+            MemoryErrorType::ChecksumError => "Checksum error",
+            MemoryErrorType::CrcError => "CRC error",
+            MemoryErrorType::CorrectedSingleBitError => "Corrected single-bit error",
+            MemoryErrorType::CorrectedError => "Corrected error",
+            MemoryErrorType::UncorrectableError => "Uncorrectable error",
+            MemoryErrorType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryErrorTypeData {
     fn from(raw: u8) -> Self {
         MemoryErrorTypeData {
@@ -249,7 +296,7 @@ pub struct MemoryErrorGranularityData {
 
 impl fmt::Debug for MemoryErrorGranularityData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryErrorGranularityData>())
+        fmt.debug_struct(core::any::type_name::<MemoryErrorGranularityData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -277,7 +324,7 @@ impl Deref for MemoryErrorGranularityData {
 }
 
 /// # Memory Error - Error Granularity
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryErrorGranularity {
     /// Other
     Other,
@@ -291,6 +338,18 @@ pub enum MemoryErrorGranularity {
     None,
 }
 
+impl fmt::Display for MemoryErrorGranularity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryErrorGranularity::Other => "Other",
+            MemoryErrorGranularity::Unknown => "Unknown",
+            MemoryErrorGranularity::DeviceLevel => "Device level",
+            MemoryErrorGranularity::MemoryPartitionLevel => "Memory partition level",
+            MemoryErrorGranularity::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryErrorGranularityData {
     fn from(raw: u8) -> Self {
         MemoryErrorGranularityData {
@@ -321,7 +380,7 @@ pub struct MemoryErrorOperationData {
 
 impl fmt::Debug for MemoryErrorOperationData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryErrorOperationData>())
+        fmt.debug_struct(core::any::type_name::<MemoryErrorOperationData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -349,7 +408,7 @@ impl Deref for MemoryErrorOperationData {
 }
 
 /// # Memory Error - Error Operation
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryErrorOperation {
     /// Other
     Other,
@@ -365,6 +424,19 @@ pub enum MemoryErrorOperation {
     None,
 }
 
+impl fmt::Display for MemoryErrorOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryErrorOperation::Other => "Other",
+            MemoryErrorOperation::Unknown => "Unknown",
+            MemoryErrorOperation::Read => "Read",
+            MemoryErrorOperation::Write => "Write",
+            MemoryErrorOperation::PartialWrite => "Partial write",
+            MemoryErrorOperation::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryErrorOperationData {
     fn from(raw: u8) -> Self {
         MemoryErrorOperationData {
@@ -384,6 +456,7 @@ impl From<u8> for MemoryErrorOperationData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -408,5 +481,38 @@ mod tests {
         assert_eq!(test_struct.memory_array_error_address(), Some(0x8000_0000));
         assert_eq!(test_struct.device_error_address(), Some(0x8000_0000));
         assert_eq!(test_struct.error_resolution(), Some(0x8000_0000));
+        assert_eq!(test_struct.known_vendor_syndrome(), None);
+        assert_eq!(test_struct.known_memory_array_error_address(), None);
+        assert_eq!(test_struct.known_device_error_address(), None);
+        assert_eq!(test_struct.known_error_resolution(), None);
+    }
+
+    #[test]
+    fn test_known_error() {
+        // Multi-bit error on a write, resolved to the memory partition
+        let struct_type18 = vec![
+            0x12, 0x17, 0x51, 0x00, 0x08, 0x04, 0x04, 0x78, 0x56, 0x34, 0x12, 0x00, 0x20, 0x00,
+            0x00, 0x00, 0x02, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type18);
+        let test_struct = SMBiosMemoryErrorInformation32::new(&parts);
+
+        assert_eq!(
+            *test_struct.error_type().unwrap(),
+            MemoryErrorType::MultiBitError
+        );
+        assert_eq!(
+            *test_struct.error_granularity().unwrap(),
+            MemoryErrorGranularity::MemoryPartitionLevel
+        );
+        assert_eq!(
+            *test_struct.error_operation().unwrap(),
+            MemoryErrorOperation::Write
+        );
+        assert_eq!(test_struct.known_vendor_syndrome(), Some(0x1234_5678));
+        assert_eq!(test_struct.known_memory_array_error_address(), Some(0x2000));
+        assert_eq!(test_struct.known_device_error_address(), Some(0x200));
+        assert_eq!(test_struct.known_error_resolution(), Some(0x8));
     }
 }
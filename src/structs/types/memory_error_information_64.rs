@@ -2,8 +2,8 @@ use crate::{
     MemoryErrorGranularityData, MemoryErrorOperationData, MemoryErrorTypeData, SMBiosStruct,
     UndefinedStruct,
 };
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # 64-Bit Memory Error Information (Type 33)
 ///
@@ -18,6 +18,7 @@ pub struct SMBiosMemoryErrorInformation64<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryErrorInformation64<'a> {
     const STRUCT_TYPE: u8 = 33u8;
+    const MIN_LENGTH: u8 = 0x1F;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -87,11 +88,37 @@ impl<'a> SMBiosMemoryErrorInformation64<'a> {
     pub fn error_resolution(&self) -> Option<u32> {
         self.parts.get_field_dword(0x1B)
     }
+
+    /// 'vendor_syndrome', or None when it is unknown (0000 0000h)
+    pub fn known_vendor_syndrome(&self) -> Option<u32> {
+        self.vendor_syndrome()
+            .filter(|&syndrome| syndrome != 0x0000_0000)
+    }
+
+    /// 'memory_array_error_address', or None when it is unknown
+    /// (8000 0000 0000 0000h)
+    pub fn known_memory_array_error_address(&self) -> Option<u64> {
+        self.memory_array_error_address()
+            .filter(|&address| address != 0x8000_0000_0000_0000)
+    }
+
+    /// 'device_error_address', or None when it is unknown
+    /// (8000 0000 0000 0000h)
+    pub fn known_device_error_address(&self) -> Option<u64> {
+        self.device_error_address()
+            .filter(|&address| address != 0x8000_0000_0000_0000)
+    }
+
+    /// 'error_resolution', or None when it is unknown (8000 0000h)
+    pub fn known_error_resolution(&self) -> Option<u32> {
+        self.error_resolution()
+            .filter(|&resolution| resolution != 0x8000_0000)
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryErrorInformation64<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryErrorInformation64<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryErrorInformation64<'_>>())
             .field("header", &self.parts.header)
             .field("error_type", &self.error_type())
             .field("error_granularity", &self.error_granularity())
@@ -131,6 +158,7 @@ impl Serialize for SMBiosMemoryErrorInformation64<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
     use crate::{
         MemoryErrorGranularity, MemoryErrorOperation, MemoryErrorType, SMBiosStruct,
         UndefinedStruct,
@@ -166,5 +194,43 @@ mod tests {
             Some(0x8000_0000_0000_0000)
         );
         assert_eq!(test_struct.error_resolution(), Some(0x8000_0000));
+
+        assert_eq!(test_struct.known_vendor_syndrome(), None);
+        assert_eq!(test_struct.known_memory_array_error_address(), None);
+        assert_eq!(test_struct.known_device_error_address(), None);
+        assert_eq!(test_struct.known_error_resolution(), None);
+    }
+
+    #[test]
+    fn test_known_error() {
+        // Corrected single-bit error on a read, resolved to the device
+        let struct_type33 = vec![
+            0x21, 0x1F, 0x52, 0x00, 0x0C, 0x03, 0x03, 0xEF, 0xBE, 0xAD, 0xDE, 0x40, 0x10, 0x00,
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x40, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+            0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type33);
+        let test_struct = SMBiosMemoryErrorInformation64::new(&parts);
+
+        assert_eq!(
+            *test_struct.error_type().unwrap(),
+            MemoryErrorType::CorrectedSingleBitError
+        );
+        assert_eq!(
+            *test_struct.error_granularity().unwrap(),
+            MemoryErrorGranularity::DeviceLevel
+        );
+        assert_eq!(
+            *test_struct.error_operation().unwrap(),
+            MemoryErrorOperation::Read
+        );
+        assert_eq!(test_struct.known_vendor_syndrome(), Some(0xDEAD_BEEF));
+        assert_eq!(
+            test_struct.known_memory_array_error_address(),
+            Some(0x1_0000_1040)
+        );
+        assert_eq!(test_struct.known_device_error_address(), Some(0x1040));
+        assert_eq!(test_struct.known_error_resolution(), Some(0x40));
     }
 }
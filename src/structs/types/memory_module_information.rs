@@ -1,7 +1,7 @@
 use crate::core::{strings::*, UndefinedStruct};
 use crate::{MemoryTypes, SMBiosStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Memory Module Information (Type 6, Obsolete)
 ///
@@ -16,6 +16,7 @@ pub struct SMBiosMemoryModuleInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryModuleInformation<'a> {
     const STRUCT_TYPE: u8 = 6u8;
+    const MIN_LENGTH: u8 = 0x0C;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -77,7 +78,7 @@ impl<'a> SMBiosMemoryModuleInformation<'a> {
 
 impl fmt::Debug for SMBiosMemoryModuleInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosMemoryModuleInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosMemoryModuleInformation<'_>>())
             .field("header", &self.parts.header)
             .field("socket_designation", &self.socket_designation())
             .field("bank_connections", &self.bank_connections())
@@ -111,6 +112,7 @@ impl Serialize for SMBiosMemoryModuleInformation<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -6,6 +6,9 @@
 mod unknown;
 pub use unknown::*;
 
+mod oem_defined;
+pub use oem_defined::*;
+
 mod bios_information;
 pub use bios_information::*;
 
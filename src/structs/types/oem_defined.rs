@@ -0,0 +1,120 @@
+use crate::{Handle, Header, SMBiosStringSet, UndefinedStruct};
+use core::fmt;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+/// # OEM-Defined Structure (Types 128 to 255)
+///
+/// Types 128 through 255 (80h to FFh) are available for system- and
+/// OEM-specific information. Their layout is known only to the vendor, so this
+/// structure exposes the raw formatted bytes and the string-set for the
+/// consumer to parse.
+///
+/// Offsets into [SMBiosOemDefined::formatted_bytes] start after the [Header];
+/// use [SMBiosOemDefined::parts] for the `get_field_*()` functions, whose
+/// offsets include the [Header] as the specification gives them.
+pub struct SMBiosOemDefined<'a> {
+    parts: &'a UndefinedStruct,
+}
+
+impl<'a> SMBiosOemDefined<'a> {
+    /// The first structure type available for OEM-specific information
+    pub const FIRST_STRUCT_TYPE: u8 = 0x80;
+
+    /// Creates an instance of this struct if the structure has an OEM type
+    pub fn try_new(parts: &'a UndefinedStruct) -> Option<Self> {
+        match parts.header.struct_type() >= Self::FIRST_STRUCT_TYPE {
+            true => Some(SMBiosOemDefined { parts }),
+            false => None,
+        }
+    }
+
+    /// Structure parts of this OEM structure
+    pub fn parts(&self) -> &'a UndefinedStruct {
+        self.parts
+    }
+
+    /// The OEM structure type (128 to 255)
+    pub fn struct_type(&self) -> u8 {
+        self.parts.header.struct_type()
+    }
+
+    /// The handle of this structure
+    pub fn handle(&self) -> Handle {
+        self.parts.header.handle()
+    }
+
+    /// The formatted section of the structure following the [Header]
+    pub fn formatted_bytes(&self) -> &'a [u8] {
+        self.parts.fields.get(Header::SIZE..).unwrap_or(&[])
+    }
+
+    /// The strings of the structure
+    pub fn strings(&self) -> &'a SMBiosStringSet {
+        &self.parts.strings
+    }
+}
+
+impl fmt::Debug for SMBiosOemDefined<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(core::any::type_name::<SMBiosOemDefined<'_>>())
+            .field("header", &self.parts.header)
+            .field("formatted_bytes", &self.formatted_bytes())
+            .field("strings", &self.parts.strings)
+            .finish()
+    }
+}
+
+impl Serialize for SMBiosOemDefined<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("SMBiosOemDefined", 3)?;
+        state.serialize_field("header", &self.parts.header)?;
+        state.serialize_field("formatted_bytes", &self.formatted_bytes())?;
+        state.serialize_field("strings", &self.parts.strings)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{SMBiosData, SMBiosStruct, SMBiosSystemInformation, StructureBuilder};
+
+    #[test]
+    fn test_oem_defined() {
+        let struct_type90 = StructureBuilder::new(0x90, 0x9000)
+            .word(0xBEEF)
+            .string("Vendor Tool")
+            .dword(0x0102_0304)
+            .string("Rev A")
+            .build();
+
+        let parts = UndefinedStruct::new(&struct_type90);
+        let oem = SMBiosOemDefined::try_new(&parts).unwrap();
+        assert_eq!(oem.struct_type(), 0x90);
+        assert_eq!(oem.handle(), Handle(0x9000));
+        assert_eq!(
+            oem.formatted_bytes(),
+            &[0xEF, 0xBE, 0x01, 0x04, 0x03, 0x02, 0x01, 0x02]
+        );
+        assert_eq!(oem.strings().get_string(1).to_string(), "Vendor Tool");
+        assert_eq!(oem.strings().get_string(2).to_string(), "Rev A");
+        assert_eq!(oem.parts().get_field_word(0x04), Some(0xBEEF));
+
+        let mut table_data = StructureBuilder::new(SMBiosSystemInformation::STRUCT_TYPE, 1)
+            .string("LENOVO")
+            .build();
+        table_data.extend(&struct_type90);
+        table_data.extend(StructureBuilder::new(127, 0xFEFF).build());
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+
+        assert_eq!(data.iter().count(), 3);
+        let oem_structures: Vec<SMBiosOemDefined<'_>> = data.oem_defined_iter().collect();
+        assert_eq!(oem_structures.len(), 1);
+        assert_eq!(oem_structures[0].handle(), Handle(0x9000));
+        assert!(SMBiosOemDefined::try_new(data.iter().next().unwrap()).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{SMBiosStringSet, SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # OEM Strings (Type 11)
 ///
@@ -16,6 +16,7 @@ pub struct SMBiosOemStrings<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosOemStrings<'a> {
     const STRUCT_TYPE: u8 = 11u8;
+    const MIN_LENGTH: u8 = 0x05;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -40,7 +41,7 @@ impl<'a> SMBiosOemStrings<'a> {
 
 impl fmt::Debug for SMBiosOemStrings<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosOemStrings<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosOemStrings<'_>>())
             .field("header", &self.parts.header)
             .field("count", &self.count())
             .field("oem_strings", &self.oem_strings())
@@ -64,6 +65,7 @@ impl Serialize for SMBiosOemStrings<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -98,4 +100,23 @@ mod tests {
             Some("BUILDID#13WWCDC8601#SABA#DABA;".to_string())
         );
     }
+
+    #[test]
+    fn test_count_mismatch() {
+        // The count claims 5 strings but the string-set contains 2
+        let struct_type11 = vec![
+            0x0B, 0x05, 0x05, 0x00, 0x05, b'O', b'n', b'e', 0x00, b'T', b'w', b'o', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type11);
+        let test_struct = SMBiosOemStrings::new(&parts);
+
+        assert_eq!(test_struct.count(), Some(0x05));
+        let strings: Vec<String> = test_struct
+            .oem_strings()
+            .into_iter()
+            .filter_map(|oem_string| oem_string.ok())
+            .collect();
+        assert_eq!(strings, vec!["One".to_string(), "Two".to_string()]);
+    }
 }
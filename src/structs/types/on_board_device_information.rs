@@ -1,7 +1,8 @@
 use crate::core::{strings::*, Header, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::fmt;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # On Board Devices Information (Type 10, Obsolete)
 ///
@@ -22,6 +23,7 @@ pub struct SMBiosOnBoardDeviceInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosOnBoardDeviceInformation<'a> {
     const STRUCT_TYPE: u8 = 10u8;
+    const MIN_LENGTH: u8 = 0x06;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -48,7 +50,7 @@ impl<'a> SMBiosOnBoardDeviceInformation<'a> {
 
 impl fmt::Debug for SMBiosOnBoardDeviceInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosOnBoardDeviceInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosOnBoardDeviceInformation<'_>>())
             .field("header", &self.parts.header)
             .field("number_of_devices", &self.number_of_devices())
             .field("onboard_device_iterator", &self.onboard_device_iterator())
@@ -112,7 +114,7 @@ impl<'a> OnBoardDevice<'a> {
 
 impl fmt::Debug for OnBoardDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<OnBoardDevice<'_>>())
+        fmt.debug_struct(core::any::type_name::<OnBoardDevice<'_>>())
             .field("device_type", &self.device_type())
             .field("description", &self.description())
             .finish()
@@ -180,7 +182,7 @@ impl From<u8> for OnBoardDeviceType {
 
 impl fmt::Debug for OnBoardDeviceType {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<OnBoardDevice<'_>>())
+        fmt.debug_struct(core::any::type_name::<OnBoardDevice<'_>>())
             .field("raw", &self.raw)
             .field("type_of_device", &self.type_of_device())
             .field("status", &self.status())
@@ -202,7 +204,7 @@ impl Serialize for OnBoardDeviceType {
 }
 
 /// # Onboard Device Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TypeOfDevice {
     /// Other
     Other,
@@ -240,8 +242,32 @@ pub enum TypeOfDevice {
     None,
 }
 
+impl fmt::Display for TypeOfDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TypeOfDevice::Other => "Other",
+            TypeOfDevice::Unknown => "Unknown",
+            TypeOfDevice::Video => "Video",
+            TypeOfDevice::ScsiController => "SCSI Controller",
+            TypeOfDevice::Ethernet => "Ethernet",
+            TypeOfDevice::TokenRing => "Token Ring",
+            TypeOfDevice::Sound => "Sound",
+            TypeOfDevice::PataController => "PATA Controller",
+            TypeOfDevice::SataController => "SATA Controller",
+            TypeOfDevice::SasController => "SAS Controller",
+            TypeOfDevice::WirelessLan => "Wireless LAN",
+            TypeOfDevice::Bluetooth => "Bluetooth",
+            TypeOfDevice::Wwan => "WWAN",
+            TypeOfDevice::Emmc => "eMMC (embedded Multi-Media Controller)",
+            TypeOfDevice::NvmeController => "NVMe Controller",
+            TypeOfDevice::UfsController => "UFS Controller",
+            TypeOfDevice::None => "Undefined",
+        })
+    }
+}
+
 /// # Enabled/Disabled Device Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum DeviceStatus {
     /// Device is enabled
     Enabled,
@@ -249,6 +275,15 @@ pub enum DeviceStatus {
     Disabled,
 }
 
+impl fmt::Display for DeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DeviceStatus::Enabled => "Enabled",
+            DeviceStatus::Disabled => "Disabled",
+        })
+    }
+}
+
 /// # On-board Device Itereator for [OnBoardDevice]s contained within [SMBiosOnBoardDeviceInformation]
 pub struct OnBoardDeviceIterator<'a> {
     data: &'a SMBiosOnBoardDeviceInformation<'a>,
@@ -338,7 +373,7 @@ impl<'a> Serialize for OnBoardDeviceIterator<'a> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -1,8 +1,8 @@
 use super::system_slot::{BusNumber, DeviceFunctionNumber, SegmentGroupNumber};
 use crate::core::{strings::*, UndefinedStruct};
 use crate::{OnBoardDeviceType, SMBiosStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Onboard Devices Extended Information (Type 41)
 ///
@@ -28,6 +28,7 @@ pub struct SMBiosOnboardDevicesExtendedInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosOnboardDevicesExtendedInformation<'a> {
     const STRUCT_TYPE: u8 = 41u8;
+    const MIN_LENGTH: u8 = 0x0B;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -80,7 +81,7 @@ impl<'a> SMBiosOnboardDevicesExtendedInformation<'a> {
 
 impl fmt::Debug for SMBiosOnboardDevicesExtendedInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<
+        fmt.debug_struct(core::any::type_name::<
             SMBiosOnboardDevicesExtendedInformation<'_>,
         >())
         .field("header", &self.parts.header)
@@ -115,6 +116,7 @@ impl Serialize for SMBiosOnboardDevicesExtendedInformation<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
     use crate::{DeviceStatus, SMBiosStruct, TypeOfDevice, UndefinedStruct};
 
     #[test]
@@ -155,4 +157,56 @@ mod tests {
             _ => panic!("expected device and function values"),
         }
     }
+
+    #[test]
+    fn test_not_applicable_and_disabled() {
+        // Disabled sound device without bus/device/function information
+        let struct_type41 = vec![
+            0x29, 0x0B, 0x3C, 0x00, 0x01, 0x07, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, b'H', b'D', b'A',
+            0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type41);
+        let test_struct = SMBiosOnboardDevicesExtendedInformation::new(&parts);
+
+        let device_type = test_struct.device_type().unwrap();
+        assert_eq!(device_type.type_of_device(), TypeOfDevice::Sound);
+        assert_eq!(device_type.status(), DeviceStatus::Disabled);
+        assert_eq!(test_struct.device_type_instance(), Some(2));
+        assert_eq!(
+            test_struct.segment_group_number(),
+            Some(SegmentGroupNumber::NotApplicable)
+        );
+        assert_eq!(test_struct.bus_number(), Some(BusNumber::NotApplicable));
+        assert_eq!(
+            test_struct.device_function_number(),
+            Some(DeviceFunctionNumber::NotApplicable)
+        );
+
+        // Segment group 0x00FF is a valid segment, not the 0xFFFF sentinel
+        let struct_type41 = vec![
+            0x29, 0x0B, 0x3D, 0x00, 0x01, 0x89, 0x01, 0xFF, 0x00, 0x02, 0x00, b'S', b'A', b'T',
+            b'A', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type41);
+        let test_struct = SMBiosOnboardDevicesExtendedInformation::new(&parts);
+
+        assert_eq!(
+            test_struct.device_type().unwrap().type_of_device(),
+            TypeOfDevice::SataController
+        );
+        assert_eq!(
+            test_struct.segment_group_number(),
+            Some(SegmentGroupNumber::Number(0xFF))
+        );
+        assert_eq!(test_struct.bus_number(), Some(BusNumber::Number(2)));
+        assert_eq!(
+            test_struct.device_function_number(),
+            Some(DeviceFunctionNumber::Number {
+                device: 0,
+                function: 0
+            })
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use crate::SMBiosStruct;
 use crate::{strings::*, UndefinedStruct};
+use core::{fmt, ops::Deref};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::{fmt, ops::Deref};
 
 /// # Out-of-Band Remote Access (Type 30)
 ///
@@ -20,6 +20,7 @@ pub struct SMBiosOutOfBandRemoteAccess<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosOutOfBandRemoteAccess<'a> {
     const STRUCT_TYPE: u8 = 30u8;
+    const MIN_LENGTH: u8 = 0x06;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -89,7 +90,7 @@ impl Connections {
 
 impl fmt::Debug for Connections {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<Connections>())
+        fmt.debug_struct(core::any::type_name::<Connections>())
             .field("raw", &self.raw)
             .field(
                 "inbound_connection_enabled",
@@ -124,7 +125,7 @@ impl Serialize for Connections {
 
 impl fmt::Debug for SMBiosOutOfBandRemoteAccess<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosOutOfBandRemoteAccess<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosOutOfBandRemoteAccess<'_>>())
             .field("header", &self.parts.header)
             .field("manufacturer_name", &self.manufacturer_name())
             .field("connections", &self.connections())
@@ -148,6 +149,7 @@ impl Serialize for SMBiosOutOfBandRemoteAccess<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -168,4 +170,33 @@ mod tests {
         assert!(connections.outbound_connection_enabled());
         assert_eq!(connections.raw, 0x03);
     }
+
+    #[test]
+    fn test_connection_bits() {
+        let struct_type30 = vec![
+            30, 0x06, 0x3C, 0x00, 0x01, 0x00, b'A', b'c', b'm', b'e', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type30);
+        let test_struct = SMBiosOutOfBandRemoteAccess::new(&parts);
+
+        let connections = test_struct.connections().unwrap();
+        assert!(!connections.inbound_connection_enabled());
+        assert!(!connections.outbound_connection_enabled());
+        assert_eq!(*connections, 0x00);
+
+        // Inbound is bit 0 and outbound is bit 1 per DSP0134
+        let inbound_only = Connections::from(0x01);
+        assert!(inbound_only.inbound_connection_enabled());
+        assert!(!inbound_only.outbound_connection_enabled());
+
+        let outbound_only = Connections::from(0x02);
+        assert!(!outbound_only.inbound_connection_enabled());
+        assert!(outbound_only.outbound_connection_enabled());
+
+        // Reserved bits do not affect the decoded flags
+        let reserved = Connections::from(0xFC);
+        assert!(!reserved.inbound_connection_enabled());
+        assert!(!reserved.outbound_connection_enabled());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::core::{Handle, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::{fmt, ops::Deref};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::{fmt, ops::Deref};
 /// # Physical Memory Array (Type 16)
 ///
 /// This structure describes a collection of memory devices that operate together to form a memory address space.
@@ -15,6 +15,7 @@ pub struct SMBiosPhysicalMemoryArray<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosPhysicalMemoryArray<'a> {
     const STRUCT_TYPE: u8 = 16u8;
+    const MIN_LENGTH: u8 = 0x0F;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -93,11 +94,22 @@ impl<'a> SMBiosPhysicalMemoryArray<'a> {
     pub fn extended_maximum_capacity(&self) -> Option<u64> {
         self.parts.get_field_qword(0x0F)
     }
+
+    /// Maximum memory capacity, in bytes, for this array
+    ///
+    /// Resolves 'maximum_capacity' (in kilobytes) or, when it
+    /// contains 8000 0000h, 'extended_maximum_capacity'.
+    pub fn maximum_capacity_in_bytes(&self) -> Option<u64> {
+        match self.maximum_capacity()? {
+            MaximumMemoryCapacity::Kilobytes(kb) => Some(kb as u64 * 1024),
+            MaximumMemoryCapacity::SeeExtendedMaximumCapacity => self.extended_maximum_capacity(),
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosPhysicalMemoryArray<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosPhysicalMemoryArray<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosPhysicalMemoryArray<'_>>())
             .field("header", &self.parts.header)
             .field("location", &self.location())
             .field("usage", &self.usage())
@@ -155,7 +167,7 @@ pub struct MemoryArrayLocationData {
 
 impl fmt::Debug for MemoryArrayLocationData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryArrayLocationData>())
+        fmt.debug_struct(core::any::type_name::<MemoryArrayLocationData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -183,7 +195,7 @@ impl Deref for MemoryArrayLocationData {
 }
 
 /// # Memory Array - Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryArrayLocation {
     /// Other
     Other = 0x01,
@@ -219,6 +231,29 @@ pub enum MemoryArrayLocation {
     None,
 }
 
+impl fmt::Display for MemoryArrayLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryArrayLocation::Other => "Other",
+            MemoryArrayLocation::Unknown => "Unknown",
+            MemoryArrayLocation::SystemBoardOrMotherboard => "System board or motherboard",
+            MemoryArrayLocation::IsaAddOnCard => "ISA add-on card",
+            MemoryArrayLocation::EisaAddOnCard => "EISA add-on card",
+            MemoryArrayLocation::PciAddOnCard => "PCI add-on card",
+            MemoryArrayLocation::McaAddOnCard => "MCA add-on card",
+            MemoryArrayLocation::PcmciaAddOnCard => "PCMCIA add-on card",
+            MemoryArrayLocation::ProprietaryAddOnCard => "Proprietary add-on card",
+            MemoryArrayLocation::NuBus => "NuBus",
+            MemoryArrayLocation::PC98C20AddOnCard => "PC-98/C20 add-on card",
+            MemoryArrayLocation::PC98C24AddOnCard => "PC-98/C24 add-on card",
+            MemoryArrayLocation::PC98EAddOnCard => "PC-98/E add-on card",
+            MemoryArrayLocation::PC98LocalBusAddOnCard => "PC-98/Local bus add-on card",
+            MemoryArrayLocation::CxlFlexbus10AddOnCard => "CXL add-on card",
+            MemoryArrayLocation::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryArrayLocationData {
     fn from(raw: u8) -> Self {
         MemoryArrayLocationData {
@@ -260,7 +295,7 @@ pub struct MemoryArrayUseData {
 
 impl fmt::Debug for MemoryArrayUseData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryArrayUseData>())
+        fmt.debug_struct(core::any::type_name::<MemoryArrayUseData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -288,7 +323,7 @@ impl Deref for MemoryArrayUseData {
 }
 
 /// # Memory Array - Use
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryArrayUse {
     /// Other
     Other,
@@ -308,6 +343,21 @@ pub enum MemoryArrayUse {
     None,
 }
 
+impl fmt::Display for MemoryArrayUse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryArrayUse::Other => "Other",
+            MemoryArrayUse::Unknown => "Unknown",
+            MemoryArrayUse::SystemMemory => "System memory",
+            MemoryArrayUse::VideoMemory => "Video memory",
+            MemoryArrayUse::FlashMemory => "Flash memory",
+            MemoryArrayUse::NonVolatileRam => "Non-volatile RAM",
+            MemoryArrayUse::CacheMemory => "Cache memory",
+            MemoryArrayUse::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryArrayUseData {
     fn from(raw: u8) -> Self {
         MemoryArrayUseData {
@@ -341,7 +391,7 @@ pub struct MemoryArrayErrorCorrectionData {
 
 impl fmt::Debug for MemoryArrayErrorCorrectionData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<MemoryArrayErrorCorrectionData>())
+        fmt.debug_struct(core::any::type_name::<MemoryArrayErrorCorrectionData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -369,7 +419,7 @@ impl Deref for MemoryArrayErrorCorrectionData {
 }
 
 /// # Memory Array - Error Correction Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MemoryArrayErrorCorrection {
     /// Other
     Other,
@@ -389,6 +439,21 @@ pub enum MemoryArrayErrorCorrection {
     None,
 }
 
+impl fmt::Display for MemoryArrayErrorCorrection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MemoryArrayErrorCorrection::Other => "Other",
+            MemoryArrayErrorCorrection::Unknown => "Unknown",
+            MemoryArrayErrorCorrection::NoCorrection => "No Error Correction",
+            MemoryArrayErrorCorrection::Parity => "Parity",
+            MemoryArrayErrorCorrection::SingleBitEcc => "Single-bit ECC",
+            MemoryArrayErrorCorrection::MultiBitEcc => "Multi-bit ECC",
+            MemoryArrayErrorCorrection::Crc => "CRC",
+            MemoryArrayErrorCorrection::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for MemoryArrayErrorCorrectionData {
     fn from(raw: u8) -> Self {
         MemoryArrayErrorCorrectionData {
@@ -408,7 +473,7 @@ impl From<u8> for MemoryArrayErrorCorrectionData {
 }
 
 /// # Maximum memory capacity, in kilobytes, for this array
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MaximumMemoryCapacity {
     /// Maximum memory capacity in Kilobytes
     Kilobytes(u32),
@@ -416,6 +481,17 @@ pub enum MaximumMemoryCapacity {
     SeeExtendedMaximumCapacity,
 }
 
+impl fmt::Display for MaximumMemoryCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MaximumMemoryCapacity::Kilobytes(capacity) => write!(f, "{} kB", capacity),
+            MaximumMemoryCapacity::SeeExtendedMaximumCapacity => {
+                f.write_str("See Extended Maximum Capacity")
+            }
+        }
+    }
+}
+
 impl From<u32> for MaximumMemoryCapacity {
     fn from(raw: u32) -> Self {
         match raw {
@@ -428,6 +504,7 @@ impl From<u32> for MaximumMemoryCapacity {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -459,4 +536,40 @@ mod tests {
         assert_eq!(test_struct.number_of_memory_devices(), Some(4));
         assert_eq!(test_struct.extended_maximum_capacity(), Some(0));
     }
+
+    #[test]
+    fn test_extended_maximum_capacity() {
+        // Maximum Capacity of 8000 0000h redirects to the 4 TB Extended Maximum Capacity
+        let struct_type16 = vec![
+            0x10, 0x17, 0x3F, 0x00, 0x03, 0x03, 0x05, 0x00, 0x00, 0x00, 0x80, 0xFE, 0xFF, 0x20,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type16);
+        let test_struct = SMBiosPhysicalMemoryArray::new(&parts);
+
+        match test_struct.maximum_capacity().unwrap() {
+            MaximumMemoryCapacity::Kilobytes(_) => panic!("expected extended capacity"),
+            MaximumMemoryCapacity::SeeExtendedMaximumCapacity => (),
+        }
+        assert_eq!(
+            test_struct.extended_maximum_capacity(),
+            Some(0x0000_0400_0000_0000)
+        );
+        assert_eq!(
+            test_struct.maximum_capacity_in_bytes(),
+            Some(0x0000_0400_0000_0000)
+        );
+        assert_eq!(test_struct.number_of_memory_devices(), Some(32));
+
+        // A structure prior to 2.7 has no Extended Maximum Capacity
+        let struct_type16 = vec![
+            0x10, 0x0F, 0x3F, 0x00, 0x03, 0x03, 0x05, 0x00, 0x00, 0x00, 0x80, 0xFE, 0xFF, 0x20,
+            0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type16);
+        let test_struct = SMBiosPhysicalMemoryArray::new(&parts);
+        assert_eq!(test_struct.maximum_capacity_in_bytes(), None);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::core::{strings::*, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::{fmt, ops::Deref};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::{fmt, ops::Deref};
 
 /// # Port Connector Information (Type 8)
 ///
@@ -18,6 +18,7 @@ pub struct SMBiosPortConnectorInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosPortConnectorInformation<'a> {
     const STRUCT_TYPE: u8 = 8u8;
+    const MIN_LENGTH: u8 = 0x09;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -69,7 +70,7 @@ impl<'a> SMBiosPortConnectorInformation<'a> {
 
 impl fmt::Debug for SMBiosPortConnectorInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosPortConnectorInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosPortConnectorInformation<'_>>())
             .field("header", &self.parts.header)
             .field(
                 "internal_reference_designator",
@@ -123,7 +124,7 @@ pub struct PortInformationConnectorTypeData {
 
 impl fmt::Debug for PortInformationConnectorTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<PortInformationConnectorTypeData>())
+        fmt.debug_struct(core::any::type_name::<PortInformationConnectorTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -146,7 +147,7 @@ impl fmt::Display for PortInformationConnectorTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             PortInformationConnectorType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -160,7 +161,7 @@ impl Deref for PortInformationConnectorTypeData {
 }
 
 /// # Port Information - Connector Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PortInformationConnectorType {
     /// There is No Connector
     NoConnector,
@@ -250,6 +251,58 @@ pub enum PortInformationConnectorType {
     None,
 }
 
+impl fmt::Display for PortInformationConnectorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PortInformationConnectorType::NoConnector => "None",
+            PortInformationConnectorType::Centronics => "Centronics",
+            PortInformationConnectorType::MiniCentronics => "Mini Centronics",
+            PortInformationConnectorType::Proprietary => "Proprietary",
+            PortInformationConnectorType::DB25PinMale => "DB-25 pin male",
+            PortInformationConnectorType::DB25PinFemale => "DB-25 pin female",
+            PortInformationConnectorType::DB15PinMale => "DB-15 pin male",
+            PortInformationConnectorType::DB15PinFemale => "DB-15 pin female",
+            PortInformationConnectorType::DB9PinMale => "DB-9 pin male",
+            PortInformationConnectorType::DB8PinFemale => "DB-9 pin female",
+            PortInformationConnectorType::RJ11 => "RJ-11",
+            PortInformationConnectorType::RJ45 => "RJ-45",
+            PortInformationConnectorType::MiniScsi50Pin => "50-pin MiniSCSI",
+            PortInformationConnectorType::MiniDin => "Mini-DIN",
+            PortInformationConnectorType::MicroDin => "Micro-DIN",
+            PortInformationConnectorType::Ps2 => "PS/2",
+            PortInformationConnectorType::Infrared => "Infrared",
+            PortInformationConnectorType::HpHil => "HP-HIL",
+            PortInformationConnectorType::AccessBusUsb => "Access Bus (USB)",
+            PortInformationConnectorType::SsaScsi => "SSA SCSI",
+            PortInformationConnectorType::CircularDin8Male => "Circular DIN-8 male",
+            PortInformationConnectorType::CircularDin8Female => "Circular DIN-8 female",
+            PortInformationConnectorType::OnBoardIde => "On Board IDE",
+            PortInformationConnectorType::OnBoardFloppy => "On Board Floppy",
+            PortInformationConnectorType::DualInline9Pin => "9-pin Dual Inline (pin 10 cut)",
+            PortInformationConnectorType::DualInline25Pin => "25-pin Dual Inline (pin 26 cut)",
+            PortInformationConnectorType::DualInline50Pin => "50-pin Dual Inline",
+            PortInformationConnectorType::DualInline68Pin => "68-pin Dual Inline",
+            PortInformationConnectorType::OnBoardSoundInputCDRom => {
+                "On Board Sound Input from CD-ROM"
+            }
+            PortInformationConnectorType::MiniCentronicsType14 => "Mini-Centronics Type-14",
+            PortInformationConnectorType::MiniCentronicsTyp26 => "Mini-Centronics Type-26",
+            PortInformationConnectorType::MiniJackHeadphones => "Mini-jack (headphones)",
+            PortInformationConnectorType::Bnc => "BNC",
+            PortInformationConnectorType::Port1394 => "1394",
+            PortInformationConnectorType::SasSataPlugReceptacle => "SAS/SATA Plug Receptacle",
+            PortInformationConnectorType::UsbTypeCReceptacle => "USB Type-C Receptacle",
+            PortInformationConnectorType::PC98 => "PC-98",
+            PortInformationConnectorType::PC98Hireso => "PC-98Hireso",
+            PortInformationConnectorType::PCH88 => "PC-H98",
+            PortInformationConnectorType::PC98Note => "PC-98Note",
+            PortInformationConnectorType::PC98Full => "PC-98Full",
+            PortInformationConnectorType::Other => "Other",
+            PortInformationConnectorType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for PortInformationConnectorTypeData {
     fn from(raw: u8) -> Self {
         PortInformationConnectorTypeData {
@@ -318,7 +371,7 @@ pub struct PortInformationPortTypeData {
 
 impl fmt::Debug for PortInformationPortTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<PortInformationPortTypeData>())
+        fmt.debug_struct(core::any::type_name::<PortInformationPortTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -341,7 +394,7 @@ impl fmt::Display for PortInformationPortTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             PortInformationPortType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -355,7 +408,7 @@ impl Deref for PortInformationPortTypeData {
 }
 
 /// # Port Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PortInformationPortType {
     /// No Port
     NoPort,
@@ -439,6 +492,53 @@ pub enum PortInformationPortType {
     None,
 }
 
+impl fmt::Display for PortInformationPortType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PortInformationPortType::NoPort => "No Port",
+            PortInformationPortType::ParallelPortXTATCompatible => "Parallel Port XT/AT Compatible",
+            PortInformationPortType::ParallelPortPS2 => "Parallel Port PS/2",
+            PortInformationPortType::ParallelPortEcp => "Parallel Port ECP",
+            PortInformationPortType::ParallelPortEpp => "Parallel Port EPP",
+            PortInformationPortType::ParallelPortEcpEpp => "Parallel Port ECP/EPP",
+            PortInformationPortType::SerialPortXTATCompatible => "Serial Port XT/AT Compatible",
+            PortInformationPortType::SerialPort16450Compatible => "Serial Port 16450 Compatible",
+            PortInformationPortType::SerialPort16550Compatible => "Serial Port 16550 Compatible",
+            PortInformationPortType::SerialPort16550ACompatible => "Serial Port 16550A Compatible",
+            PortInformationPortType::ScsiPort => "SCSI Port",
+            PortInformationPortType::MidiPort => "MIDI Port",
+            PortInformationPortType::JoyStickPort => "Joy Stick Port",
+            PortInformationPortType::KeyboardPort => "Keyboard Port",
+            PortInformationPortType::MousePort => "Mouse Port",
+            PortInformationPortType::SsaScsi => "SSA SCSI",
+            PortInformationPortType::Usb => "USB",
+            PortInformationPortType::Firewire => "FireWire (IEEE P1394)",
+            PortInformationPortType::PcmciaTypeI => "PCMCIA Type I",
+            PortInformationPortType::PcmcialTypeII => "PCMCIA Type II",
+            PortInformationPortType::PcmciaTypeIii => "PCMCIA Type III",
+            PortInformationPortType::Cardbus => "Cardbus",
+            PortInformationPortType::AccessBusPort => "Access Bus Port",
+            PortInformationPortType::ScsiII => "SCSI II",
+            PortInformationPortType::ScsiWide => "SCSI Wide",
+            PortInformationPortType::PC98 => "PC-98",
+            PortInformationPortType::PC98Hireso => "PC-98-Hireso",
+            PortInformationPortType::PCH98 => "PC-H98",
+            PortInformationPortType::VideoPort => "Video Port",
+            PortInformationPortType::AudioPort => "Audio Port",
+            PortInformationPortType::ModemPort => "Modem Port",
+            PortInformationPortType::NetworkPort => "Network Port",
+            PortInformationPortType::Sata => "SATA",
+            PortInformationPortType::Sas => "SAS",
+            PortInformationPortType::Mfdp => "MFDP (Multi-Function Display Port)",
+            PortInformationPortType::Thunderbolt => "Thunderbolt",
+            PortInformationPortType::Port8251Compatible => "8251 Compatible",
+            PortInformationPortType::Port8251FifoCompatible => "8251 FIFO Compatible",
+            PortInformationPortType::Other => "Other",
+            PortInformationPortType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for PortInformationPortTypeData {
     fn from(raw: u8) -> Self {
         PortInformationPortTypeData {
@@ -492,6 +592,7 @@ impl From<u8> for PortInformationPortTypeData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -524,4 +625,47 @@ mod tests {
             PortInformationPortType::MousePort
         );
     }
+
+    #[test]
+    fn test_usb_and_network_ports() {
+        // USB port with an external Access Bus (USB) connector
+        let struct_type8 = vec![
+            0x08, 0x09, 0x05, 0x00, 0x01, 0x00, 0x02, 0x12, 0x10, b'J', b'3', b'A', b'1', 0x00,
+            b'U', b'S', b'B', b'1', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type8);
+        let test_struct = SMBiosPortConnectorInformation::new(&parts);
+
+        assert_eq!(
+            *test_struct.external_connector_type().unwrap(),
+            PortInformationConnectorType::AccessBusUsb
+        );
+        assert_eq!(
+            *test_struct.port_type().unwrap(),
+            PortInformationPortType::Usb
+        );
+
+        // Network port with an external RJ-45 connector
+        let struct_type8 = vec![
+            0x08, 0x09, 0x06, 0x00, 0x01, 0x00, 0x02, 0x0B, 0x1F, b'J', b'9', b'A', b'1', 0x00,
+            b'L', b'A', b'N', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type8);
+        let test_struct = SMBiosPortConnectorInformation::new(&parts);
+
+        assert_eq!(
+            test_struct.external_reference_designator().to_string(),
+            "LAN".to_string()
+        );
+        assert_eq!(
+            *test_struct.external_connector_type().unwrap(),
+            PortInformationConnectorType::RJ45
+        );
+        assert_eq!(
+            *test_struct.port_type().unwrap(),
+            PortInformationPortType::NetworkPort
+        );
+    }
 }
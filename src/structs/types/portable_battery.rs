@@ -1,8 +1,9 @@
 use crate::core::{strings::*, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Portable Battery (Type 22)
 ///
@@ -18,6 +19,7 @@ pub struct SMBiosPortableBattery<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosPortableBattery<'a> {
     const STRUCT_TYPE: u8 = 22u8;
+    const MIN_LENGTH: u8 = 0x10;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -163,11 +165,54 @@ impl<'a> SMBiosPortableBattery<'a> {
     pub fn oem_specific(&self) -> Option<u32> {
         self.parts.get_field_dword(0x16)
     }
+
+    /// Serial number of the battery, taken from 'sbds_serial_number'
+    /// (as four hex digits) when the 'serial_number' string is not set
+    pub fn effective_serial_number(&self) -> Option<String> {
+        match self.parts.get_field_byte(0x07)? {
+            0 => self
+                .sbds_serial_number()
+                .map(|serial| format!("{:04X}", serial)),
+            _ => self.serial_number().ok(),
+        }
+    }
+
+    /// Manufacture date of the battery, decoded from the packed
+    /// 'sbds_manufacture_date' (as YYYY-MM-DD) when the 'manufacture_date'
+    /// string is not set
+    pub fn effective_manufacture_date(&self) -> Option<String> {
+        match self.parts.get_field_byte(0x06)? {
+            0 => self.sbds_manufacture_date().map(|date| {
+                // Bits 15:9 year biased by 1980, bits 8:5 month, bits 4:0 day
+                format!(
+                    "{}-{:02}-{:02}",
+                    1980 + (date >> 9),
+                    (date >> 5) & 0x0F,
+                    date & 0x1F
+                )
+            }),
+            _ => self.manufacture_date().ok(),
+        }
+    }
+
+    /// Design capacity of the battery in mWatt-hours, scaled by
+    /// 'design_capacity_multiplier' when present (version 2.2+)
+    ///
+    /// Returns None when the design capacity is unknown.
+    pub fn effective_design_capacity(&self) -> Option<u32> {
+        match self.design_capacity()? {
+            PortableBatteryDesignCapacity::MilliWattHours(mwh) => {
+                let multiplier = self.design_capacity_multiplier().unwrap_or(1).max(1);
+                Some(mwh as u32 * multiplier as u32)
+            }
+            PortableBatteryDesignCapacity::Unknown => None,
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosPortableBattery<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosPortableBattery<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosPortableBattery<'_>>())
             .field("header", &self.parts.header)
             .field("location", &self.location())
             .field("manufacturer", &self.manufacturer())
@@ -241,7 +286,7 @@ pub struct PortableBatteryDeviceChemistryData {
 
 impl fmt::Debug for PortableBatteryDeviceChemistryData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<PortableBatteryDeviceChemistryData>())
+        fmt.debug_struct(core::any::type_name::<PortableBatteryDeviceChemistryData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -269,7 +314,7 @@ impl Deref for PortableBatteryDeviceChemistryData {
 }
 
 /// # Portable Battery - Device Chemistry
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PortableBatteryDeviceChemistry {
     /// Other
     Other,
@@ -295,6 +340,22 @@ pub enum PortableBatteryDeviceChemistry {
     None,
 }
 
+impl fmt::Display for PortableBatteryDeviceChemistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PortableBatteryDeviceChemistry::Other => "Other",
+            PortableBatteryDeviceChemistry::Unknown => "Unknown",
+            PortableBatteryDeviceChemistry::LeadAcid => "Lead Acid",
+            PortableBatteryDeviceChemistry::NickelCadmium => "Nickel Cadmium",
+            PortableBatteryDeviceChemistry::NickelMetalHydride => "Nickel metal hydride",
+            PortableBatteryDeviceChemistry::LithiumIon => "Lithium-ion",
+            PortableBatteryDeviceChemistry::ZincAir => "Zinc air",
+            PortableBatteryDeviceChemistry::LithiumPolymer => "Lithium Polymer",
+            PortableBatteryDeviceChemistry::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for PortableBatteryDeviceChemistryData {
     fn from(raw: u8) -> Self {
         PortableBatteryDeviceChemistryData {
@@ -315,7 +376,7 @@ impl From<u8> for PortableBatteryDeviceChemistryData {
 }
 
 /// # Portable Battery - Design Capacity
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PortableBatteryDesignCapacity {
     /// Design capacity of the battery in mWatt-hours
     ///
@@ -327,6 +388,17 @@ pub enum PortableBatteryDesignCapacity {
     Unknown,
 }
 
+impl fmt::Display for PortableBatteryDesignCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PortableBatteryDesignCapacity::MilliWattHours(capacity) => {
+                write!(f, "{} mWh", capacity)
+            }
+            PortableBatteryDesignCapacity::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for PortableBatteryDesignCapacity {
     fn from(raw: u16) -> Self {
         match raw {
@@ -337,7 +409,7 @@ impl From<u16> for PortableBatteryDesignCapacity {
 }
 
 /// # Portable Battery - Design Voltage
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PortableBatteryDesignVoltage {
     /// Design voltage of the battery in mVolts.
     MilliVolts(u16),
@@ -345,6 +417,15 @@ pub enum PortableBatteryDesignVoltage {
     Unknown,
 }
 
+impl fmt::Display for PortableBatteryDesignVoltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PortableBatteryDesignVoltage::MilliVolts(voltage) => write!(f, "{} mV", voltage),
+            PortableBatteryDesignVoltage::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for PortableBatteryDesignVoltage {
     fn from(raw: u16) -> Self {
         match raw {
@@ -357,6 +438,7 @@ impl From<u16> for PortableBatteryDesignVoltage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{SMBiosStructError, StructureBuilder};
 
     #[test]
     fn unit_test() {
@@ -401,4 +483,91 @@ mod tests {
         assert_eq!(test_struct.design_capacity_multiplier(), Some(10));
         assert_eq!(test_struct.oem_specific(), Some(0));
     }
+
+    #[test]
+    fn test_effective_values() {
+        // SBDS battery: serial number and manufacture date strings are 0
+        let struct_type22 = vec![
+            0x16, 0x1A, 0x2E, 0x00, 0x01, 0x02, 0x00, 0x00, 0x03, 0x02, 0xFB, 0x11, 0xD0, 0x39,
+            0x04, 0xFF, 0xC7, 0x02, 0x7A, 0x42, 0x05, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x52, 0x65,
+            0x61, 0x72, 0x00, 0x53, 0x4D, 0x50, 0x00, 0x34, 0x35, 0x4E, 0x31, 0x30, 0x37, 0x31,
+            0x00, 0x30, 0x33, 0x2E, 0x30, 0x31, 0x00, 0x4C, 0x69, 0x50, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type22);
+        let test_struct = SMBiosPortableBattery::new(&parts);
+
+        assert_eq!(
+            test_struct.effective_serial_number(),
+            Some("02C7".to_string())
+        );
+        assert_eq!(
+            test_struct.effective_manufacture_date(),
+            Some("2013-03-26".to_string())
+        );
+        assert_eq!(test_struct.effective_design_capacity(), Some(46030));
+
+        // Pre-2.2 battery: strings are used and there is no multiplier
+        let struct_type22 = vec![
+            0x16, 0x10, 0x2F, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x10, 0x27, 0xD0, 0x39,
+            0x00, 0x05, b'L', 0x00, b'M', 0x00, b'0', b'3', b'/', b'1', b'5', b'/', b'2', b'0',
+            b'2', b'1', 0x00, b'S', b'N', b'1', 0x00, b'D', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type22);
+        let test_struct = SMBiosPortableBattery::new(&parts);
+
+        assert_eq!(
+            test_struct.effective_serial_number(),
+            Some("SN1".to_string())
+        );
+        assert_eq!(
+            test_struct.effective_manufacture_date(),
+            Some("03/15/2021".to_string())
+        );
+        assert_eq!(test_struct.effective_design_capacity(), Some(10000));
+    }
+
+    #[test]
+    fn test_validate() {
+        // SMBIOS 2.1 battery: length 0x10, no SBDS fields
+        let raw = StructureBuilder::new(SMBiosPortableBattery::STRUCT_TYPE, 0x002F)
+            .string("L") // 0x04 Location
+            .string("M") // 0x05 Manufacturer
+            .string("03/15/2021") // 0x06 Manufacture Date
+            .string("SN1") // 0x07 Serial Number
+            .string("D") // 0x08 Device Name
+            .byte(0x06) // 0x09 Device Chemistry
+            .word(10000) // 0x0A Design Capacity
+            .word(14800) // 0x0C Design Voltage
+            .string_ref(0) // 0x0E SBDS Version Number
+            .byte(0x05) // 0x0F Maximum Error in Battery Data
+            .build();
+
+        let parts = UndefinedStruct::new(&raw);
+        let test_struct = SMBiosPortableBattery::new(&parts);
+
+        assert_eq!(raw[1], 0x10);
+        assert_eq!(test_struct.validate(), Ok(()));
+        assert_eq!(test_struct.sbds_serial_number(), None);
+        assert_eq!(test_struct.design_capacity_multiplier(), None);
+
+        // Declares a length of 0x0E, shorter than the SMBIOS 2.1 length of 0x10
+        let raw = StructureBuilder::new(SMBiosPortableBattery::STRUCT_TYPE, 0x0030)
+            .bytes(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06])
+            .word(10000)
+            .word(14800)
+            .add_string("L")
+            .build();
+
+        let parts = UndefinedStruct::new(&raw);
+        assert_eq!(
+            SMBiosPortableBattery::new(&parts).validate(),
+            Err(SMBiosStructError::LengthTooShort {
+                struct_type: 22,
+                length: 0x0E,
+                min_length: SMBiosPortableBattery::MIN_LENGTH
+            })
+        );
+    }
 }
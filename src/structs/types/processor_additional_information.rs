@@ -1,8 +1,9 @@
 use crate::core::{Handle, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::convert::TryInto;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # Processor Additional Information (Type 44)
 ///
@@ -25,6 +26,7 @@ pub struct SMBiosProcessorAdditionalInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosProcessorAdditionalInformation<'a> {
     const STRUCT_TYPE: u8 = 44u8;
+    const MIN_LENGTH: u8 = 0x06;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -54,7 +56,7 @@ impl<'a> SMBiosProcessorAdditionalInformation<'a> {
 
 impl fmt::Debug for SMBiosProcessorAdditionalInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<
+        fmt.debug_struct(core::any::type_name::<
             SMBiosProcessorAdditionalInformation<'_>,
         >())
         .field("header", &self.parts.header)
@@ -127,11 +129,25 @@ impl<'a> ProcessorSpecificBlock<'a> {
     pub fn processor_specific_data(&self) -> &'a [u8] {
         &self.raw[Self::PROCESSOR_SPECIFIC_DATA_OFFSET..]
     }
+
+    /// RISC-V processor-specific data
+    ///
+    /// Returns None unless 'processor_type' is a RISC-V architecture.
+    pub fn risc_v_data(&self) -> Option<RiscVProcessorSpecificData<'a>> {
+        match *self.processor_type() {
+            ProcessorArchitectureType::RiscV32Bit
+            | ProcessorArchitectureType::RiscV64Bit
+            | ProcessorArchitectureType::RiscV128Bit => Some(RiscVProcessorSpecificData {
+                raw: self.processor_specific_data(),
+            }),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for ProcessorSpecificBlock<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorSpecificBlock<'_>>())
+        fmt.debug_struct(core::any::type_name::<ProcessorSpecificBlock<'_>>())
             .field("block_length", &self.block_length())
             .field("processor_type", &self.processor_type())
             .field("processor_specific_data", &self.processor_specific_data())
@@ -152,6 +168,130 @@ impl Serialize for ProcessorSpecificBlock<'_> {
     }
 }
 
+/// # RISC-V Processor-Specific Data
+///
+/// Layout of 'processor_specific_data' as defined by the RISC-V SMBIOS
+/// Type 44 specification.
+pub struct RiscVProcessorSpecificData<'a> {
+    /// Raw byte slice of the processor-specific data
+    pub raw: &'a [u8],
+}
+
+impl<'a> RiscVProcessorSpecificData<'a> {
+    fn get_u128(&self, offset: usize) -> Option<u128> {
+        self.raw
+            .get(offset..offset + 16)
+            .map(|bytes| u128::from_le_bytes(bytes.try_into().expect("16 bytes")))
+    }
+
+    /// Revision of the RISC-V processor-specific data
+    ///
+    /// Bits 15:8 major revision, bits 7:0 minor revision
+    pub fn revision(&self) -> Option<u16> {
+        self.raw
+            .get(0x00..0x02)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().expect("2 bytes")))
+    }
+
+    /// Length of the RISC-V processor-specific data
+    pub fn structure_length(&self) -> Option<u8> {
+        self.raw.get(0x02).copied()
+    }
+
+    /// ID of the hart
+    pub fn hart_id(&self) -> Option<u128> {
+        self.get_u128(0x03)
+    }
+
+    /// Set if the hart is the boot hart
+    pub fn boot_hart(&self) -> Option<bool> {
+        self.raw.get(0x13).map(|&raw| raw == 1)
+    }
+
+    /// Machine Vendor ID (mvendorid CSR)
+    pub fn machine_vendor_id(&self) -> Option<u128> {
+        self.get_u128(0x14)
+    }
+
+    /// Machine Architecture ID (marchid CSR)
+    pub fn machine_architecture_id(&self) -> Option<u128> {
+        self.get_u128(0x24)
+    }
+
+    /// Machine Implementation ID (mimpid CSR)
+    pub fn machine_implementation_id(&self) -> Option<u128> {
+        self.get_u128(0x34)
+    }
+
+    /// Bit field of the supported instruction set extensions (misa CSR)
+    pub fn instruction_set_supported(&self) -> Option<u32> {
+        self.raw
+            .get(0x44..0x48)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().expect("4 bytes")))
+    }
+
+    /// Bit field of the supported privilege levels
+    ///
+    /// Bit 0 machine mode, bit 2 supervisor mode, bit 3 user mode,
+    /// bit 7 debug mode
+    pub fn privilege_level_supported(&self) -> Option<u8> {
+        self.raw.get(0x48).copied()
+    }
+}
+
+impl fmt::Debug for RiscVProcessorSpecificData<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(core::any::type_name::<RiscVProcessorSpecificData<'_>>())
+            .field("revision", &self.revision())
+            .field("structure_length", &self.structure_length())
+            .field("hart_id", &self.hart_id())
+            .field("boot_hart", &self.boot_hart())
+            .field("machine_vendor_id", &self.machine_vendor_id())
+            .field("machine_architecture_id", &self.machine_architecture_id())
+            .field(
+                "machine_implementation_id",
+                &self.machine_implementation_id(),
+            )
+            .field(
+                "instruction_set_supported",
+                &self.instruction_set_supported(),
+            )
+            .field(
+                "privilege_level_supported",
+                &self.privilege_level_supported(),
+            )
+            .finish()
+    }
+}
+
+impl Serialize for RiscVProcessorSpecificData<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVProcessorSpecificData", 9)?;
+        state.serialize_field("revision", &self.revision())?;
+        state.serialize_field("structure_length", &self.structure_length())?;
+        state.serialize_field("hart_id", &self.hart_id())?;
+        state.serialize_field("boot_hart", &self.boot_hart())?;
+        state.serialize_field("machine_vendor_id", &self.machine_vendor_id())?;
+        state.serialize_field("machine_architecture_id", &self.machine_architecture_id())?;
+        state.serialize_field(
+            "machine_implementation_id",
+            &self.machine_implementation_id(),
+        )?;
+        state.serialize_field(
+            "instruction_set_supported",
+            &self.instruction_set_supported(),
+        )?;
+        state.serialize_field(
+            "privilege_level_supported",
+            &self.privilege_level_supported(),
+        )?;
+        state.end()
+    }
+}
+
 /// # Processor Architecture Types Data
 pub struct ProcessorArchitectureTypeData {
     /// Raw value
@@ -167,7 +307,7 @@ pub struct ProcessorArchitectureTypeData {
 
 impl fmt::Debug for ProcessorArchitectureTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorArchitectureTypeData>())
+        fmt.debug_struct(core::any::type_name::<ProcessorArchitectureTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -190,7 +330,7 @@ impl fmt::Display for ProcessorArchitectureTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ProcessorArchitectureType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -204,7 +344,7 @@ impl Deref for ProcessorArchitectureTypeData {
 }
 
 /// # Processor Architecture Types
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorArchitectureType {
     /// IA32 (x86)
     IA32,
@@ -226,6 +366,22 @@ pub enum ProcessorArchitectureType {
     None,
 }
 
+impl fmt::Display for ProcessorArchitectureType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ProcessorArchitectureType::IA32 => "IA32 (x86)",
+            ProcessorArchitectureType::X64 => "x64 (x86-64, Intel64, AMD64, EM64T)",
+            ProcessorArchitectureType::IntelItanium => "Intel® Itanium® architecture",
+            ProcessorArchitectureType::Arm32Bit => "32-bit ARM (Aarch32)",
+            ProcessorArchitectureType::Arm64Bit => "64-bit ARM (Aarch64)",
+            ProcessorArchitectureType::RiscV32Bit => "32-bit RISC-V (RV32)",
+            ProcessorArchitectureType::RiscV64Bit => "64-bit RISC-V (RV64)",
+            ProcessorArchitectureType::RiscV128Bit => "128-bit RISC-V (RV128)",
+            ProcessorArchitectureType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ProcessorArchitectureTypeData {
     fn from(raw: u8) -> Self {
         ProcessorArchitectureTypeData {
@@ -248,6 +404,7 @@ impl From<u8> for ProcessorArchitectureTypeData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -285,4 +442,59 @@ mod tests {
             &[0x03, 0x02, 0x01]
         );
     }
+
+    #[test]
+    fn test_risc_v_data() {
+        let mut risc_v_data = vec![0u8; 0x6E];
+        // revision 1.0, structure length
+        risc_v_data[0x00..0x03].copy_from_slice(&[0x00, 0x01, 0x6E]);
+        // hart ID 2, boot hart
+        risc_v_data[0x03] = 0x02;
+        risc_v_data[0x13] = 0x01;
+        // machine vendor, architecture and implementation IDs
+        risc_v_data[0x14..0x18].copy_from_slice(&[0x89, 0x04, 0x00, 0x00]);
+        risc_v_data[0x24..0x2C].copy_from_slice(&[0x07, 0, 0, 0, 0, 0, 0, 0x80]);
+        risc_v_data[0x34..0x36].copy_from_slice(&[0x01, 0x20]);
+        // instruction set: RV64IMAFDC, privilege levels: M, S and U
+        risc_v_data[0x44..0x48].copy_from_slice(&[0x2D, 0x11, 0x14, 0x00]);
+        risc_v_data[0x48] = 0x0D;
+
+        let mut struct_type44 = vec![44u8, 0x00, 0x2F, 0x00, 0x08, 0x09, 0x6E, 0x07];
+        struct_type44.extend_from_slice(&risc_v_data);
+        struct_type44[1] = struct_type44.len() as u8;
+        struct_type44.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type44);
+        let test_struct = SMBiosProcessorAdditionalInformation::new(&parts);
+
+        let processor_specific_block = test_struct.processor_specific_block().unwrap();
+        assert_eq!(processor_specific_block.block_length(), 0x6E);
+        let risc_v = processor_specific_block.risc_v_data().unwrap();
+        assert_eq!(risc_v.revision(), Some(0x0100));
+        assert_eq!(risc_v.structure_length(), Some(0x6E));
+        assert_eq!(risc_v.hart_id(), Some(2));
+        assert_eq!(risc_v.boot_hart(), Some(true));
+        assert_eq!(risc_v.machine_vendor_id(), Some(0x489));
+        assert_eq!(
+            risc_v.machine_architecture_id(),
+            Some(0x8000_0000_0000_0007)
+        );
+        assert_eq!(risc_v.machine_implementation_id(), Some(0x2001));
+        assert_eq!(risc_v.instruction_set_supported(), Some(0x0014_112D));
+        assert_eq!(risc_v.privilege_level_supported(), Some(0x0D));
+
+        // Not RISC-V
+        let struct_type44 = vec![
+            44u8, 0x0A, 0x30, 0x00, 0x08, 0x09, 0x02, 0x05, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type44);
+        let test_struct = SMBiosProcessorAdditionalInformation::new(&parts);
+
+        assert!(test_struct
+            .processor_specific_block()
+            .unwrap()
+            .risc_v_data()
+            .is_none());
+    }
 }
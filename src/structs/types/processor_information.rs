@@ -1,9 +1,10 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::prelude::*;
+use crate::{SMBiosCacheInformation, SMBiosData, SMBiosStruct, SMBiosVersion};
+use core::convert::TryInto;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::convert::TryInto;
-use std::fmt;
-use std::ops::Deref;
 
 /// # Processor Information (Type 4)
 ///
@@ -26,6 +27,7 @@ pub struct SMBiosProcessorInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosProcessorInformation<'a> {
     const STRUCT_TYPE: u8 = 4u8;
+    const MIN_LENGTH: u8 = 0x1A;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -149,6 +151,14 @@ impl<'a> SMBiosProcessorInformation<'a> {
         self.parts.get_field_handle(0x1A)
     }
 
+    /// The L1 cache referenced by [SMBiosProcessorInformation::l1cache_handle]
+    pub fn l1cache_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosCacheInformation<'b>> {
+        data.resolve(self.l1cache_handle()?)
+    }
+
     /// Handle of a [super::SMBiosCacheInformation] structure that
     /// defines the attributes of the primary (Level 2)
     /// cache for this processor
@@ -162,6 +172,14 @@ impl<'a> SMBiosProcessorInformation<'a> {
         self.parts.get_field_handle(0x1C)
     }
 
+    /// The L2 cache referenced by [SMBiosProcessorInformation::l2cache_handle]
+    pub fn l2cache_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosCacheInformation<'b>> {
+        data.resolve(self.l2cache_handle()?)
+    }
+
     /// Handle of a [super::SMBiosCacheInformation] structure that
     /// defines the attributes of the primary (Level 3)
     /// cache for this processor
@@ -175,6 +193,14 @@ impl<'a> SMBiosProcessorInformation<'a> {
         self.parts.get_field_handle(0x1E)
     }
 
+    /// The L3 cache referenced by [SMBiosProcessorInformation::l3cache_handle]
+    pub fn l3cache_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosCacheInformation<'b>> {
+        data.resolve(self.l3cache_handle()?)
+    }
+
     /// The serial number of this processor
     ///
     /// This value is set by the manufacturer and
@@ -242,6 +268,20 @@ impl<'a> SMBiosProcessorInformation<'a> {
             .map(|raw| ProcessorFamilyData2::from(raw))
     }
 
+    /// Processor family resolved from 'processor_family' and 'processor_family_2'
+    ///
+    /// When 'processor_family' is 'ProcessorFamily::SeeProcessorFamily2' (FEh)
+    /// the family is read from 'processor_family_2'; otherwise the
+    /// 'processor_family' byte is used.
+    pub fn effective_processor_family(&self) -> Option<ProcessorFamilyData2> {
+        match self.processor_family()? {
+            family if family.value == ProcessorFamily::SeeProcessorFamily2 => {
+                self.processor_family_2()
+            }
+            family => Some(ProcessorFamilyData2::from(family.raw as u16)),
+        }
+    }
+
     /// Number of Cores per processor socket.
     ///
     /// Supports core counts >255. If this field is
@@ -250,7 +290,13 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// core count, except for core counts that are 256
     /// or greater. In that case, 'core_count' shall be set
     /// to 'CoreCount::SeeCoreCount2' and 'core_count_2' will hold the count.
+    ///
+    /// None when the table version predates SMBIOS 3.0, which added this field.
     pub fn core_count_2(&self) -> Option<CoreCount2> {
+        if self.parts.version_predates(SMBiosVersion::new(3, 0, 0)) {
+            return None;
+        }
+
         self.parts
             .get_field_word(0x2A)
             .map(|raw| CoreCount2::from(raw))
@@ -265,7 +311,13 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// counts that are 256 or greater. In that case,
     /// 'cores_enabled' shall be set to 'CoresEnabled::SeeCoresEnabled2'
     /// and 'cores_enabled_2' will hold the count.
+    ///
+    /// None when the table version predates SMBIOS 3.0, which added this field.
     pub fn cores_enabled_2(&self) -> Option<CoresEnabled2> {
+        if self.parts.version_predates(SMBiosVersion::new(3, 0, 0)) {
+            return None;
+        }
+
         self.parts
             .get_field_word(0x2C)
             .map(|raw| CoresEnabled2::from(raw))
@@ -280,7 +332,13 @@ impl<'a> SMBiosProcessorInformation<'a> {
     /// are 256 or greater. In that case, 'thread_count'
     /// shall be set to 'ThreadCount::SeeThreadCount2'
     /// and 'thread_count_2' will hold the count.
+    ///
+    /// None when the table version predates SMBIOS 3.0, which added this field.
     pub fn thread_count_2(&self) -> Option<ThreadCount2> {
+        if self.parts.version_predates(SMBiosVersion::new(3, 0, 0)) {
+            return None;
+        }
+
         self.parts
             .get_field_word(0x2E)
             .map(|raw| ThreadCount2::from(raw))
@@ -289,7 +347,7 @@ impl<'a> SMBiosProcessorInformation<'a> {
 
 impl fmt::Debug for SMBiosProcessorInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosProcessorInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosProcessorInformation<'_>>())
             .field("header", &self.parts.header)
             .field("socket_designation", &self.socket_designation())
             .field("processor_type", &self.processor_type())
@@ -379,7 +437,7 @@ pub struct ProcessorTypeData {
 
 impl fmt::Debug for ProcessorTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorTypeData>())
+        fmt.debug_struct(core::any::type_name::<ProcessorTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -402,7 +460,7 @@ impl fmt::Display for ProcessorTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ProcessorType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -416,7 +474,7 @@ impl Deref for ProcessorTypeData {
 }
 
 /// # Processor Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorType {
     /// Other
     Other,
@@ -434,6 +492,20 @@ pub enum ProcessorType {
     None,
 }
 
+impl fmt::Display for ProcessorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ProcessorType::Other => "Other",
+            ProcessorType::Unknown => "Unknown",
+            ProcessorType::CentralProcessor => "Central Processor",
+            ProcessorType::MathProcessor => "Math Processor",
+            ProcessorType::DspProcessor => "DSP Processor",
+            ProcessorType::VideoProcessor => "Video Processor",
+            ProcessorType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ProcessorTypeData {
     fn from(raw: u8) -> Self {
         ProcessorTypeData {
@@ -466,7 +538,7 @@ pub struct ProcessorFamilyData {
 
 impl fmt::Debug for ProcessorFamilyData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorFamilyData>())
+        fmt.debug_struct(core::any::type_name::<ProcessorFamilyData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -490,7 +562,7 @@ impl fmt::Display for ProcessorFamilyData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ProcessorFamily::None => write!(f, "{:#X}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -527,7 +599,7 @@ pub struct ProcessorFamilyData2 {
 
 impl fmt::Debug for ProcessorFamilyData2 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorFamilyData2>())
+        fmt.debug_struct(core::any::type_name::<ProcessorFamilyData2>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -551,7 +623,7 @@ impl fmt::Display for ProcessorFamilyData2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ProcessorFamily::None => write!(f, "{:#X}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -573,7 +645,7 @@ impl From<u16> for ProcessorFamilyData2 {
     }
 }
 /// # Processor Family
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorFamily {
     /// Other
     Other,
@@ -1011,6 +1083,320 @@ pub enum ProcessorFamily {
     None,
 }
 
+impl fmt::Display for ProcessorFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ProcessorFamily::Other => "Other",
+            ProcessorFamily::Unknown => "Unknown",
+            ProcessorFamily::I8086 => "8086",
+            ProcessorFamily::I80286 => "80286",
+            ProcessorFamily::Intel386Processor => "Intel386™ processor",
+            ProcessorFamily::Intel486Processor => "Intel486™ processor",
+            ProcessorFamily::I8087 => "8087",
+            ProcessorFamily::I80287 => "80287",
+            ProcessorFamily::I80387 => "80387",
+            ProcessorFamily::I80487 => "80487",
+            ProcessorFamily::IntelPentiumProcessor => "Intel® Pentium® processor",
+            ProcessorFamily::PentiumProProcessor => "Pentium® Pro processor",
+            ProcessorFamily::PentiumIIProcessor => "Pentium® II processor",
+            ProcessorFamily::PentiumprocessorwithMMXtechnology => {
+                "Pentium® processor with MMX™ technology"
+            }
+            ProcessorFamily::IntelCeleronProcessor => "Intel® Celeron® processor",
+            ProcessorFamily::PentiumIIXeonProcessor => "Pentium® II Xeon™ processor",
+            ProcessorFamily::PentiumIIIProcessor => "Pentium® III processor",
+            ProcessorFamily::M1Family => "M1 Family",
+            ProcessorFamily::M2Family => "M2 Family",
+            ProcessorFamily::IntelCeleronMProcessor => "Intel® Celeron® M processor",
+            ProcessorFamily::IntelPentium4HTProcessor => "Intel® Pentium® 4 HT processor",
+            ProcessorFamily::AMDDuronProcessorFamily => "AMD Duron™ Processor Family",
+            ProcessorFamily::K5Family => "K5 Family",
+            ProcessorFamily::K6Family => "K6 Family",
+            ProcessorFamily::K62 => "K6-2",
+            ProcessorFamily::K63 => "K6-3",
+            ProcessorFamily::AMDAthlonProcessorFamily => "AMD Athlon™ Processor Family",
+            ProcessorFamily::AMD29000Family => "AMD29000 Family",
+            ProcessorFamily::K62Plus => "K6-2+",
+            ProcessorFamily::PowerPCFamily => "Power PC Family",
+            ProcessorFamily::PowerPC601 => "Power PC 601",
+            ProcessorFamily::PowerPC603 => "Power PC 603",
+            ProcessorFamily::PowerPC603Plus => "Power PC 603+",
+            ProcessorFamily::PowerPC604 => "Power PC 604",
+            ProcessorFamily::PowerPC620 => "Power PC 620",
+            ProcessorFamily::PowerPCx704 => "Power PC x704",
+            ProcessorFamily::PowerPC750 => "Power PC 750",
+            ProcessorFamily::IntelCoreDuoProcessor => "Intel® Core™ Duo processor",
+            ProcessorFamily::IntelCoreDuomobileProcessor => "Intel® Core™ Duo mobile processor",
+            ProcessorFamily::IntelCoreSolomobileProcessor => "Intel® Core™ Solo mobile processor",
+            ProcessorFamily::IntelAtomProcessor => "Intel® Atom™ processor",
+            ProcessorFamily::IntelCoreMProcessor => "Intel® Core™ M processor",
+            ProcessorFamily::IntelCorem3Processor => "Intel(R) Core(TM) m3 processor",
+            ProcessorFamily::IntelCorem5Processor => "Intel(R) Core(TM) m5 processor",
+            ProcessorFamily::IntelCorem7Processor => "Intel(R) Core(TM) m7 processor",
+            ProcessorFamily::AlphaFamily => "Alpha Family",
+            ProcessorFamily::Alpha21064 => "Alpha 21064",
+            ProcessorFamily::Alpha21066 => "Alpha 21066",
+            ProcessorFamily::Alpha21164 => "Alpha 21164",
+            ProcessorFamily::Alpha21164PC => "Alpha 21164PC",
+            ProcessorFamily::Alpha21164a => "Alpha 21164a",
+            ProcessorFamily::Alpha21264 => "Alpha 21264",
+            ProcessorFamily::Alpha21364 => "Alpha 21364",
+            ProcessorFamily::AMDTurionIIUltraDualCoreMobileMProcessorFamily => {
+                "AMD Turion™ II Ultra Dual-Core Mobile M Processor Family"
+            }
+            ProcessorFamily::AMDTurionIIDualCoreMobileMProcessorFamily => {
+                "AMD Turion™ II Dual-Core Mobile M Processor Family"
+            }
+            ProcessorFamily::AMDAthlonIIDualCoreMProcessorFamily => {
+                "AMD Athlon™ II Dual-Core M Processor Family"
+            }
+            ProcessorFamily::AMDOpteron6100SeriesProcessor => "AMD Opteron™ 6100 Series Processor",
+            ProcessorFamily::AMDOpteron4100SeriesProcessor => "AMD Opteron™ 4100 Series Processor",
+            ProcessorFamily::AMDOpteron6200SeriesProcessor => "AMD Opteron™ 6200 Series Processor",
+            ProcessorFamily::AMDOpteron4200SeriesProcessor => "AMD Opteron™ 4200 Series Processor",
+            ProcessorFamily::AMDFXSeriesProcessor => "AMD FX™ Series Processor",
+            ProcessorFamily::MIPSFamily => "MIPS Family",
+            ProcessorFamily::MIPSR4000 => "MIPS R4000",
+            ProcessorFamily::MIPSR4200 => "MIPS R4200",
+            ProcessorFamily::MIPSR4400 => "MIPS R4400",
+            ProcessorFamily::MIPSR4600 => "MIPS R4600",
+            ProcessorFamily::MIPSR10000 => "MIPS R10000",
+            ProcessorFamily::AMDCSeriesProcessor => "AMD C-Series Processor",
+            ProcessorFamily::AMDESeriesProcessor => "AMD E-Series Processor",
+            ProcessorFamily::AMDASeriesProcessor => "AMD A-Series Processor",
+            ProcessorFamily::AMDGSeriesProcessor => "AMD G-Series Processor",
+            ProcessorFamily::AMDZSeriesProcessor => "AMD Z-Series Processor",
+            ProcessorFamily::AMDRSeriesProcessor => "AMD R-Series Processor",
+            ProcessorFamily::AMDOpteron4300SeriesProcessor => "AMD Opteron™ 4300 Series Processor",
+            ProcessorFamily::AMDOpteron6300SeriesProcessor => "AMD Opteron™ 6300 Series Processor",
+            ProcessorFamily::AMDOpteron3300SeriesProcessor => "AMD Opteron™ 3300 Series Processor",
+            ProcessorFamily::AMDFireProSeriesProcessor => "AMD FirePro™ Series Processor",
+            ProcessorFamily::SPARCFamily => "SPARC Family",
+            ProcessorFamily::SuperSPARC => "SuperSPARC",
+            ProcessorFamily::MicroSparcii => "microSPARC II",
+            ProcessorFamily::MicroSparciiep => "microSPARC IIep",
+            ProcessorFamily::UltraSPARC => "UltraSPARC",
+            ProcessorFamily::UltraSPARCII => "UltraSPARC II",
+            ProcessorFamily::UltraSPARCIii => "UltraSPARC IIi",
+            ProcessorFamily::UltraSPARCIII => "UltraSPARC III",
+            ProcessorFamily::UltraSPARCIIIi => "UltraSPARC IIIi",
+            ProcessorFamily::M68040Family => "68040 Family",
+            ProcessorFamily::M68xxx => "68xxx",
+            ProcessorFamily::M68000 => "68000",
+            ProcessorFamily::M68010 => "68010",
+            ProcessorFamily::M68020 => "68020",
+            ProcessorFamily::M68030 => "68030",
+            ProcessorFamily::AMDAthlonX4QuadCoreProcessorFamily => {
+                "AMD Athlon(TM) X4 Quad-Core Processor Family"
+            }
+            ProcessorFamily::AMDOpteronX1000SeriesProcessor => {
+                "AMD Opteron(TM) X1000 Series Processor"
+            }
+            ProcessorFamily::AMDOpteronX2000SeriesAPU => "AMD Opteron(TM) X2000 Series APU",
+            ProcessorFamily::AMDOpteronASeriesProcessor => "AMD Opteron(TM) A-Series Processor",
+            ProcessorFamily::AMDOpteronX3000SeriesAPU => "AMD Opteron(TM) X3000 Series APU",
+            ProcessorFamily::AMDZenProcessorFamily => "AMD Zen Processor Family",
+            ProcessorFamily::HobbitFamily => "Hobbit Family",
+            ProcessorFamily::CrusoeTM5000Family => "Crusoe™ TM5000 Family",
+            ProcessorFamily::CrusoeTM3000Family => "Crusoe™ TM3000 Family",
+            ProcessorFamily::EfficeonTM8000Family => "Efficeon™ TM8000 Family",
+            ProcessorFamily::Weitek => "Weitek",
+            ProcessorFamily::Itaniumprocessor => "Itanium™ processor",
+            ProcessorFamily::AMDAthlon64ProcessorFamily => "AMD Athlon™ 64 Processor Family",
+            ProcessorFamily::AMDOpteronProcessorFamily => "AMD Opteron™ Processor Family",
+            ProcessorFamily::AMDSempronProcessorFamily => "AMD Sempron™ Processor Family",
+            ProcessorFamily::AMDTurion64MobileTechnology => "AMD Turion™ 64 Mobile Technology",
+            ProcessorFamily::DualCoreAMDOpteronProcessorFamily => {
+                "Dual-Core AMD Opteron™ Processor Family"
+            }
+            ProcessorFamily::AMDAthlon64X2DualCoreProcessorFamily => {
+                "AMD Athlon™ 64 X2 Dual-Core Processor Family"
+            }
+            ProcessorFamily::AMDTurion64X2MobileTechnology => "AMD Turion™ 64 X2 Mobile Technology",
+            ProcessorFamily::QuadCoreAMDOpteronProcessorFamily => {
+                "Quad-Core AMD Opteron™ Processor Family"
+            }
+            ProcessorFamily::ThirdGenerationAMDOpteronProcessorFamily => {
+                "Third-Generation AMD Opteron™ Processor Family"
+            }
+            ProcessorFamily::AMDPhenomFXQuadCoreProcessorFamily => {
+                "AMD Phenom™ FX Quad-Core Processor Family"
+            }
+            ProcessorFamily::AMDPhenomX4QuadCoreProcessorFamily => {
+                "AMD Phenom™ X4 Quad-Core Processor Family"
+            }
+            ProcessorFamily::AMDPhenomX2DualCoreProcessorFamily => {
+                "AMD Phenom™ X2 Dual-Core Processor Family"
+            }
+            ProcessorFamily::AMDAthlonX2DualCoreProcessorFamily => {
+                "AMD Athlon™ X2 Dual-Core Processor Family"
+            }
+            ProcessorFamily::PARISCFamily => "PA-RISC Family",
+            ProcessorFamily::PARISC8500 => "PA-RISC 8500",
+            ProcessorFamily::PARISC8000 => "PA-RISC 8000",
+            ProcessorFamily::PARISC7300LC => "PA-RISC 7300LC",
+            ProcessorFamily::PARISC7200 => "PA-RISC 7200",
+            ProcessorFamily::PARISC7100LC => "PA-RISC 7100LC",
+            ProcessorFamily::PARISC7100 => "PA-RISC 7100",
+            ProcessorFamily::V30Family => "V30 Family",
+            ProcessorFamily::QuadCoreIntelXeonProcessor3200Series => {
+                "Quad-Core Intel® Xeon® processor 3200 Series"
+            }
+            ProcessorFamily::DualCoreIntelXeonProcessor3000Series => {
+                "Dual-Core Intel® Xeon® processor 3000 Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor5300Series => {
+                "Quad-Core Intel® Xeon® processor 5300 Series"
+            }
+            ProcessorFamily::DualCoreIntelXeonProcessor5100Series => {
+                "Dual-Core Intel® Xeon® processor 5100 Series"
+            }
+            ProcessorFamily::DualCoreIntelXeonProcessor5000Series => {
+                "Dual-Core Intel® Xeon® processor 5000 Series"
+            }
+            ProcessorFamily::DualCoreIntelXeonProcessorLV => "Dual-Core Intel® Xeon® processor LV",
+            ProcessorFamily::DualCoreIntelXeonProcessorULV => {
+                "Dual-Core Intel® Xeon® processor ULV"
+            }
+            ProcessorFamily::DualCoreIntelXeonProcessor7100Series => {
+                "Dual-Core Intel® Xeon® processor 7100 Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor5400Series => {
+                "Quad-Core Intel® Xeon® processor 5400 Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor => "Quad-Core Intel® Xeon® processor",
+            ProcessorFamily::DualCoreIntelXeonProcessor5200Series => {
+                "Dual-Core Intel® Xeon® processor 5200 Series"
+            }
+            ProcessorFamily::DualCoreIntelXeonProcessor7200Series => {
+                "Dual-Core Intel® Xeon® processor 7200 Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor7300Series => {
+                "Quad-Core Intel® Xeon® processor 7300 Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor7400Series => {
+                "Quad-Core Intel® Xeon® processor 7400 Series"
+            }
+            ProcessorFamily::MultiCoreIntelXeonProcessor7400Series => {
+                "Multi-Core Intel® Xeon® processor 7400 Series"
+            }
+            ProcessorFamily::PentiumIIIXeonProcessor => "Pentium® III Xeon™ processor",
+            ProcessorFamily::PentiumIIIProcessorwithIntelSpeedStepTechnology => {
+                "Pentium® III Processor with Intel® SpeedStep™ Technology"
+            }
+            ProcessorFamily::Pentium4Processor => "Pentium® 4 Processor",
+            ProcessorFamily::IntelXeonProcessor => "Intel® Xeon® processor",
+            ProcessorFamily::AS400Family => "AS400 Family",
+            ProcessorFamily::IntelXeonProcessorMP => "Intel® Xeon™ processor MP",
+            ProcessorFamily::AMDAthlonXPProcessorFamily => "AMD Athlon™ XP Processor Family",
+            ProcessorFamily::AMDAthlonMPProcessorFamily => "AMD Athlon™ MP Processor Family",
+            ProcessorFamily::IntelItanium2Processor => "Intel® Itanium® 2 processor",
+            ProcessorFamily::IntelPentiumMProcessor => "Intel® Pentium® M processor",
+            ProcessorFamily::IntelCeleronDProcessor => "Intel® Celeron® D processor",
+            ProcessorFamily::IntelPentiumDProcessor => "Intel® Pentium® D processor",
+            ProcessorFamily::IntelPentiumProcessorExtremeEdition => {
+                "Intel® Pentium® Processor Extreme Edition"
+            }
+            ProcessorFamily::IntelCoreSoloProcessor => "Intel® Core™ Solo Processor",
+            ProcessorFamily::IntelCore2DuoProcessor => "Intel® Core™ 2 Duo Processor",
+            ProcessorFamily::IntelCore2SoloProcessor => "Intel® Core™ 2 Solo processor",
+            ProcessorFamily::IntelCore2ExtremeProcessor => "Intel® Core™ 2 Extreme processor",
+            ProcessorFamily::IntelCore2QuadProcessor => "Intel® Core™ 2 Quad processor",
+            ProcessorFamily::IntelCore2ExtremeMobileProcessor => {
+                "Intel® Core™ 2 Extreme mobile processor"
+            }
+            ProcessorFamily::IntelCore2DuoMobileProcessor => "Intel® Core™ 2 Duo mobile processor",
+            ProcessorFamily::IntelCore2SoloMobileProcessor => {
+                "Intel® Core™ 2 Solo mobile processor"
+            }
+            ProcessorFamily::IntelCorei7Processor => "Intel® Core™ i7 processor",
+            ProcessorFamily::DualCoreIntelCeleronProcessor => "Dual-Core Intel® Celeron® processor",
+            ProcessorFamily::IBM390Family => "IBM390 Family",
+            ProcessorFamily::G4 => "G4",
+            ProcessorFamily::G5 => "G5",
+            ProcessorFamily::ESA390G6 => "ESA/390 G6",
+            ProcessorFamily::ZArchitecturebase => "z/Architecture base",
+            ProcessorFamily::IntelCorei5processor => "Intel® Core™ i5 processor",
+            ProcessorFamily::IntelCorei3processor => "Intel® Core™ i3 processor",
+            ProcessorFamily::IntelCorei9processor => "Intel® Core™ i9 processor",
+            ProcessorFamily::VIAC7MProcessorFamily => "VIA C7™-M Processor Family",
+            ProcessorFamily::VIAC7DProcessorFamily => "VIA C7™-D Processor Family",
+            ProcessorFamily::VIAC7ProcessorFamily => "VIA C7™ Processor Family",
+            ProcessorFamily::VIAEdenProcessorFamily => "VIA Eden™ Processor Family",
+            ProcessorFamily::MultiCoreIntelXeonProcessor => "Multi-Core Intel® Xeon® processor",
+            ProcessorFamily::DualCoreIntelXeonProcessor3xxxSeries => {
+                "Dual-Core Intel® Xeon® processor 3xxx Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor3xxxSeries => {
+                "Quad-Core Intel® Xeon® processor 3xxx Series"
+            }
+            ProcessorFamily::VIANanoProcessorFamily => "VIA Nano™ Processor Family",
+            ProcessorFamily::DualCoreIntelXeonProcessor5xxxSeries => {
+                "Dual-Core Intel® Xeon® processor 5xxx Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor5xxxSeries => {
+                "Quad-Core Intel® Xeon® processor 5xxx Series"
+            }
+            ProcessorFamily::DualCoreIntelXeonProcessor7xxxSeries => {
+                "Dual-Core Intel® Xeon® processor 7xxx Series"
+            }
+            ProcessorFamily::QuadCoreIntelXeonProcessor7xxxSeries => {
+                "Quad-Core Intel® Xeon® processor 7xxx Series"
+            }
+            ProcessorFamily::MultiCoreIntelXeonProcessor7xxxSeries => {
+                "Multi-Core Intel® Xeon® processor 7xxx Series"
+            }
+            ProcessorFamily::MultiCoreIntelXeonProcessor3400Series => {
+                "Multi-Core Intel® Xeon® processor 3400 Series"
+            }
+            ProcessorFamily::AMDOpteron3000SeriesProcessor => "AMD Opteron™ 3000 Series Processor",
+            ProcessorFamily::AMDSempronIIProcessor => "AMD Sempron™ II Processor",
+            ProcessorFamily::EmbeddedAMDOpteronQuadCoreProcessorFamily => {
+                "Embedded AMD Opteron™ Quad-Core Processor Family"
+            }
+            ProcessorFamily::AMDPhenomTripleCoreProcessorFamily => {
+                "AMD Phenom™ Triple-Core Processor Family"
+            }
+            ProcessorFamily::AMDTurionUltraDualCoreMobileProcessorFamily => {
+                "AMD Turion™ Ultra Dual-Core Mobile Processor Family"
+            }
+            ProcessorFamily::AMDTurionDualCoreMobileProcessorFamily => {
+                "AMD Turion™ Dual-Core Mobile Processor Family"
+            }
+            ProcessorFamily::AMDAthlonDualCoreProcessorFamily => {
+                "AMD Athlon™ Dual-Core Processor Family"
+            }
+            ProcessorFamily::AMDSempronSIProcessorFamily => "AMD Sempron™ SI Processor Family",
+            ProcessorFamily::AMDPhenomIIProcessorFamily => "AMD Phenom™ II Processor Family",
+            ProcessorFamily::AMDAthlonIIProcessorFamily => "AMD Athlon™ II Processor Family",
+            ProcessorFamily::SixCoreAMDOpteronProcessorFamily => {
+                "Six-Core AMD Opteron™ Processor Family"
+            }
+            ProcessorFamily::AMDSempronMProcessorFamily => "AMD Sempron™ M Processor Family",
+            ProcessorFamily::I860 => "i860",
+            ProcessorFamily::I960 => "i960",
+            ProcessorFamily::SeeProcessorFamily2 => "See Processor Family 2",
+            ProcessorFamily::ARMv7 => "ARMv7",
+            ProcessorFamily::ARMv8 => "ARMv8",
+            ProcessorFamily::ARMv9 => "ARMv9",
+            ProcessorFamily::SH3 => "SH-3",
+            ProcessorFamily::SH4 => "SH-4",
+            ProcessorFamily::ARM => "ARM",
+            ProcessorFamily::StrongARM => "StrongARM",
+            ProcessorFamily::Cyrix6x86 => "6x86",
+            ProcessorFamily::MediaGX => "MediaGX",
+            ProcessorFamily::MII => "MII",
+            ProcessorFamily::WinChip => "WinChip",
+            ProcessorFamily::DSP => "DSP",
+            ProcessorFamily::VideoProcessor => "Video Processor",
+            ProcessorFamily::RISCVRV32 => "RISC-V RV32",
+            ProcessorFamily::RISCVRV64 => "RISC-V RV64",
+            ProcessorFamily::RISCVRV128 => "RISC-V RV128",
+            ProcessorFamily::None => "Undefined",
+        })
+    }
+}
+
 impl From<u16> for ProcessorFamily {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1250,7 +1636,7 @@ pub struct ProcessorUpgradeData {
 
 impl fmt::Debug for ProcessorUpgradeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorUpgradeData>())
+        fmt.debug_struct(core::any::type_name::<ProcessorUpgradeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -1278,7 +1664,7 @@ impl Deref for ProcessorUpgradeData {
 }
 
 /// #
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ProcessorUpgrade {
     /// Other
     Other,
@@ -1416,6 +1802,80 @@ pub enum ProcessorUpgrade {
     None,
 }
 
+impl fmt::Display for ProcessorUpgrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ProcessorUpgrade::Other => "Other",
+            ProcessorUpgrade::Unknown => "Unknown",
+            ProcessorUpgrade::DaughterBoard => "Daughter Board",
+            ProcessorUpgrade::ZIFSocket => "ZIF Socket",
+            ProcessorUpgrade::ReplaceablePiggyBack => "Replaceable Piggy Back",
+            ProcessorUpgrade::NoUpgrade => "No Upgrade",
+            ProcessorUpgrade::LIFSocket => "LIF Socket",
+            ProcessorUpgrade::Slot1 => "Slot 1",
+            ProcessorUpgrade::Slot2 => "Slot 2",
+            ProcessorUpgrade::PinSocket370 => "370-pin socket",
+            ProcessorUpgrade::SlotA => "Slot A",
+            ProcessorUpgrade::SlotM => "Slot M",
+            ProcessorUpgrade::Socket423 => "Socket 423",
+            ProcessorUpgrade::SocketASocket462 => "Socket A (Socket 462)",
+            ProcessorUpgrade::Socket478 => "Socket 478",
+            ProcessorUpgrade::Socket754 => "Socket 754",
+            ProcessorUpgrade::Socket940 => "Socket 940",
+            ProcessorUpgrade::Socket939 => "Socket 939",
+            ProcessorUpgrade::SocketmPGA604 => "Socket mPGA604",
+            ProcessorUpgrade::SocketLGA771 => "Socket LGA771",
+            ProcessorUpgrade::SocketLGA775 => "Socket LGA775",
+            ProcessorUpgrade::SocketS1 => "Socket S1",
+            ProcessorUpgrade::SocketAM2 => "Socket AM2",
+            ProcessorUpgrade::SocketF1207 => "Socket F (1207)",
+            ProcessorUpgrade::SocketLGA1366 => "Socket LGA1366",
+            ProcessorUpgrade::SocketG34 => "Socket G34",
+            ProcessorUpgrade::SocketAM3 => "Socket AM3",
+            ProcessorUpgrade::SocketC32 => "Socket C32",
+            ProcessorUpgrade::SocketLGA1156 => "Socket LGA1156",
+            ProcessorUpgrade::SocketLGA1567 => "Socket LGA1567",
+            ProcessorUpgrade::SocketPGA988A => "Socket PGA988A",
+            ProcessorUpgrade::SocketBGA1288 => "Socket BGA1288",
+            ProcessorUpgrade::SocketrPGA988B => "Socket rPGA988B",
+            ProcessorUpgrade::SocketBGA1023 => "Socket BGA1023",
+            ProcessorUpgrade::SocketBGA1224 => "Socket BGA1224",
+            ProcessorUpgrade::SocketLGA1155 => "Socket LGA1155",
+            ProcessorUpgrade::SocketLGA1356 => "Socket LGA1356",
+            ProcessorUpgrade::SocketLGA2011 => "Socket LGA2011",
+            ProcessorUpgrade::SocketFS1 => "Socket FS1",
+            ProcessorUpgrade::SocketFS2 => "Socket FS2",
+            ProcessorUpgrade::SocketFM1 => "Socket FM1",
+            ProcessorUpgrade::SocketFM2 => "Socket FM2",
+            ProcessorUpgrade::SocketLGA2011_3 => "Socket LGA2011-3",
+            ProcessorUpgrade::SocketLGA1356_3 => "Socket LGA1356-3",
+            ProcessorUpgrade::SocketLGA1150 => "Socket LGA1150",
+            ProcessorUpgrade::SocketBGA1168 => "Socket BGA1168",
+            ProcessorUpgrade::SocketBGA1234 => "Socket BGA1234",
+            ProcessorUpgrade::SocketBGA1364 => "Socket BGA1364",
+            ProcessorUpgrade::SocketAM4 => "Socket AM4",
+            ProcessorUpgrade::SocketLGA1151 => "Socket LGA1151",
+            ProcessorUpgrade::SocketBGA1356 => "Socket BGA1356",
+            ProcessorUpgrade::SocketBGA1440 => "Socket BGA1440",
+            ProcessorUpgrade::SocketBGA1515 => "Socket BGA1515",
+            ProcessorUpgrade::SocketLGA3647_1 => "Socket LGA3647-1",
+            ProcessorUpgrade::SocketSP3 => "Socket SP3",
+            ProcessorUpgrade::SocketSP3r23 => "Socket SP3r2",
+            ProcessorUpgrade::SocketLGA2066 => "Socket LGA2066",
+            ProcessorUpgrade::SocketBGA1392 => "Socket BGA1392",
+            ProcessorUpgrade::SocketBGA1510 => "Socket BGA1510",
+            ProcessorUpgrade::SocketBGA1528 => "Socket BGA1528",
+            ProcessorUpgrade::SocketLGA4189 => "Socket LGA4189",
+            ProcessorUpgrade::SocketLGA1200 => "Socket LGA1200",
+            ProcessorUpgrade::SocketLGA4677 => "Socket LGA4677",
+            ProcessorUpgrade::SocketLGA1700 => "Socket LGA1700",
+            ProcessorUpgrade::SocketBGA1744 => "Socket BGA1744",
+            ProcessorUpgrade::SocketBGA1781 => "Socket BGA1781",
+            ProcessorUpgrade::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ProcessorUpgradeData {
     fn from(raw: u8) -> Self {
         ProcessorUpgradeData {
@@ -1563,7 +2023,7 @@ impl ProcessorCharacteristics {
 
 impl fmt::Debug for ProcessorCharacteristics {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorCharacteristics>())
+        fmt.debug_struct(core::any::type_name::<ProcessorCharacteristics>())
             .field("raw", &self.raw)
             .field("unknown", &self.unknown())
             .field("bit_64capable", &self.bit_64capable())
@@ -1605,7 +2065,7 @@ impl Serialize for ProcessorCharacteristics {
 }
 
 /// # Processor Voltage
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ProcessorVoltage {
     /// Current Processor Voltage
     CurrentVolts(f32),
@@ -1613,6 +2073,23 @@ pub enum ProcessorVoltage {
     SupportedVolts(ProcessorSupportedVoltages),
 }
 
+impl fmt::Display for ProcessorVoltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ProcessorVoltage::CurrentVolts(volts) => write!(f, "{:.1} V", volts),
+            ProcessorVoltage::SupportedVolts(supported) => {
+                for (index, volts) in supported.voltages().iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{:.1} V", volts)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 impl From<u8> for ProcessorVoltage {
     fn from(raw: u8) -> Self {
         if raw & 0b1000_0000 == 0b1000_0000 {
@@ -1624,7 +2101,7 @@ impl From<u8> for ProcessorVoltage {
 }
 
 /// # Processor Supported Voltages
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct ProcessorSupportedVoltages {
     /// Raw value
     pub raw: u8,
@@ -1683,7 +2160,7 @@ impl ProcessorSupportedVoltages {
 
 impl fmt::Debug for ProcessorSupportedVoltages {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorSupportedVoltages>())
+        fmt.debug_struct(core::any::type_name::<ProcessorSupportedVoltages>())
             .field("raw", &self.raw)
             .field("voltages", &self.voltages().as_slice())
             .finish()
@@ -1703,7 +2180,7 @@ impl Serialize for ProcessorSupportedVoltages {
 }
 
 /// External Clock Frequency in MHz
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Copy)]
 pub enum ProcessorExternalClock {
     /// The value is unknown
     Unknown,
@@ -1711,6 +2188,15 @@ pub enum ProcessorExternalClock {
     MHz(u16),
 }
 
+impl fmt::Display for ProcessorExternalClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ProcessorExternalClock::Unknown => f.write_str("Unknown"),
+            ProcessorExternalClock::MHz(mhz) => write!(f, "{} MHz", mhz),
+        }
+    }
+}
+
 impl From<u16> for ProcessorExternalClock {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1731,7 +2217,7 @@ impl fmt::Debug for ProcessorExternalClock {
 }
 
 /// Processor Speed in MHz
-#[derive(Serialize)]
+#[derive(Serialize, Clone, Copy)]
 pub enum ProcessorSpeed {
     /// The value is unknown
     Unknown,
@@ -1739,6 +2225,15 @@ pub enum ProcessorSpeed {
     MHz(u16),
 }
 
+impl fmt::Display for ProcessorSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ProcessorSpeed::Unknown => f.write_str("Unknown"),
+            ProcessorSpeed::MHz(mhz) => write!(f, "{} MHz", mhz),
+        }
+    }
+}
+
 impl From<u16> for ProcessorSpeed {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1793,7 +2288,7 @@ impl ProcessorStatus {
 
 impl fmt::Debug for ProcessorStatus {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ProcessorStatus>())
+        fmt.debug_struct(core::any::type_name::<ProcessorStatus>())
             .field("raw", &self.raw)
             .field("socket_populated", &self.socket_populated())
             .field("cpu_status", &self.cpu_status())
@@ -1815,7 +2310,7 @@ impl Serialize for ProcessorStatus {
 }
 
 /// CPU Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CpuStatus {
     /// 0h – Unknown
     Unknown,
@@ -1833,6 +2328,20 @@ pub enum CpuStatus {
     None,
 }
 
+impl fmt::Display for CpuStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CpuStatus::Unknown => "Unknown",
+            CpuStatus::Enabled => "CPU Enabled",
+            CpuStatus::UserDisabled => "CPU Disabled by User through BIOS Setup",
+            CpuStatus::BiosDisabled => "CPU Disabled by BIOS (POST Error)",
+            CpuStatus::Idle => "CPU is Idle, waiting to be enabled",
+            CpuStatus::Other => "Other",
+            CpuStatus::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for CpuStatus {
     fn from(raw: u8) -> Self {
         match raw & 0b0000_0111 {
@@ -1848,7 +2357,7 @@ impl From<u8> for CpuStatus {
 }
 
 /// Processor Core Count
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum CoreCount {
     /// The value is unknown
     Unknown,
@@ -1859,6 +2368,16 @@ pub enum CoreCount {
     SeeCoreCount2,
 }
 
+impl fmt::Display for CoreCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CoreCount::Unknown => f.write_str("Unknown"),
+            CoreCount::Count(count) => write!(f, "{}", count),
+            CoreCount::SeeCoreCount2 => f.write_str("See Core Count 2"),
+        }
+    }
+}
+
 impl From<u8> for CoreCount {
     fn from(raw: u8) -> Self {
         match raw {
@@ -1870,7 +2389,7 @@ impl From<u8> for CoreCount {
 }
 
 /// Processor Core Count #2
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum CoreCount2 {
     /// The value is unknown
     Unknown,
@@ -1880,6 +2399,16 @@ pub enum CoreCount2 {
     Reserved,
 }
 
+impl fmt::Display for CoreCount2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CoreCount2::Unknown => f.write_str("Unknown"),
+            CoreCount2::Count(count) => write!(f, "{}", count),
+            CoreCount2::Reserved => f.write_str("Reserved"),
+        }
+    }
+}
+
 impl From<u16> for CoreCount2 {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1891,7 +2420,7 @@ impl From<u16> for CoreCount2 {
 }
 
 /// Processor Cores Enabled
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum CoresEnabled {
     /// The value is unknown
     Unknown,
@@ -1902,6 +2431,16 @@ pub enum CoresEnabled {
     SeeCoresEnabled2,
 }
 
+impl fmt::Display for CoresEnabled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CoresEnabled::Unknown => f.write_str("Unknown"),
+            CoresEnabled::Count(count) => write!(f, "{}", count),
+            CoresEnabled::SeeCoresEnabled2 => f.write_str("See Cores Enabled 2"),
+        }
+    }
+}
+
 impl From<u8> for CoresEnabled {
     fn from(raw: u8) -> Self {
         match raw {
@@ -1913,7 +2452,7 @@ impl From<u8> for CoresEnabled {
 }
 
 /// Processor Cores Enabled #2
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum CoresEnabled2 {
     /// The value is unknown
     Unknown,
@@ -1923,6 +2462,16 @@ pub enum CoresEnabled2 {
     Reserved,
 }
 
+impl fmt::Display for CoresEnabled2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CoresEnabled2::Unknown => f.write_str("Unknown"),
+            CoresEnabled2::Count(count) => write!(f, "{}", count),
+            CoresEnabled2::Reserved => f.write_str("Reserved"),
+        }
+    }
+}
+
 impl From<u16> for CoresEnabled2 {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1934,7 +2483,7 @@ impl From<u16> for CoresEnabled2 {
 }
 
 /// Processor Thread Count
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ThreadCount {
     /// The value is unknown
     Unknown,
@@ -1945,6 +2494,16 @@ pub enum ThreadCount {
     SeeThreadCount2,
 }
 
+impl fmt::Display for ThreadCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ThreadCount::Unknown => f.write_str("Unknown"),
+            ThreadCount::Count(count) => write!(f, "{}", count),
+            ThreadCount::SeeThreadCount2 => f.write_str("See Thread Count 2"),
+        }
+    }
+}
+
 impl From<u8> for ThreadCount {
     fn from(raw: u8) -> Self {
         match raw {
@@ -1956,7 +2515,7 @@ impl From<u8> for ThreadCount {
 }
 
 /// Processor Thread Count #2
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ThreadCount2 {
     /// The value is unknown
     Unknown,
@@ -1966,6 +2525,16 @@ pub enum ThreadCount2 {
     Reserved,
 }
 
+impl fmt::Display for ThreadCount2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ThreadCount2::Unknown => f.write_str("Unknown"),
+            ThreadCount2::Count(count) => write!(f, "{}", count),
+            ThreadCount2::Reserved => f.write_str("Reserved"),
+        }
+    }
+}
+
 impl From<u16> for ThreadCount2 {
     fn from(raw: u16) -> Self {
         match raw {
@@ -1979,6 +2548,7 @@ impl From<u16> for ThreadCount2 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::StructureBuilder;
 
     #[test]
     fn unit_test() {
@@ -2089,5 +2659,209 @@ mod tests {
             ThreadCount2::Unknown => panic!("expected number"),
             ThreadCount2::Reserved => panic!("expected number"),
         }
+
+        // The *_2 fields were introduced in 3.0
+        let mut data =
+            SMBiosData::from_vec_and_version(struct_type4, Some(SMBiosVersion::new(2, 8, 0)));
+        let test_struct = data.first::<SMBiosProcessorInformation<'_>>().unwrap();
+        assert!(test_struct.core_count_2().is_none());
+        assert!(test_struct.cores_enabled_2().is_none());
+        assert!(test_struct.thread_count_2().is_none());
+
+        data.set_version(Some(SMBiosVersion::new(3, 0, 0)));
+        let test_struct = data.first::<SMBiosProcessorInformation<'_>>().unwrap();
+        assert!(test_struct.core_count_2().is_some());
+        assert!(test_struct.cores_enabled_2().is_some());
+        assert!(test_struct.thread_count_2().is_some());
+    }
+
+    #[test]
+    fn test_effective_processor_family() {
+        // processor_family is FEh (see processor_family_2), processor_family_2 is 0104h (SH-3)
+        let mut struct_type4 = vec![
+            0x04, 0x2A, 0x57, 0x00, 0x01, 0x03, 0xFE, 0x02, 0x54, 0x06, 0x05, 0x00, 0xFF, 0xFB,
+            0xEB, 0xBF, 0x03, 0x90, 0x64, 0x00, 0x3C, 0x0F, 0x10, 0x0E, 0x41, 0x01, 0x53, 0x00,
+            0x54, 0x00, 0x55, 0x00, 0x00, 0x04, 0x00, 0x06, 0x06, 0x0C, 0xFC, 0x00, 0x04, 0x01,
+            b'C', b'P', b'U', b'0', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        assert_eq!(
+            *test_struct.processor_family().unwrap(),
+            ProcessorFamily::SeeProcessorFamily2
+        );
+        let family = test_struct.effective_processor_family().unwrap();
+        assert_eq!(family.raw, 0x0104);
+        assert_eq!(*family, ProcessorFamily::SH3);
+
+        // processor_family byte is used directly when not FEh
+        struct_type4[0x06] = 0xB3;
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        let family = test_struct.effective_processor_family().unwrap();
+        assert_eq!(family.raw, 0xB3);
+        assert_eq!(*family, ProcessorFamily::IntelXeonProcessor);
+    }
+
+    #[test]
+    fn test_voltage_encodings() {
+        // Bit 7 set: bits 6:0 are the current voltage in tenths of a volt
+        match ProcessorVoltage::from(0x8C) {
+            ProcessorVoltage::CurrentVolts(volts) => assert_eq!(volts, 1.2),
+            ProcessorVoltage::SupportedVolts(_) => panic!("expected current volts"),
+        }
+        match ProcessorVoltage::from(0xFF) {
+            ProcessorVoltage::CurrentVolts(volts) => assert_eq!(volts, 12.7),
+            ProcessorVoltage::SupportedVolts(_) => panic!("expected current volts"),
+        }
+
+        // Bit 7 clear: bits 2:0 are the legacy supported voltage flags
+        match ProcessorVoltage::from(0x05) {
+            ProcessorVoltage::SupportedVolts(supported) => {
+                assert!(supported.volts_5_0());
+                assert!(!supported.volts_3_3());
+                assert!(supported.volts_2_9());
+                assert_eq!(supported.voltages(), vec![2.9, 5.0]);
+            }
+            ProcessorVoltage::CurrentVolts(_) => panic!("expected supported volts"),
+        }
+        match ProcessorVoltage::from(0x02) {
+            ProcessorVoltage::SupportedVolts(supported) => {
+                assert_eq!(supported.voltages(), vec![3.3]);
+            }
+            ProcessorVoltage::CurrentVolts(_) => panic!("expected supported volts"),
+        }
+    }
+
+    #[test]
+    fn test_processor_status() {
+        let status = ProcessorStatus::from(0x41);
+        assert!(status.socket_populated());
+        assert_eq!(status.cpu_status(), CpuStatus::Enabled);
+
+        // An empty socket reports status 0 (Unknown)
+        let status = ProcessorStatus::from(0x00);
+        assert!(!status.socket_populated());
+        assert_eq!(status.cpu_status(), CpuStatus::Unknown);
+
+        assert_eq!(
+            ProcessorStatus::from(0x42).cpu_status(),
+            CpuStatus::UserDisabled
+        );
+        assert_eq!(
+            ProcessorStatus::from(0x43).cpu_status(),
+            CpuStatus::BiosDisabled
+        );
+        assert_eq!(ProcessorStatus::from(0x44).cpu_status(), CpuStatus::Idle);
+        assert_eq!(ProcessorStatus::from(0x47).cpu_status(), CpuStatus::Other);
+        assert_eq!(ProcessorStatus::from(0x45).cpu_status(), CpuStatus::None);
+
+        // Skipping empty sockets in a two socket system
+        let mut table_data = Vec::new();
+        for (handle, status) in [(0x0400u16, 0x41u8), (0x0401, 0x00)].iter() {
+            table_data.extend(
+                StructureBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE, *handle)
+                    .bytes(&[0; 0x14])
+                    .byte(*status)
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let populated: Vec<Handle> = data
+            .filter(|processor: &SMBiosProcessorInformation<'_>| {
+                processor
+                    .status()
+                    .is_some_and(|status| status.socket_populated())
+            })
+            .map(|processor| processor.handle())
+            .collect();
+        assert_eq!(populated, vec![Handle(0x0400)]);
+    }
+
+    #[test]
+    fn test_processor_characteristics() {
+        // Intel(R) Xeon(R) W-2133 from unit_test(), characteristics 00FCh
+        let struct_type4 = StructureBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE, 0x56)
+            .bytes(&[0; 0x22])
+            .word(0x00FC)
+            .build();
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        let characteristics = test_struct.processor_characteristics().unwrap();
+
+        assert_eq!(characteristics.raw, 0x00FC);
+        assert!(!characteristics.unknown());
+        assert!(characteristics.bit_64capable());
+        assert!(characteristics.multi_core());
+        assert!(characteristics.hardware_thread());
+        assert!(characteristics.execute_protection());
+        assert!(characteristics.enhanced_virtualization());
+        assert!(characteristics.power_performance_control());
+        assert!(!characteristics.bit_128capable());
+        assert!(!characteristics.arm_64soc_id());
+
+        // Single core 32-bit processor with unknown characteristics
+        let characteristics = ProcessorCharacteristics::from(0x0002);
+        assert!(characteristics.unknown());
+        assert!(!characteristics.bit_64capable());
+        assert!(!characteristics.multi_core());
+    }
+
+    #[test]
+    fn test_cache_handles_resolved() {
+        let mut table_data = Vec::new();
+        for (handle, socket_designation) in [
+            (0x0700, "L1 Cache"),
+            (0x0701, "L2 Cache"),
+            (0x0702, "L3 Cache"),
+        ]
+        .iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosCacheInformation::STRUCT_TYPE, *handle)
+                    .string(socket_designation)
+                    .word(0x0180)
+                    .word(0x0100)
+                    .word(0x0100)
+                    .word(0x0020)
+                    .word(0x0020)
+                    .build(),
+            );
+        }
+
+        // Caches which resolve, handles of another structure type, and
+        // missing handles
+        for (handle, l1, l2, l3) in [
+            (0x0400, 0x0700, 0x0701, 0x0702),
+            (0x0401, 0x0400, 0x0400, 0x0401),
+            (0x0402, 0x1234, 0x1235, 0x1236),
+        ]
+        .iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosProcessorInformation::STRUCT_TYPE, *handle)
+                    .bytes(&[0; 0x16])
+                    .handle(Handle(*l1))
+                    .handle(Handle(*l2))
+                    .handle(Handle(*l3))
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let processors = data.collect::<SMBiosProcessorInformation<'_>>();
+
+        let l1cache = processors[0].l1cache_handle_resolved(&data).unwrap();
+        assert_eq!(l1cache.socket_designation().to_string(), "L1 Cache");
+        let l2cache = processors[0].l2cache_handle_resolved(&data).unwrap();
+        assert_eq!(l2cache.socket_designation().to_string(), "L2 Cache");
+        let l3cache = processors[0].l3cache_handle_resolved(&data).unwrap();
+        assert_eq!(l3cache.socket_designation().to_string(), "L3 Cache");
+
+        for processor in &processors[1..] {
+            assert!(processor.l1cache_handle_resolved(&data).is_none());
+            assert!(processor.l2cache_handle_resolved(&data).is_none());
+            assert!(processor.l3cache_handle_resolved(&data).is_none());
+        }
     }
 }
@@ -1,8 +1,8 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # String Property (Type 46)
 ///
@@ -21,6 +21,7 @@ pub struct SMBiosStringProperty<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosStringProperty<'a> {
     const STRUCT_TYPE: u8 = 46u8;
+    const MIN_LENGTH: u8 = 0x09;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -54,7 +55,7 @@ impl<'a> SMBiosStringProperty<'a> {
 
 impl fmt::Debug for SMBiosStringProperty<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosStringProperty<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosStringProperty<'_>>())
             .field("header", &self.parts.header)
             .field("string_property_id", &self.string_property_id())
             .field("string_property_value", &self.string_property_value())
@@ -92,7 +93,7 @@ pub struct StringPropertyIdData {
 
 impl fmt::Debug for StringPropertyIdData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<StringPropertyIdData>())
+        fmt.debug_struct(core::any::type_name::<StringPropertyIdData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -115,7 +116,7 @@ impl fmt::Display for StringPropertyIdData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             StringPropertyId::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -149,7 +150,7 @@ impl From<u16> for StringPropertyIdData {
 }
 
 /// # String Property Id of [SMBiosStringProperty]
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum StringPropertyId {
     /// UEFI Device Path
     ///
@@ -164,9 +165,21 @@ pub enum StringPropertyId {
     None,
 }
 
+impl fmt::Display for StringPropertyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StringPropertyId::UefiDevicePath => "UEFI Device Path",
+            StringPropertyId::VendorSpecific => "Vendor Specific",
+            StringPropertyId::OemSpecific => "OEM Specific",
+            StringPropertyId::None => "Undefined",
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # System Boot Information (Type 32)
 ///
@@ -18,6 +18,7 @@ pub struct SMBiosSystemBootInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemBootInformation<'a> {
     const STRUCT_TYPE: u8 = 32u8;
+    const MIN_LENGTH: u8 = 0x0B;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -60,7 +61,7 @@ impl<'a> SMBiosSystemBootInformation<'a> {
 
 impl fmt::Debug for SMBiosSystemBootInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemBootInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemBootInformation<'_>>())
             .field("header", &self.parts.header)
             .field("boot_status_data", &self.boot_status_data())
             .finish()
@@ -99,15 +100,25 @@ impl<'a> SystemBootStatusData<'a> {
             0x06 => SystemBootStatus::SystemSecurityViolation,
             0x07 => SystemBootStatus::PreviouslyRequestedImage,
             0x08 => SystemBootStatus::SystemWatchdogTimerExpired,
+            0x80..=0xBF => SystemBootStatus::VendorSpecific,
+            0xC0..=0xFF => SystemBootStatus::ProductSpecific,
             _ => SystemBootStatus::None,
         }
     }
+
+    /// Status-specific additional data following the status byte
+    ///
+    /// Empty when the structure carries only the status byte.
+    pub fn additional_data(&self) -> &'a [u8] {
+        self.raw.get(1..).unwrap_or(&[])
+    }
 }
 
 impl fmt::Debug for SystemBootStatusData<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemBootInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SystemBootStatusData<'_>>())
             .field("system_boot_status", &self.system_boot_status())
+            .field("additional_data", &self.additional_data())
             .finish()
     }
 }
@@ -117,14 +128,15 @@ impl Serialize for SystemBootStatusData<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SystemBootStatusData", 1)?;
+        let mut state = serializer.serialize_struct("SystemBootStatusData", 2)?;
         state.serialize_field("system_boot_status", &self.system_boot_status())?;
+        state.serialize_field("additional_data", &self.additional_data())?;
         state.end()
     }
 }
 
 /// # System Boot Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SystemBootStatus {
     /// No errors detected
     NoErrors,
@@ -151,13 +163,43 @@ pub enum SystemBootStatus {
     PreviouslyRequestedImage,
     /// System watchdog timer expired, causing the system to reboot
     SystemWatchdogTimerExpired,
+    /// Vendor/OEM-specific implementations (128-191)
+    VendorSpecific,
+    /// Product-specific implementations (192-255)
+    ProductSpecific,
     /// A value unknown to this standard, check the raw value
     None,
 }
 
+impl fmt::Display for SystemBootStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SystemBootStatus::NoErrors => "No errors detected",
+            SystemBootStatus::NoBootableMedia => "No bootable media",
+            SystemBootStatus::NormalOSFailedToLoad => "Normal operating system failed to load",
+            SystemBootStatus::FirmwareDetectedFailure => {
+                "Firmware-detected hardware failure, including “unknown” failure types"
+            }
+            SystemBootStatus::OSDetectedFailure => "Operating system-detected hardware failure",
+            SystemBootStatus::UserRequestedBoot => {
+                "User-requested boot, usually through a keystroke"
+            }
+            SystemBootStatus::SystemSecurityViolation => "System security violation",
+            SystemBootStatus::PreviouslyRequestedImage => "Previously-requested image",
+            SystemBootStatus::SystemWatchdogTimerExpired => {
+                "System watchdog timer expired, causing the system to reboot"
+            }
+            SystemBootStatus::VendorSpecific => "Vendor/OEM-specific implementations (128-191)",
+            SystemBootStatus::ProductSpecific => "Product-specific implementations (192-255)",
+            SystemBootStatus::None => "Undefined",
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -206,4 +248,51 @@ mod tests {
 
         assert!(test_struct.boot_status_data().is_none());
     }
+
+    #[test]
+    fn test_vendor_specific_data() {
+        // vendor-specific status (0x80) followed by 3 bytes of vendor data
+        let struct_type32 = vec![
+            0x20, 0x0E, 0x26, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xDE, 0xAD, 0x01,
+            0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type32);
+        let test_struct = SMBiosSystemBootInformation::new(&parts);
+
+        let boot_status_data = test_struct.boot_status_data().unwrap();
+        assert_eq!(
+            boot_status_data.system_boot_status(),
+            SystemBootStatus::VendorSpecific
+        );
+        assert_eq!(
+            boot_status_data.additional_data(),
+            &[0xDEu8, 0xAD, 0x01] as &[u8]
+        );
+
+        // "no errors" with only the status byte
+        let struct_type32 = vec![
+            0x20, 0x0B, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type32);
+        let test_struct = SMBiosSystemBootInformation::new(&parts);
+
+        let boot_status_data = test_struct.boot_status_data().unwrap();
+        assert_eq!(
+            boot_status_data.system_boot_status(),
+            SystemBootStatus::NoErrors
+        );
+        assert!(boot_status_data.additional_data().is_empty());
+
+        // product-specific and reserved values
+        assert_eq!(
+            SystemBootStatusData { raw: &[0xC5] }.system_boot_status(),
+            SystemBootStatus::ProductSpecific
+        );
+        assert_eq!(
+            SystemBootStatusData { raw: &[0x09] }.system_boot_status(),
+            SystemBootStatus::None
+        );
+    }
 }
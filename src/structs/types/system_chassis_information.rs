@@ -1,8 +1,9 @@
 use crate::core::{strings::*, UndefinedStruct};
-use crate::{BoardTypeData, SMBiosStruct, SMBiosType};
+use crate::prelude::*;
+use crate::{BoardTypeData, SMBiosStruct, SMBiosType, SMBiosVersion};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # System Enclosure or Chassis (Type 3)
 ///
@@ -21,6 +22,7 @@ pub struct SMBiosSystemChassisInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemChassisInformation<'a> {
     const STRUCT_TYPE: u8 = 3u8;
+    const MIN_LENGTH: u8 = 0x09;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -125,6 +127,18 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
             .map(|raw| ChassisHeight::from(raw))
     }
 
+    /// Height in millimeters
+    ///
+    /// Height of the enclosure converted from 'U's
+    /// (1 U = 44.45 mm). Returns None when the height
+    /// is unspecified.
+    pub fn height_in_millimeters(&self) -> Option<f32> {
+        match self.height()? {
+            ChassisHeight::U(units) => Some(units as f32 * ChassisHeight::MILLIMETERS_PER_U),
+            ChassisHeight::Unspecified => None,
+        }
+    }
+
     /// Number of power cords
     ///
     /// Number of power cords associated with the
@@ -175,7 +189,14 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
     /// SKU number
     ///
     /// Chassis or enclosure SKU number
+    ///
+    /// [SMBiosStringError::FieldOutOfBounds] when the table version predates
+    /// SMBIOS 2.7, which added this field.
     pub fn sku_number(&self) -> SMBiosString {
+        if self.parts.version_predates(SMBiosVersion::new(2, 7, 0)) {
+            return Err(SMBiosStringError::FieldOutOfBounds).into();
+        }
+
         match self.contained_elements_size() {
             Some(size) => self
                 .parts
@@ -187,7 +208,7 @@ impl<'a> SMBiosSystemChassisInformation<'a> {
 
 impl fmt::Debug for SMBiosSystemChassisInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemChassisInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemChassisInformation<'_>>())
             .field("header", &self.parts.header)
             .field("manufacturer", &self.manufacturer())
             .field("chassis_type", &self.chassis_type())
@@ -243,7 +264,7 @@ impl Serialize for SMBiosSystemChassisInformation<'_> {
 }
 
 /// # Chassis Height
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ChassisHeight {
     /// A chassis enclosure height is not specified.
     Unspecified,
@@ -255,6 +276,20 @@ pub enum ChassisHeight {
     U(u8),
 }
 
+impl fmt::Display for ChassisHeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ChassisHeight::Unspecified => f.write_str("Unspecified"),
+            ChassisHeight::U(height) => write!(f, "{} U", height),
+        }
+    }
+}
+
+impl ChassisHeight {
+    /// Millimeters per 'U' (1.75 inches)
+    pub const MILLIMETERS_PER_U: f32 = 44.45f32;
+}
+
 impl From<u8> for ChassisHeight {
     fn from(raw: u8) -> Self {
         match raw {
@@ -265,7 +300,7 @@ impl From<u8> for ChassisHeight {
 }
 
 /// # Number of Power Cords
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum PowerCords {
     /// The number of power cords is not specified.
     Unspecified,
@@ -273,6 +308,15 @@ pub enum PowerCords {
     Count(u8),
 }
 
+impl fmt::Display for PowerCords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PowerCords::Unspecified => f.write_str("Unspecified"),
+            PowerCords::Count(count) => write!(f, "{}", count),
+        }
+    }
+}
+
 impl From<u8> for PowerCords {
     fn from(raw: u8) -> Self {
         match raw {
@@ -283,7 +327,7 @@ impl From<u8> for PowerCords {
 }
 
 /// # Chassis Lock Presence
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ChassisLockPresence {
     /// The enclosure has a lock.
     Present,
@@ -291,6 +335,15 @@ pub enum ChassisLockPresence {
     NotPresent,
 }
 
+impl fmt::Display for ChassisLockPresence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChassisLockPresence::Present => "Present",
+            ChassisLockPresence::NotPresent => "Not present",
+        })
+    }
+}
+
 /// # Chassis Type Data
 pub struct ChassisTypeData {
     /// Raw value
@@ -308,7 +361,7 @@ pub struct ChassisTypeData {
 
 impl fmt::Debug for ChassisTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ChassisTypeData>())
+        fmt.debug_struct(core::any::type_name::<ChassisTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .field("lock_presence", &self.lock_presence)
@@ -333,7 +386,7 @@ impl fmt::Display for ChassisTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             ChassisType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -347,7 +400,7 @@ impl Deref for ChassisTypeData {
 }
 
 /// # Chassis Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChassisType {
     /// Other
     Other,
@@ -425,6 +478,50 @@ pub enum ChassisType {
     None,
 }
 
+impl fmt::Display for ChassisType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChassisType::Other => "Other",
+            ChassisType::Unknown => "Unknown",
+            ChassisType::Desktop => "Desktop",
+            ChassisType::LowProfileDesktop => "Low Profile Desktop",
+            ChassisType::PizzaBox => "Pizza Box",
+            ChassisType::MiniTower => "Mini Tower",
+            ChassisType::Tower => "Tower",
+            ChassisType::Portable => "Portable",
+            ChassisType::Laptop => "Laptop",
+            ChassisType::Notebook => "Notebook",
+            ChassisType::HandHeld => "Hand Held",
+            ChassisType::DockingStation => "Docking Station",
+            ChassisType::AllInOne => "All In One",
+            ChassisType::SubNotebook => "Sub Notebook",
+            ChassisType::SpaceSaving => "Space-saving",
+            ChassisType::LunchBox => "Lunch Box",
+            ChassisType::MainServerChassis => "Main Server Chassis",
+            ChassisType::ExpansionChassis => "Expansion Chassis",
+            ChassisType::SubChassis => "Sub Chassis",
+            ChassisType::BusExpansionChassis => "Bus Expansion Chassis",
+            ChassisType::PeripheralChassis => "Peripheral Chassis",
+            ChassisType::RaidChassis => "RAID Chassis",
+            ChassisType::RackMountChassis => "Rack Mount Chassis",
+            ChassisType::SealedCasePC => "Sealed-case PC",
+            ChassisType::MultiSystemChassis => "Multi-system",
+            ChassisType::CompactPci => "CompactPCI",
+            ChassisType::AdvancedTca => "AdvancedTCA",
+            ChassisType::Blade => "Blade",
+            ChassisType::BladeEnclosure => "Blade Enclosure",
+            ChassisType::Tablet => "Tablet",
+            ChassisType::Convertible => "Convertible",
+            ChassisType::Detachable => "Detachable",
+            ChassisType::IoTGateway => "IoT Gateway",
+            ChassisType::EmbeddedPC => "Embedded PC",
+            ChassisType::MiniPC => "Mini PC",
+            ChassisType::StickPC => "Stick PC",
+            ChassisType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ChassisTypeData {
     fn from(raw: u8) -> Self {
         ChassisTypeData {
@@ -492,7 +589,7 @@ pub struct ChassisStateData {
 
 impl fmt::Debug for ChassisStateData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ChassisStateData>())
+        fmt.debug_struct(core::any::type_name::<ChassisStateData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -519,8 +616,8 @@ impl Deref for ChassisStateData {
     }
 }
 
-/// # Chassis Statue
-#[derive(Serialize, Debug, PartialEq, Eq)]
+/// # Chassis State
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChassisState {
     /// Other
     Other,
@@ -538,6 +635,20 @@ pub enum ChassisState {
     None,
 }
 
+impl fmt::Display for ChassisState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChassisState::Other => "Other",
+            ChassisState::Unknown => "Unknown",
+            ChassisState::Safe => "Safe",
+            ChassisState::Warning => "Warning",
+            ChassisState::Critical => "Critical",
+            ChassisState::NonRecoverable => "Non-recoverable",
+            ChassisState::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ChassisStateData {
     fn from(raw: u8) -> Self {
         ChassisStateData {
@@ -570,7 +681,7 @@ pub struct ChassisSecurityStatusData {
 
 impl fmt::Debug for ChassisSecurityStatusData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ChassisSecurityStatusData>())
+        fmt.debug_struct(core::any::type_name::<ChassisSecurityStatusData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -598,7 +709,7 @@ impl Deref for ChassisSecurityStatusData {
 }
 
 /// # Chassis Security Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ChassisSecurityStatus {
     /// Other
     Other,
@@ -614,6 +725,19 @@ pub enum ChassisSecurityStatus {
     None,
 }
 
+impl fmt::Display for ChassisSecurityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChassisSecurityStatus::Other => "Other",
+            ChassisSecurityStatus::Unknown => "Unknown",
+            ChassisSecurityStatus::StatusNone => "None",
+            ChassisSecurityStatus::ExternalInterfaceLockedOut => "External Interface Locked Out",
+            ChassisSecurityStatus::ExternalInterfaceEnabled => "External Interface Enabled",
+            ChassisSecurityStatus::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for ChassisSecurityStatusData {
     fn from(raw: u8) -> Self {
         ChassisSecurityStatusData {
@@ -639,34 +763,38 @@ pub struct ContainedElements<'a> {
 
 impl<'a> ContainedElements<'a> {
     fn new(chassis_information: &'a SMBiosSystemChassisInformation<'a>) -> Option<Self> {
+        let record_length = chassis_information.contained_element_record_length()? as usize;
+        let declared_count = chassis_information.contained_element_count()? as usize;
+        let fields = &chassis_information.parts().fields;
+
+        // Only whole records which lie within the structure are exposed; a record
+        // length of 0 or a count which runs past the structure yields fewer records.
+        let available = fields
+            .len()
+            .saturating_sub(SMBiosSystemChassisInformation::CONTAINED_ELEMENTS_OFFSET);
+        let record_count = match record_length {
+            0 => 0,
+            _ => declared_count.min(available / record_length),
+        };
+
         chassis_information
-            .contained_element_record_length()
-            .and_then(|record_length| {
-                chassis_information
-                    .contained_element_count()
-                    .and_then(|record_count| {
-                        chassis_information
-                            .parts()
-                            .get_field_data(
-                                SMBiosSystemChassisInformation::CONTAINED_ELEMENTS_OFFSET,
-                                SMBiosSystemChassisInformation::CONTAINED_ELEMENTS_OFFSET
-                                    + (record_length as usize * record_count as usize),
-                            )
-                            .and_then(|raw| {
-                                Some(Self {
-                                    raw,
-                                    record_count: record_count as usize,
-                                    record_length: record_length as usize,
-                                })
-                            })
-                    })
+            .parts()
+            .get_field_data(
+                SMBiosSystemChassisInformation::CONTAINED_ELEMENTS_OFFSET,
+                SMBiosSystemChassisInformation::CONTAINED_ELEMENTS_OFFSET
+                    + record_length * record_count,
+            )
+            .map(|raw| Self {
+                raw,
+                record_count,
+                record_length,
             })
     }
 }
 
 impl<'a> fmt::Debug for ContainedElements<'a> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ContainedElements<'_>>())
+        fmt.debug_struct(core::any::type_name::<ContainedElements<'_>>())
             .field("records", &self.into_iter())
             .finish()
     }
@@ -724,7 +852,7 @@ impl<'a> ChassisElement<'a> {
 
 impl fmt::Debug for ChassisElement<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<ChassisElement<'_>>())
+        fmt.debug_struct(core::any::type_name::<ChassisElement<'_>>())
             .field("raw", &self.raw)
             .field("element_type", &self.element_type())
             .field("element_minimum", &self.element_minimum())
@@ -748,7 +876,7 @@ impl Serialize for ChassisElement<'_> {
 }
 
 /// # Contained Element Type
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ElementType {
     /// SMBIOS Baseboard Type enumeration
     BaseboardType(BoardTypeData),
@@ -756,6 +884,15 @@ pub enum ElementType {
     SMBiosType(SMBiosType),
 }
 
+impl fmt::Display for ElementType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ElementType::BaseboardType(board_type) => write!(f, "{}", board_type),
+            ElementType::SMBiosType(struct_type) => write!(f, "SMBIOS type {}", *struct_type),
+        }
+    }
+}
+
 impl From<u8> for ElementType {
     fn from(raw: u8) -> Self {
         if raw & 0b1000_0000 == 0b1000_0000 {
@@ -771,7 +908,7 @@ impl From<u8> for ElementType {
 /// Specifies the minimum number of the 'element_type' that can be
 /// installed in the chassis for the chassis to properly operate,
 /// in the range 0 to 254.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ElementMinimum {
     /// Specifies the minimum number of the 'element_type' that can be
     /// installed in the chassis for the chassis to properly operate,
@@ -781,6 +918,15 @@ pub enum ElementMinimum {
     Reserved,
 }
 
+impl fmt::Display for ElementMinimum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ElementMinimum::Count(count) => write!(f, "{}", count),
+            ElementMinimum::Reserved => f.write_str("Reserved"),
+        }
+    }
+}
+
 impl From<u8> for ElementMinimum {
     fn from(raw: u8) -> Self {
         match raw {
@@ -794,7 +940,7 @@ impl From<u8> for ElementMinimum {
 ///
 /// Specifies the minimum number of the 'element_type' that can be
 /// installed in the chassis in the range 0 to 254.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ElementMaximum {
     /// Specifies the maximum number of the 'element_type' that can be
     /// installed in the chassis for the chassis to properly operate,
@@ -804,6 +950,15 @@ pub enum ElementMaximum {
     Reserved,
 }
 
+impl fmt::Display for ElementMaximum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ElementMaximum::Count(count) => write!(f, "{}", count),
+            ElementMaximum::Reserved => f.write_str("Reserved"),
+        }
+    }
+}
+
 impl From<u8> for ElementMaximum {
     fn from(raw: u8) -> Self {
         match raw {
@@ -893,6 +1048,7 @@ mod tests {
         BoardType, ChassisHeight, ChassisSecurityStatus, ChassisState, ChassisType, ElementType,
         PowerCords, SMBiosSystemChassisInformation,
     };
+    use crate::SMBiosData;
 
     #[test]
     fn unit_test() {
@@ -960,5 +1116,159 @@ mod tests {
             test_struct.sku_number().to_string(),
             "Default string".to_string()
         );
+        assert_eq!(test_struct.sku_number().normalized(), None);
+    }
+
+    #[test]
+    fn test_contained_elements_bounds() {
+        // Declares 3 records of 3 bytes but the structure only holds 1 record
+        let struct_type3 = vec![
+            0x03, 0x18, 0x03, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x03, 0x03, 0x85, 0x01, 0x02, b'L', b'E', b'N', b'O',
+            b'V', b'O', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type3);
+        let test_struct = SMBiosSystemChassisInformation::new(&parts);
+        let contained_elements = test_struct.contained_elements().unwrap();
+        let elements: Vec<ChassisElement<'_>> = contained_elements.into_iter().collect();
+        assert_eq!(elements.len(), 1);
+        match elements[0].element_type() {
+            ElementType::SMBiosType(bios_type) => assert_eq!(*bios_type, 5),
+            _ => panic!("expected SMBIOS type"),
+        }
+        match elements[0].element_minimum() {
+            ElementMinimum::Count(count) => assert_eq!(count, 1),
+            ElementMinimum::Reserved => panic!("expected a count"),
+        }
+        match elements[0].element_maximum() {
+            ElementMaximum::Count(count) => assert_eq!(count, 2),
+            ElementMaximum::Reserved => panic!("expected a count"),
+        }
+
+        // A record length of 0 yields no records
+        let struct_type3 = vec![
+            0x03, 0x15, 0x03, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, b'L', b'E', b'N', b'O', b'V', b'O', 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type3);
+        let test_struct = SMBiosSystemChassisInformation::new(&parts);
+        let contained_elements = test_struct.contained_elements().unwrap();
+        assert_eq!(contained_elements.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_sku_number_offset() {
+        // 2 contained element records of 4 bytes each push the SKU number to 0x1D
+        let struct_type3 = vec![
+            0x03, 0x1E, 0x03, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x04, 0x85, 0x01, 0x02, 0x00, 0x0A, 0x01, 0x01,
+            0x00, 0x02, b'L', b'E', b'N', b'O', b'V', b'O', 0x00, b'S', b'K', b'U', b'1', 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type3);
+        let test_struct = SMBiosSystemChassisInformation::new(&parts);
+        assert_eq!(test_struct.contained_element_count(), Some(2));
+        assert_eq!(test_struct.contained_element_record_length(), Some(4));
+        assert_eq!(
+            test_struct
+                .contained_elements()
+                .unwrap()
+                .into_iter()
+                .count(),
+            2
+        );
+        assert_eq!(test_struct.sku_number().to_string(), "SKU1".to_string());
+
+        // SKU number was introduced in 2.7
+        let mut data =
+            SMBiosData::from_vec_and_version(struct_type3, Some(SMBiosVersion::new(2, 6, 0)));
+        let test_struct = data.first::<SMBiosSystemChassisInformation<'_>>().unwrap();
+        assert!(matches!(
+            test_struct.sku_number().err(),
+            Some(SMBiosStringError::FieldOutOfBounds)
+        ));
+
+        data.set_version(Some(SMBiosVersion::new(2, 7, 0)));
+        let test_struct = data.first::<SMBiosSystemChassisInformation<'_>>().unwrap();
+        assert_eq!(test_struct.sku_number().to_string(), "SKU1".to_string());
+    }
+
+    #[test]
+    fn test_chassis_type_data() {
+        let locked_desktop = ChassisTypeData::from(0x83);
+        assert_eq!(locked_desktop.raw, 0x83);
+        assert_eq!(*locked_desktop, ChassisType::Desktop);
+        match locked_desktop.lock_presence {
+            ChassisLockPresence::Present => (),
+            ChassisLockPresence::NotPresent => panic!("expected a lock"),
+        }
+
+        let rack_mount = ChassisTypeData::from(0x17);
+        assert_eq!(*rack_mount, ChassisType::RackMountChassis);
+        match rack_mount.lock_presence {
+            ChassisLockPresence::Present => panic!("expected no lock"),
+            ChassisLockPresence::NotPresent => (),
+        }
+
+        let unknown = ChassisTypeData::from(0x7F);
+        assert_eq!(unknown.raw, 0x7F);
+        assert_eq!(*unknown, ChassisType::None);
+        assert_eq!(format!("{}", unknown), "127".to_string());
+    }
+
+    #[test]
+    fn test_chassis_state_data() {
+        let states = [
+            (0x01, ChassisState::Other),
+            (0x02, ChassisState::Unknown),
+            (0x03, ChassisState::Safe),
+            (0x04, ChassisState::Warning),
+            (0x05, ChassisState::Critical),
+            (0x06, ChassisState::NonRecoverable),
+            (0x07, ChassisState::None),
+        ];
+        for (raw, state) in states.iter() {
+            let data = ChassisStateData::from(*raw);
+            assert_eq!(data.raw, *raw);
+            assert_eq!(*data, *state);
+        }
+
+        let security_statuses = [
+            (0x01, ChassisSecurityStatus::Other),
+            (0x02, ChassisSecurityStatus::Unknown),
+            (0x03, ChassisSecurityStatus::StatusNone),
+            (0x04, ChassisSecurityStatus::ExternalInterfaceLockedOut),
+            (0x05, ChassisSecurityStatus::ExternalInterfaceEnabled),
+            (0x06, ChassisSecurityStatus::None),
+        ];
+        for (raw, status) in security_statuses.iter() {
+            let data = ChassisSecurityStatusData::from(*raw);
+            assert_eq!(data.raw, *raw);
+            assert_eq!(*data, *status);
+        }
+    }
+
+    #[test]
+    fn test_height_in_millimeters() {
+        let mut struct_type3 = vec![
+            0x03, 0x15, 0x03, 0x00, 0x01, 0x17, 0x00, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03, 0x00,
+            0x00, 0x00, 0x00, 0x04, 0x01, 0x00, 0x00, b'L', b'E', b'N', b'O', b'V', b'O', 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type3);
+        let test_struct = SMBiosSystemChassisInformation::new(&parts);
+        let millimeters = test_struct.height_in_millimeters().unwrap();
+        assert!((millimeters - 177.8).abs() < 0.001);
+
+        // A height of 0 is unspecified
+        struct_type3[0x11] = 0x00;
+        let parts = UndefinedStruct::new(&struct_type3);
+        let test_struct = SMBiosSystemChassisInformation::new(&parts);
+        assert_eq!(test_struct.height_in_millimeters(), None);
     }
 }
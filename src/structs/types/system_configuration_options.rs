@@ -1,6 +1,6 @@
 use crate::{SMBiosStringSet, SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # System Configuration Options (Type 12)
 ///
@@ -15,6 +15,7 @@ pub struct SMBiosSystemConfigurationOptions<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemConfigurationOptions<'a> {
     const STRUCT_TYPE: u8 = 12u8;
+    const MIN_LENGTH: u8 = 0x05;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -31,7 +32,7 @@ impl<'a> SMBiosSystemConfigurationOptions<'a> {
         self.parts.get_field_byte(0x04)
     }
 
-    /// Iterable collection of OEM strings
+    /// Iterable collection of configuration strings
     ///
     /// EXAMPLES:
     /// "JP2: 1-2 Cache Size is 256K, 2-3 Cache Size is 512K"
@@ -43,7 +44,7 @@ impl<'a> SMBiosSystemConfigurationOptions<'a> {
 
 impl fmt::Debug for SMBiosSystemConfigurationOptions<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemConfigurationOptions<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemConfigurationOptions<'_>>())
             .field("header", &self.parts.header)
             .field("count", &self.count())
             .field("configuration_strings", &self.configuration_strings())
@@ -67,6 +68,7 @@ impl Serialize for SMBiosSystemConfigurationOptions<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -88,4 +90,37 @@ mod tests {
             Some("scre++".to_string())
         );
     }
+
+    #[test]
+    fn test_count_mismatch_and_empty() {
+        // The count claims 3 strings but the string-set contains 2
+        let struct_type12 = vec![
+            0x0C, 0x05, 0x24, 0x00, 0x03, b'J', b'P', b'2', b':', b' ', b'1', b'-', b'2', 0x00,
+            b'S', b'W', b'1', b'-', b'1', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type12);
+        let test_struct = SMBiosSystemConfigurationOptions::new(&parts);
+
+        assert_eq!(test_struct.count(), Some(3));
+        let options: Vec<String> = test_struct
+            .configuration_strings()
+            .into_iter()
+            .filter_map(|option| option.ok())
+            .collect();
+        assert_eq!(options, vec!["JP2: 1-2".to_string(), "SW1-1".to_string()]);
+
+        // The count claims 1 string but the string-set is empty
+        let struct_type12 = vec![0x0C, 0x05, 0x25, 0x00, 0x01, 0x00, 0x00];
+
+        let parts = UndefinedStruct::new(&struct_type12);
+        let test_struct = SMBiosSystemConfigurationOptions::new(&parts);
+
+        assert_eq!(test_struct.count(), Some(1));
+        assert!(test_struct
+            .configuration_strings()
+            .into_iter()
+            .next()
+            .is_none());
+    }
 }
@@ -1,7 +1,8 @@
+use crate::prelude::*;
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
+use core::ops::Deref;
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
-use std::ops::Deref;
 
 /// # System Event Log (Type 15)
 ///
@@ -19,6 +20,7 @@ pub struct SMBiosSystemEventLog<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemEventLog<'a> {
     const STRUCT_TYPE: u8 = 15u8;
+    const MIN_LENGTH: u8 = 0x14;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -132,7 +134,7 @@ impl<'a> SMBiosSystemEventLog<'a> {
 
 impl fmt::Debug for SMBiosSystemEventLog<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemEventLog<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemEventLog<'_>>())
             .field("header", &self.parts.header)
             .field("log_area_length", &self.log_area_length())
             .field("log_header_start_offset", &self.log_header_start_offset())
@@ -198,7 +200,7 @@ pub struct LogTypeData {
 
 impl fmt::Debug for LogTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<LogTypeData>())
+        fmt.debug_struct(core::any::type_name::<LogTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -221,7 +223,7 @@ impl fmt::Display for LogTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             LogType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -235,7 +237,7 @@ impl Deref for LogTypeData {
 }
 
 /// # System Event Log - Log Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum LogType {
     /// Single-bit ECC memory error
     SingleBitEccMemoryError,
@@ -287,6 +289,41 @@ pub enum LogType {
     None,
 }
 
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LogType::SingleBitEccMemoryError => "Single-bit ECC memory error",
+            LogType::MultiBitEccMemoryError => "Multi-bit ECC memory error",
+            LogType::ParityMemoryError => "Parity memory error",
+            LogType::BusTimeOut => "Bus time-out",
+            LogType::IOChannelCheck => "I/O Channel Check",
+            LogType::SoftwareNmi => "Software NMI",
+            LogType::PostMemoryResize => "POST Memory Resize",
+            LogType::PostError => "POST Error",
+            LogType::PciParityError => "PCI Parity Error",
+            LogType::PciSystemError => "PCI System Error",
+            LogType::CpuFailure => "CPU Failure",
+            LogType::EisaFailSafeTimerTimeout => "EISA FailSafe Timer time-out",
+            LogType::CorrectableMemoryLogDisabled => "Correctable memory log disabled",
+            LogType::LoggingDisabledForSpecificEventType => {
+                "Logging disabled for a specific Event Type"
+            }
+            LogType::Reserved0F => "Reserved",
+            LogType::SystemLimitExceeded => "System Limit Exceeded",
+            LogType::AsyncHardwareTimerExpired => {
+                "Asynchronous hardware timer expired and issued a system reset"
+            }
+            LogType::SystemConfigurationInformation => "System configuration information",
+            LogType::HardDiskInformation => "Hard-disk information",
+            LogType::SystemReconfigured => "System reconfigured",
+            LogType::UncorrectableCpuComplexError => "Uncorrectable CPU-complex error",
+            LogType::LogAreaReset => "Log Area Reset/Cleared",
+            LogType::SystemBoot => "System boot",
+            LogType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for LogTypeData {
     fn from(raw: u8) -> Self {
         LogTypeData {
@@ -336,7 +373,7 @@ pub struct VariableDataFormatTypeData {
 
 impl fmt::Debug for VariableDataFormatTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<VariableDataFormatTypeData>())
+        fmt.debug_struct(core::any::type_name::<VariableDataFormatTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -359,7 +396,7 @@ impl fmt::Display for VariableDataFormatTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             VariableDataFormatType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -373,7 +410,7 @@ impl Deref for VariableDataFormatTypeData {
 }
 
 /// # System Event Log - Variable Data Format Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VariableDataFormatType {
     /// No standard format data is available; the first byte of the variable data (if present) contains OEM-specific unformatted information.
     NoStandardFormat,
@@ -393,6 +430,23 @@ pub enum VariableDataFormatType {
     None,
 }
 
+impl fmt::Display for VariableDataFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VariableDataFormatType::NoStandardFormat => "No standard format",
+            VariableDataFormatType::Handle => "Handle",
+            VariableDataFormatType::MultipleEvent => "Multiple-event",
+            VariableDataFormatType::MultipleEventHandle => "Multiple-event handle",
+            VariableDataFormatType::PostResultsBitmap => "POST results bitmap",
+            VariableDataFormatType::SystemManagementType => "System management type",
+            VariableDataFormatType::MultipleEventSystemManagementType => {
+                "Multiple-event system management type"
+            }
+            VariableDataFormatType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for VariableDataFormatTypeData {
     fn from(raw: u8) -> Self {
         VariableDataFormatTypeData {
@@ -426,7 +480,7 @@ pub struct AccessMethodData {
 
 impl fmt::Debug for AccessMethodData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<AccessMethodData>())
+        fmt.debug_struct(core::any::type_name::<AccessMethodData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -464,7 +518,7 @@ impl From<u8> for AccessMethodData {
 /// # System Event Log - Access Method
 ///
 /// Defines the Location and Method used by higher-level software to access the log area.
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum AccessMethod {
     /// 00h Indexed I/O
     ///
@@ -493,6 +547,27 @@ pub enum AccessMethod {
     None,
 }
 
+impl fmt::Display for AccessMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AccessMethod::IndexedIO18Bit => {
+                "Indexed I/O, one 8-bit index port, one 8-bit data port"
+            }
+            AccessMethod::IndexedIO28Bit => {
+                "Indexed I/O, two 8-bit index ports, one 8-bit data port"
+            }
+            AccessMethod::IndexedIO116Bit => {
+                "Indexed I/O, one 16-bit index port, one 8-bit data port"
+            }
+            AccessMethod::MemoryMapped32Bit => "Memory-mapped physical 32-bit address",
+            AccessMethod::GeneralPurposeNonVolatile => {
+                "General-purpose non-volatile data functions"
+            }
+            AccessMethod::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for AccessMethod {
     fn from(raw: u8) -> Self {
         match raw {
@@ -547,7 +622,7 @@ impl<'a> EventLogTypeDescriptor<'a> {
 
 impl<'a> fmt::Debug for EventLogTypeDescriptor<'a> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<EventLogTypeDescriptor<'_>>())
+        fmt.debug_struct(core::any::type_name::<EventLogTypeDescriptor<'_>>())
             .field("raw", &self.raw)
             .field("log_type", &self.log_type())
             .field(
@@ -610,7 +685,7 @@ impl<'a> TypeDescriptors<'a> {
 
 impl<'a> fmt::Debug for TypeDescriptors<'a> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<TypeDescriptors<'_>>())
+        fmt.debug_struct(core::any::type_name::<TypeDescriptors<'_>>())
             .field("descriptors", &self.into_iter())
             .finish()
     }
@@ -737,7 +812,7 @@ impl LogStatus {
 
 impl fmt::Debug for LogStatus {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<LogStatus>())
+        fmt.debug_struct(core::any::type_name::<LogStatus>())
             .field("raw", &self.raw)
             .field("log_area_valid", &self.log_area_valid())
             .field("log_area_full", &self.log_area_full())
@@ -773,7 +848,7 @@ pub struct HeaderFormatData {
 
 impl fmt::Debug for HeaderFormatData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<HeaderFormatData>())
+        fmt.debug_struct(core::any::type_name::<HeaderFormatData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -801,7 +876,7 @@ impl Deref for HeaderFormatData {
 }
 
 /// # System Event Log - Header Format
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HeaderFormat {
     /// No header (for example, the header is 0 bytes in length)
     NoHeader,
@@ -811,6 +886,16 @@ pub enum HeaderFormat {
     None,
 }
 
+impl fmt::Display for HeaderFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HeaderFormat::NoHeader => "No header",
+            HeaderFormat::Type1LogHeader => "Type 1 log header",
+            HeaderFormat::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for HeaderFormatData {
     fn from(raw: u8) -> Self {
         HeaderFormatData {
@@ -828,6 +913,7 @@ impl From<u8> for HeaderFormatData {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn unit_test() {
         let struct_type15 = vec![
@@ -871,4 +957,72 @@ mod tests {
         let first = iterator.next().unwrap();
         assert_eq!(*first.log_type(), LogType::SingleBitEccMemoryError);
     }
+
+    #[test]
+    fn test_type_descriptor_lengths() {
+        // Three descriptors, each three bytes long (a future extension of the 2-byte entry)
+        let struct_type15 = vec![
+            0x0F, 0x20, 0x3E, 0x00, 0x00, 0x04, 0x00, 0x00, 0x10, 0x00, 0x04, 0x03, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03, 0x03, 0x01, 0x00, 0xAA, 0x08, 0x04,
+            0xBB, 0xE0, 0xE0, 0xCC, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type15);
+        let test_struct = SMBiosSystemEventLog::new(&parts);
+
+        assert_eq!(
+            *test_struct.access_method().unwrap(),
+            AccessMethod::GeneralPurposeNonVolatile
+        );
+        let status = test_struct.log_status().unwrap();
+        assert!(status.log_area_valid());
+        assert!(status.log_area_full());
+        assert_eq!(
+            *test_struct.log_header_format().unwrap(),
+            HeaderFormat::Type1LogHeader
+        );
+
+        let type_descriptors = test_struct.type_descriptors().unwrap();
+        let descriptors: Vec<EventLogTypeDescriptor<'_>> = type_descriptors.into_iter().collect();
+        assert_eq!(descriptors.len(), 3);
+        assert_eq!(*descriptors[0].log_type(), LogType::SingleBitEccMemoryError);
+        assert_eq!(
+            *descriptors[0].variable_data_format_type(),
+            VariableDataFormatType::NoStandardFormat
+        );
+        assert_eq!(descriptors[0].raw, &[0x01, 0x00, 0xAA]);
+        assert_eq!(*descriptors[1].log_type(), LogType::PostError);
+        assert_eq!(
+            *descriptors[1].variable_data_format_type(),
+            VariableDataFormatType::PostResultsBitmap
+        );
+        assert_eq!(*descriptors[2].log_type(), LogType::None);
+        assert_eq!(descriptors[2].log_type().raw, 0xE0);
+
+        // The iterator resets once exhausted and can be walked again
+        let mut iterator = type_descriptors.into_iter();
+        assert_eq!(iterator.by_ref().count(), 3);
+        assert_eq!(iterator.count(), 3);
+
+        // A descriptor list that runs past the end of the structure is not exposed
+        let truncated = vec![
+            0x0F, 0x19, 0x3F, 0x00, 0x00, 0x04, 0x00, 0x00, 0x10, 0x00, 0x03, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x04, 0x02, 0x01, 0x00, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&truncated);
+        let test_struct = SMBiosSystemEventLog::new(&parts);
+        assert!(test_struct.type_descriptors().is_none());
+
+        // Zero descriptors yields an empty iterator
+        let empty = vec![
+            0x0F, 0x17, 0x40, 0x00, 0x00, 0x04, 0x00, 0x00, 0x10, 0x00, 0x03, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&empty);
+        let test_struct = SMBiosSystemEventLog::new(&parts);
+        assert_eq!(
+            test_struct.type_descriptors().unwrap().into_iter().count(),
+            0
+        );
+    }
 }
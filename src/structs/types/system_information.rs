@@ -1,12 +1,13 @@
-use crate::core::{strings::*, UndefinedStruct};
+use crate::core::{strings::*, SMBiosVersion, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
-use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::{
+use core::{
     array::TryFromSliceError,
     convert::{TryFrom, TryInto},
     fmt,
     ops::Deref,
 };
+use serde::{ser::SerializeStruct, Serialize, Serializer};
 
 /// # System Information (Type 1)
 ///
@@ -23,6 +24,7 @@ pub struct SMBiosSystemInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemInformation<'a> {
     const STRUCT_TYPE: u8 = 1u8;
+    const MIN_LENGTH: u8 = 0x08;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -103,7 +105,7 @@ impl<'a> SMBiosSystemInformation<'a> {
 
 impl fmt::Debug for SMBiosSystemInformation<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemInformation<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemInformation<'_>>())
             .field("header", &self.parts.header)
             .field("manufacturer", &self.manufacturer())
             .field("product_name", &self.product_name())
@@ -137,7 +139,7 @@ impl Serialize for SMBiosSystemInformation<'_> {
 }
 
 /// # System - UUID Data
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum SystemUuidData {
     /// The ID is not currently present in the system, but it can be set
     IdNotPresentButSettable,
@@ -178,7 +180,7 @@ impl fmt::Display for SystemUuidData {
 }
 
 /// # System - UUID
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct SystemUuid {
     /// Raw byte array for this UUID
     pub raw: [u8; 0x10],
@@ -214,6 +216,27 @@ impl SystemUuid {
     pub fn node(&self) -> &[u8; 6] {
         self.raw[0xA..0x10].try_into().expect("incorrect size")
     }
+
+    /// UUID string for the given SMBIOS version
+    ///
+    /// Beginning with SMBIOS 2.6 the time_low, time_mid, and
+    /// time_high_and_version fields are encoded little-endian
+    /// (as used by [fmt::Display]). Earlier versions encode
+    /// every field in network byte order (big-endian).
+    pub fn to_string_for_version(&self, version: &SMBiosVersion) -> String {
+        if *version >= SMBiosVersion::new(2, 6, 0) {
+            return format!("{}", self);
+        }
+
+        self.raw
+            .iter()
+            .enumerate()
+            .map(|(index, byte)| match index {
+                0x4 | 0x6 | 0x8 | 0xA => format!("-{:02x}", byte),
+                _ => format!("{:02x}", byte),
+            })
+            .collect()
+    }
 }
 
 impl<'a> From<&'a [u8; 0x10]> for SystemUuid {
@@ -279,7 +302,7 @@ pub struct SystemWakeUpTypeData {
 
 impl fmt::Debug for SystemWakeUpTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SystemWakeUpTypeData>())
+        fmt.debug_struct(core::any::type_name::<SystemWakeUpTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -302,7 +325,7 @@ impl fmt::Display for SystemWakeUpTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             SystemWakeUpType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
@@ -316,7 +339,7 @@ impl Deref for SystemWakeUpTypeData {
 }
 
 /// # System - Wake-up Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SystemWakeUpType {
     /// Other
     Other,
@@ -338,6 +361,22 @@ pub enum SystemWakeUpType {
     None,
 }
 
+impl fmt::Display for SystemWakeUpType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SystemWakeUpType::Other => "Other",
+            SystemWakeUpType::Unknown => "Unknown",
+            SystemWakeUpType::ApmTimer => "APM Timer",
+            SystemWakeUpType::ModernRing => "Modem Ring",
+            SystemWakeUpType::LanRemote => "LAN Remote",
+            SystemWakeUpType::PowerSwitch => "Power Switch",
+            SystemWakeUpType::PciPme => "PCI PME#",
+            SystemWakeUpType::ACPowerRestored => "AC Power Restored",
+            SystemWakeUpType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u8> for SystemWakeUpTypeData {
     fn from(raw: u8) -> Self {
         SystemWakeUpTypeData {
@@ -360,6 +399,7 @@ impl From<u8> for SystemWakeUpTypeData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Handle, SMBiosStructError, StructureBuilder};
 
     #[test]
     fn unit_test() {
@@ -409,4 +449,114 @@ mod tests {
             "ThinkStation P520".to_string()
         );
     }
+
+    #[test]
+    fn test_uuid_byte_order() {
+        let raw = [
+            0xD2, 0x01, 0x25, 0x3E, 0x48, 0xE6, 0x11, 0xE8, 0xBA, 0xD3, 0x70, 0x20, 0x84, 0x0F,
+            0x9D, 0x47,
+        ];
+
+        match SystemUuidData::try_from(&raw[..]).unwrap() {
+            SystemUuidData::Uuid(uuid) => {
+                // SMBIOS 2.6 and later: first three fields are little-endian
+                assert_eq!(
+                    uuid.to_string_for_version(&SMBiosVersion::new(3, 2, 0)),
+                    "3e2501d2-e648-e811-bad3-7020840f9d47".to_string()
+                );
+                assert_eq!(
+                    uuid.to_string_for_version(&SMBiosVersion::new(2, 6, 0)),
+                    format!("{}", uuid)
+                );
+                // Prior to SMBIOS 2.6: network byte order
+                assert_eq!(
+                    uuid.to_string_for_version(&SMBiosVersion::new(2, 5, 0)),
+                    "d201253e-48e6-11e8-bad3-7020840f9d47".to_string()
+                );
+            }
+            _ => panic!("expected a UUID"),
+        }
+
+        match SystemUuidData::try_from(&[0u8; 0x10][..]).unwrap() {
+            SystemUuidData::IdNotPresentButSettable => (),
+            _ => panic!("expected IdNotPresentButSettable"),
+        }
+        match SystemUuidData::try_from(&[0xFFu8; 0x10][..]).unwrap() {
+            SystemUuidData::IdNotPresent => (),
+            _ => panic!("expected IdNotPresent"),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let struct_type1 = vec![
+            0x01, 0x08, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, b'A', 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type1);
+        assert_eq!(SMBiosSystemInformation::new(&parts).validate(), Ok(()));
+
+        // Declares a length of 6, shorter than the SMBIOS 2.0 length of 8
+        let struct_type1 = vec![0x01, 0x06, 0x01, 0x00, 0x01, 0x00, b'A', 0x00, 0x00];
+        let parts = UndefinedStruct::new(&struct_type1);
+        assert_eq!(
+            SMBiosSystemInformation::new(&parts).validate(),
+            Err(SMBiosStructError::LengthTooShort {
+                struct_type: 1,
+                length: 0x06,
+                min_length: SMBiosSystemInformation::MIN_LENGTH
+            })
+        );
+    }
+
+    #[test]
+    fn test_header_fields() {
+        let struct_type1 = vec![
+            0x01, 0x08, 0x34, 0x12, 0x01, 0x00, 0x00, 0x00, b'A', 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type1);
+        let test_struct = SMBiosSystemInformation::new(&parts);
+
+        assert_eq!(
+            test_struct.struct_type(),
+            SMBiosSystemInformation::STRUCT_TYPE
+        );
+        assert_eq!(test_struct.length(), 0x08);
+        assert_eq!(test_struct.handle(), Handle(0x1234));
+    }
+
+    #[test]
+    fn test_wakeup_type() {
+        let cases = [
+            (0x01u8, SystemWakeUpType::Other),
+            (0x02, SystemWakeUpType::Unknown),
+            (0x03, SystemWakeUpType::ApmTimer),
+            (0x04, SystemWakeUpType::ModernRing),
+            (0x05, SystemWakeUpType::LanRemote),
+            (0x06, SystemWakeUpType::PowerSwitch),
+            (0x07, SystemWakeUpType::PciPme),
+            (0x08, SystemWakeUpType::ACPowerRestored),
+            (0x00, SystemWakeUpType::None),
+            (0x09, SystemWakeUpType::None),
+        ];
+        for (raw, value) in cases.iter() {
+            let wakeup_type = SystemWakeUpTypeData::from(*raw);
+            assert_eq!(wakeup_type.raw, *raw);
+            assert_eq!(wakeup_type.value, *value);
+        }
+
+        // Values unknown to the standard display their raw value
+        assert_eq!(SystemWakeUpTypeData::from(0x09).to_string(), "9");
+
+        let struct_type1 = StructureBuilder::new(SMBiosSystemInformation::STRUCT_TYPE, 1)
+            .bytes(&[0; 4])
+            .bytes(&[0x11; 16])
+            .byte(0x07)
+            .build();
+        let parts = UndefinedStruct::new(&struct_type1);
+        let test_struct = SMBiosSystemInformation::new(&parts);
+        assert_eq!(
+            *test_struct.wakeup_type().unwrap(),
+            SystemWakeUpType::PciPme
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # System Power Controls (Type 25)
 ///
@@ -21,6 +21,7 @@ pub struct SMBiosSystemPowerControls<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemPowerControls<'a> {
     const STRUCT_TYPE: u8 = 25u8;
+    const MIN_LENGTH: u8 = 0x09;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -71,11 +72,56 @@ impl<'a> SMBiosSystemPowerControls<'a> {
     pub fn next_scheduled_power_on_second(&self) -> Option<u8> {
         self.parts.get_field_byte(0x08)
     }
+
+    /// 'next_scheduled_power_on_month' decoded from BCD
+    ///
+    /// None when the field is FFh (don't care) or not valid BCD.
+    pub fn next_scheduled_power_on_month_decoded(&self) -> Option<u8> {
+        self.next_scheduled_power_on_month().and_then(decode_bcd)
+    }
+
+    /// 'next_scheduled_power_on_day_of_month' decoded from BCD
+    ///
+    /// None when the field is FFh (don't care) or not valid BCD.
+    pub fn next_scheduled_power_on_day_of_month_decoded(&self) -> Option<u8> {
+        self.next_scheduled_power_on_day_of_month()
+            .and_then(decode_bcd)
+    }
+
+    /// 'next_scheduled_power_on_hour' decoded from BCD
+    ///
+    /// None when the field is FFh (don't care) or not valid BCD.
+    pub fn next_scheduled_power_on_hour_decoded(&self) -> Option<u8> {
+        self.next_scheduled_power_on_hour().and_then(decode_bcd)
+    }
+
+    /// 'next_scheduled_power_on_minute' decoded from BCD
+    ///
+    /// None when the field is FFh (don't care) or not valid BCD.
+    pub fn next_scheduled_power_on_minute_decoded(&self) -> Option<u8> {
+        self.next_scheduled_power_on_minute().and_then(decode_bcd)
+    }
+
+    /// 'next_scheduled_power_on_second' decoded from BCD
+    ///
+    /// None when the field is FFh (don't care) or not valid BCD.
+    pub fn next_scheduled_power_on_second_decoded(&self) -> Option<u8> {
+        self.next_scheduled_power_on_second().and_then(decode_bcd)
+    }
+}
+
+/// Decodes a packed BCD byte, FFh meaning "don't care"
+fn decode_bcd(raw: u8) -> Option<u8> {
+    let (tens, ones) = (raw >> 4, raw & 0x0F);
+    match tens <= 9 && ones <= 9 {
+        true => Some(tens * 10 + ones),
+        false => None,
+    }
 }
 
 impl fmt::Debug for SMBiosSystemPowerControls<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemPowerControls<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemPowerControls<'_>>())
             .field("header", &self.parts.header)
             .field(
                 "next_scheduled_power_on_month",
@@ -135,6 +181,7 @@ impl Serialize for SMBiosSystemPowerControls<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -151,4 +198,58 @@ mod tests {
         assert_eq!(test_struct.next_scheduled_power_on_minute(), Some(0));
         assert_eq!(test_struct.next_scheduled_power_on_second(), Some(0));
     }
+
+    #[test]
+    fn test_decoded() {
+        // December 31st, 23:59:58
+        let struct_type25 = vec![
+            0x19, 0x09, 0x28, 0x00, 0x12, 0x31, 0x23, 0x59, 0x58, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type25);
+        let test_struct = SMBiosSystemPowerControls::new(&parts);
+
+        assert_eq!(test_struct.next_scheduled_power_on_month(), Some(0x12));
+        assert_eq!(
+            test_struct.next_scheduled_power_on_month_decoded(),
+            Some(12)
+        );
+        assert_eq!(
+            test_struct.next_scheduled_power_on_day_of_month_decoded(),
+            Some(31)
+        );
+        assert_eq!(test_struct.next_scheduled_power_on_hour_decoded(), Some(23));
+        assert_eq!(
+            test_struct.next_scheduled_power_on_minute_decoded(),
+            Some(59)
+        );
+        assert_eq!(
+            test_struct.next_scheduled_power_on_second_decoded(),
+            Some(58)
+        );
+
+        // Every day at 06:30, month and day of month are "don't care"
+        let struct_type25 = vec![
+            0x19, 0x09, 0x29, 0x00, 0xFF, 0xFF, 0x06, 0x30, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type25);
+        let test_struct = SMBiosSystemPowerControls::new(&parts);
+
+        assert_eq!(test_struct.next_scheduled_power_on_month(), Some(0xFF));
+        assert_eq!(test_struct.next_scheduled_power_on_month_decoded(), None);
+        assert_eq!(
+            test_struct.next_scheduled_power_on_day_of_month_decoded(),
+            None
+        );
+        assert_eq!(test_struct.next_scheduled_power_on_hour_decoded(), Some(6));
+        assert_eq!(
+            test_struct.next_scheduled_power_on_minute_decoded(),
+            Some(30)
+        );
+        assert_eq!(
+            test_struct.next_scheduled_power_on_second_decoded(),
+            Some(0)
+        );
+    }
 }
@@ -1,7 +1,9 @@
 use crate::core::{strings::*, Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{
+    SMBiosCoolingDevice, SMBiosData, SMBiosElectricalCurrentProbe, SMBiosStruct, SMBiosVoltageProbe,
+};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # System Power Supply (Type 39)
 ///
@@ -19,6 +21,7 @@ pub struct SMBiosSystemPowerSupply<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemPowerSupply<'a> {
     const STRUCT_TYPE: u8 = 39u8;
+    const MIN_LENGTH: u8 = 0x10;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -129,6 +132,14 @@ impl<'a> SMBiosSystemPowerSupply<'a> {
         self.parts.get_field_handle(0x10)
     }
 
+    /// The voltage probe referenced by [SMBiosSystemPowerSupply::input_voltage_probe_handle]
+    pub fn input_voltage_probe_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosVoltageProbe<'b>> {
+        data.resolve(self.input_voltage_probe_handle()?)
+    }
+
     /// Cooling device handle
     ///
     /// Handle, or instance number, of a [super::SMBiosCoolingDevice] (Type
@@ -140,6 +151,14 @@ impl<'a> SMBiosSystemPowerSupply<'a> {
         self.parts.get_field_handle(0x12)
     }
 
+    /// The cooling device referenced by [SMBiosSystemPowerSupply::cooling_device_handle]
+    pub fn cooling_device_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosCoolingDevice<'b>> {
+        data.resolve(self.cooling_device_handle()?)
+    }
+
     /// Input current probe handle
     ///
     /// Handle, or instance number, of the [super::SMBiosElectricalCurrentProbe]
@@ -151,11 +170,19 @@ impl<'a> SMBiosSystemPowerSupply<'a> {
     pub fn input_current_probe_handle(&self) -> Option<Handle> {
         self.parts.get_field_handle(0x14)
     }
+
+    /// The electrical current probe referenced by [SMBiosSystemPowerSupply::input_current_probe_handle]
+    pub fn input_current_probe_handle_resolved<'b>(
+        &self,
+        data: &'b SMBiosData,
+    ) -> Option<SMBiosElectricalCurrentProbe<'b>> {
+        data.resolve(self.input_current_probe_handle()?)
+    }
 }
 
 impl fmt::Debug for SMBiosSystemPowerSupply<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemPowerSupply<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemPowerSupply<'_>>())
             .field("header", &self.parts.header)
             .field("power_unit_group", &self.power_unit_group())
             .field("location", &self.location())
@@ -268,7 +295,7 @@ impl PowerSupplyCharacteristics {
 
 impl fmt::Debug for PowerSupplyCharacteristics {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<PowerSupplyCharacteristics>())
+        fmt.debug_struct(core::any::type_name::<PowerSupplyCharacteristics>())
             .field("raw", &self.raw)
             .field("power_supply_type", &self.power_supply_type())
             .field("power_supply_status", &self.power_supply_status())
@@ -304,7 +331,7 @@ impl Serialize for PowerSupplyCharacteristics {
 }
 
 /// # DMTF Power Supply Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PowerSupplyType {
     /// Other
     Other,
@@ -326,6 +353,22 @@ pub enum PowerSupplyType {
     None,
 }
 
+impl fmt::Display for PowerSupplyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PowerSupplyType::Other => "Other",
+            PowerSupplyType::Unknown => "Unknown",
+            PowerSupplyType::Linear => "Linear",
+            PowerSupplyType::Switching => "Switching",
+            PowerSupplyType::Battery => "Battery",
+            PowerSupplyType::Ups => "UPS",
+            PowerSupplyType::Converter => "Converter",
+            PowerSupplyType::Regulator => "Regulator",
+            PowerSupplyType::None => "Undefined",
+        })
+    }
+}
+
 impl From<u16> for PowerSupplyType {
     fn from(raw: u16) -> Self {
         match raw & 0b0011_1100_0000_0000 {
@@ -343,7 +386,7 @@ impl From<u16> for PowerSupplyType {
 }
 
 /// # Power Supply Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PowerSupplyStatus {
     /// Other
     Other,
@@ -359,6 +402,19 @@ pub enum PowerSupplyStatus {
     None,
 }
 
+impl fmt::Display for PowerSupplyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PowerSupplyStatus::Other => "Other",
+            PowerSupplyStatus::Unknown => "Unknown",
+            PowerSupplyStatus::OK => "OK",
+            PowerSupplyStatus::NonCritical => "Non-critical",
+            PowerSupplyStatus::Critical => "Critical",
+            PowerSupplyStatus::None => "Undefined",
+        })
+    }
+}
+
 impl From<u16> for PowerSupplyStatus {
     fn from(raw: u16) -> Self {
         match raw & 0b0000_0011_1000_0000 {
@@ -373,7 +429,7 @@ impl From<u16> for PowerSupplyStatus {
 }
 
 /// # DMTF Input Voltage Range Switching
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum InputVoltageRangeSwitching {
     /// Other
     Other,
@@ -391,6 +447,20 @@ pub enum InputVoltageRangeSwitching {
     None,
 }
 
+impl fmt::Display for InputVoltageRangeSwitching {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InputVoltageRangeSwitching::Other => "Other",
+            InputVoltageRangeSwitching::Unknown => "Unknown",
+            InputVoltageRangeSwitching::Manual => "Manual",
+            InputVoltageRangeSwitching::AutoSwitch => "Auto-switch",
+            InputVoltageRangeSwitching::WideRange => "Wide range",
+            InputVoltageRangeSwitching::NotApplicable => "Not applicable",
+            InputVoltageRangeSwitching::None => "Undefined",
+        })
+    }
+}
+
 impl From<u16> for InputVoltageRangeSwitching {
     fn from(raw: u16) -> Self {
         match raw & 0b0000_0000_0111_1000 {
@@ -408,7 +478,7 @@ impl From<u16> for InputVoltageRangeSwitching {
 /// # Max Power Capacity
 ///
 /// Maximum sustained power output in Watts
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MaxPowerCapacity {
     /// Maximum sustained power output in Watts
     Watts(u16),
@@ -416,6 +486,15 @@ pub enum MaxPowerCapacity {
     Unknown,
 }
 
+impl fmt::Display for MaxPowerCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MaxPowerCapacity::Watts(watts) => write!(f, "{} W", watts),
+            MaxPowerCapacity::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for MaxPowerCapacity {
     fn from(raw: u16) -> Self {
         if raw == 0x8000 {
@@ -429,7 +508,12 @@ impl From<u16> for MaxPowerCapacity {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
+    use crate::{
+        SMBiosCoolingDevice, SMBiosElectricalCurrentProbe, SMBiosVoltageProbe, StructureBuilder,
+    };
 
+    #[cfg(feature = "std")]
     #[test]
     fn unit_test() {
         let struct_type39 = vec![
@@ -494,4 +578,123 @@ mod tests {
         assert_eq!(*test_struct.cooling_device_handle().unwrap(), 56);
         assert_eq!(*test_struct.input_current_probe_handle().unwrap(), 57);
     }
+
+    #[test]
+    fn test_power_supply_characteristics() {
+        // 0x11A2: switching, OK, auto-switch, present
+        let characteristics = PowerSupplyCharacteristics::from(0x11A2);
+        assert_eq!(
+            characteristics.power_supply_type(),
+            PowerSupplyType::Switching
+        );
+        assert_eq!(characteristics.power_supply_status(), PowerSupplyStatus::OK);
+        assert_eq!(
+            characteristics.input_voltage_range_switching(),
+            InputVoltageRangeSwitching::AutoSwitch
+        );
+        assert!(!characteristics.unplugged_from_wall());
+        assert!(characteristics.is_present());
+        assert!(!characteristics.hot_replaceable());
+
+        // 0x1AAD: UPS, critical, wide range, unplugged, hot-replaceable
+        let characteristics = PowerSupplyCharacteristics::from(0x1AAD);
+        assert_eq!(characteristics.power_supply_type(), PowerSupplyType::Ups);
+        assert_eq!(
+            characteristics.power_supply_status(),
+            PowerSupplyStatus::Critical
+        );
+        assert_eq!(
+            characteristics.input_voltage_range_switching(),
+            InputVoltageRangeSwitching::WideRange
+        );
+        assert!(characteristics.unplugged_from_wall());
+        assert!(!characteristics.is_present());
+        assert!(characteristics.hot_replaceable());
+
+        // A 750 W supply with no strings and no probe or cooling handles
+        let struct_type39 = vec![
+            0x27, 0x16, 0x3B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xEE, 0x02,
+            0xA3, 0x11, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type39);
+        let test_struct = SMBiosSystemPowerSupply::new(&parts);
+
+        assert_eq!(
+            test_struct.max_power_capacity(),
+            Some(MaxPowerCapacity::Watts(750))
+        );
+        assert!(test_struct
+            .power_supply_characteristics()
+            .unwrap()
+            .hot_replaceable());
+        assert_eq!(*test_struct.input_voltage_probe_handle().unwrap(), 0xFFFF);
+    }
+
+    #[test]
+    fn test_handles_resolved() {
+        let mut table_data = StructureBuilder::new(SMBiosVoltageProbe::STRUCT_TYPE, 0x1A00)
+            .string("PSU Input Voltage")
+            .build();
+        table_data.extend(
+            StructureBuilder::new(SMBiosCoolingDevice::STRUCT_TYPE, 0x1B00)
+                .handle(Handle(0xFFFF))
+                .byte(0x67) // Fan, OK
+                .build(),
+        );
+        table_data.extend(
+            StructureBuilder::new(SMBiosElectricalCurrentProbe::STRUCT_TYPE, 0x1D00)
+                .string("PSU Input Current")
+                .build(),
+        );
+
+        // Probes and cooling device which resolve, handles of another
+        // structure type, and missing handles
+        for (handle, voltage, cooling, current) in [
+            (0x2700, 0x1A00, 0x1B00, 0x1D00),
+            (0x2701, 0x1B00, 0x1D00, 0x1A00),
+            (0x2702, 0x1234, 0x1235, 0x1236),
+        ]
+        .iter()
+        {
+            table_data.extend(
+                StructureBuilder::new(SMBiosSystemPowerSupply::STRUCT_TYPE, *handle)
+                    .byte(1)
+                    .bytes(&[0; 7])
+                    .word(0x8000)
+                    .word(0x11A2)
+                    .handle(Handle(*voltage))
+                    .handle(Handle(*cooling))
+                    .handle(Handle(*current))
+                    .build(),
+            );
+        }
+        let data = SMBiosData::from_vec_and_version(table_data, None);
+        let power_supplies = data.collect::<SMBiosSystemPowerSupply<'_>>();
+
+        let voltage_probe = power_supplies[0]
+            .input_voltage_probe_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(voltage_probe.handle(), Handle(0x1A00));
+        assert_eq!(voltage_probe.description().to_string(), "PSU Input Voltage");
+        let cooling_device = power_supplies[0]
+            .cooling_device_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(cooling_device.handle(), Handle(0x1B00));
+        let current_probe = power_supplies[0]
+            .input_current_probe_handle_resolved(&data)
+            .unwrap();
+        assert_eq!(current_probe.handle(), Handle(0x1D00));
+        assert_eq!(current_probe.description().to_string(), "PSU Input Current");
+
+        for power_supply in &power_supplies[1..] {
+            assert!(power_supply
+                .input_voltage_probe_handle_resolved(&data)
+                .is_none());
+            assert!(power_supply.cooling_device_handle_resolved(&data).is_none());
+            assert!(power_supply
+                .input_current_probe_handle_resolved(&data)
+                .is_none());
+        }
+    }
 }
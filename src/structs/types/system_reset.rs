@@ -1,6 +1,6 @@
 use crate::{SMBiosStruct, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # System Reset (Type 23)
 ///
@@ -21,6 +21,7 @@ pub struct SMBiosSystemReset<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemReset<'a> {
     const STRUCT_TYPE: u8 = 23u8;
+    const MIN_LENGTH: u8 = 0x0D;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -83,11 +84,43 @@ impl<'a> SMBiosSystemReset<'a> {
             .get_field_word(0x0B)
             .map(|raw| Timeout::from(raw))
     }
+
+    /// 'reset_count', or None when it is unknown (FFFFh)
+    pub fn known_reset_count(&self) -> Option<u16> {
+        match self.reset_count()? {
+            ResetCount::Count(count) => Some(count),
+            ResetCount::Unknown => None,
+        }
+    }
+
+    /// 'reset_limit', or None when it is unknown (FFFFh)
+    pub fn known_reset_limit(&self) -> Option<u16> {
+        match self.reset_limit()? {
+            ResetLimit::Count(count) => Some(count),
+            ResetLimit::Unknown => None,
+        }
+    }
+
+    /// 'timer_interval' in minutes, or None when it is unknown (FFFFh)
+    pub fn known_timer_interval(&self) -> Option<u16> {
+        match self.timer_interval()? {
+            TimerInterval::Minutes(minutes) => Some(minutes),
+            TimerInterval::Unknown => None,
+        }
+    }
+
+    /// 'timeout' in minutes, or None when it is unknown (FFFFh)
+    pub fn known_timeout(&self) -> Option<u16> {
+        match self.timeout()? {
+            Timeout::Minutes(minutes) => Some(minutes),
+            Timeout::Unknown => None,
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosSystemReset<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemReset<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemReset<'_>>())
             .field("header", &self.parts.header)
             .field("capabilities", &self.capabilities())
             .field("reset_count", &self.reset_count())
@@ -161,7 +194,7 @@ impl SystemResetCapabilities {
 
 impl fmt::Debug for SystemResetCapabilities {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SystemResetCapabilities>())
+        fmt.debug_struct(core::any::type_name::<SystemResetCapabilities>())
             .field("raw", &self.raw)
             .field("has_watchdog_timer", &self.has_watchdog_timer())
             .field("boot_option_on_limit", &self.boot_option_on_limit())
@@ -190,7 +223,7 @@ impl Serialize for SystemResetCapabilities {
 ///
 /// Identifies one of the following system actions to
 /// be taken when the Reset Limit is reached
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BootOptionOnLimit {
     /// Reserved, do not use.
     Reserved,
@@ -202,6 +235,17 @@ pub enum BootOptionOnLimit {
     DoNotReboot,
 }
 
+impl fmt::Display for BootOptionOnLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BootOptionOnLimit::Reserved => "Reserved",
+            BootOptionOnLimit::OperatingSystem => "Operating System",
+            BootOptionOnLimit::SystemUtilities => "System utilities",
+            BootOptionOnLimit::DoNotReboot => "Do not reboot",
+        })
+    }
+}
+
 impl From<u8> for BootOptionOnLimit {
     fn from(raw: u8) -> Self {
         match raw & 0b0001_1000 {
@@ -218,7 +262,7 @@ impl From<u8> for BootOptionOnLimit {
 ///
 /// Indicates one of the following actions to be taken
 //  after a watchdog reset
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BootOption {
     /// Reserved, do not use.
     Reserved,
@@ -230,6 +274,17 @@ pub enum BootOption {
     DoNotReboot,
 }
 
+impl fmt::Display for BootOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BootOption::Reserved => "Reserved",
+            BootOption::OperatingSystem => "Operating System",
+            BootOption::SystemUtilities => "System utilities",
+            BootOption::DoNotReboot => "Do not reboot",
+        })
+    }
+}
+
 impl From<u8> for BootOption {
     fn from(raw: u8) -> Self {
         match raw & 0b0000_0110 {
@@ -243,7 +298,7 @@ impl From<u8> for BootOption {
 }
 
 /// # Reset Count
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ResetCount {
     /// Number of automatic system resets since the last intentional reset
     Count(u16),
@@ -251,6 +306,15 @@ pub enum ResetCount {
     Unknown,
 }
 
+impl fmt::Display for ResetCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ResetCount::Count(count) => write!(f, "{}", count),
+            ResetCount::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for ResetCount {
     fn from(raw: u16) -> Self {
         match raw {
@@ -261,7 +325,7 @@ impl From<u16> for ResetCount {
 }
 
 /// # Reset Limit
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ResetLimit {
     /// Number of consecutive times the system reset is attempted
     Count(u16),
@@ -269,6 +333,15 @@ pub enum ResetLimit {
     Unknown,
 }
 
+impl fmt::Display for ResetLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ResetLimit::Count(count) => write!(f, "{}", count),
+            ResetLimit::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for ResetLimit {
     fn from(raw: u16) -> Self {
         match raw {
@@ -279,7 +352,7 @@ impl From<u16> for ResetLimit {
 }
 
 /// # Timer Interval
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum TimerInterval {
     /// Number of minutes to use for the watchdog timer
     ///
@@ -290,6 +363,15 @@ pub enum TimerInterval {
     Unknown,
 }
 
+impl fmt::Display for TimerInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TimerInterval::Minutes(minutes) => write!(f, "{} min", minutes),
+            TimerInterval::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for TimerInterval {
     fn from(raw: u16) -> Self {
         match raw {
@@ -300,7 +382,7 @@ impl From<u16> for TimerInterval {
 }
 
 /// # Timeout
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum Timeout {
     /// Number of minutes before the reboot is initiated
     ///
@@ -311,6 +393,15 @@ pub enum Timeout {
     Unknown,
 }
 
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Timeout::Minutes(minutes) => write!(f, "{} min", minutes),
+            Timeout::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for Timeout {
     fn from(raw: u16) -> Self {
         match raw {
@@ -323,6 +414,7 @@ impl From<u16> for Timeout {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -354,5 +446,37 @@ mod tests {
             Timeout::Minutes(_) => panic!("expected unknown"),
             Timeout::Unknown => (),
         }
+
+        assert_eq!(test_struct.known_reset_count(), None);
+        assert_eq!(test_struct.known_reset_limit(), None);
+        assert_eq!(test_struct.known_timer_interval(), None);
+        assert_eq!(test_struct.known_timeout(), None);
+    }
+
+    #[test]
+    fn test_known_values() {
+        // 0x2D: watchdog timer, on limit boot the OS, after a watchdog reset
+        // boot the system utilities, enabled
+        let struct_type23 = vec![
+            0x17, 0x0D, 0x50, 0x01, 0x2D, 0x02, 0x00, 0x05, 0x00, 0x0A, 0x00, 0x03, 0x00, 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type23);
+        let test_struct = SMBiosSystemReset::new(&parts);
+
+        let capabilities = test_struct.capabilities().unwrap();
+        assert!(capabilities.has_watchdog_timer());
+        assert_eq!(
+            capabilities.boot_option_on_limit(),
+            BootOptionOnLimit::OperatingSystem
+        );
+        assert_eq!(capabilities.boot_option(), BootOption::SystemUtilities);
+        assert!(capabilities.reset_enabled());
+
+        assert_eq!(test_struct.known_reset_count(), Some(2));
+        assert_eq!(test_struct.known_reset_limit(), Some(5));
+        assert_eq!(test_struct.known_timer_interval(), Some(10));
+        assert_eq!(test_struct.known_timeout(), Some(3));
     }
 }
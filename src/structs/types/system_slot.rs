@@ -1,7 +1,8 @@
 use crate::core::{strings::*, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::{convert::TryInto, fmt, ops::Deref};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
-use std::{convert::TryInto, fmt, ops::Deref};
 
 /// # System Slots (Type 9)
 ///
@@ -17,6 +18,7 @@ pub struct SMBiosSystemSlot<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemSlot<'a> {
     const STRUCT_TYPE: u8 = 9u8;
+    const MIN_LENGTH: u8 = 0x0C;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -82,6 +84,16 @@ impl<'a> SMBiosSystemSlot<'a> {
             .map(|raw| SystemSlotCharacteristics2::from(raw))
     }
 
+    /// Slot Characteristics 1 and 2 decoded together
+    ///
+    /// Slot Characteristics 2 was added in version 2.1; when absent its flags
+    /// read as false and [SlotCharacteristics::raw_2] is `None`.
+    pub fn slot_characteristics(&self) -> Option<SlotCharacteristics> {
+        self.parts
+            .get_field_byte(0x0B)
+            .map(|raw_1| SlotCharacteristics::from_raw(raw_1, self.parts.get_field_byte(0x0C)))
+    }
+
     /// Segment Group Number (Base)
     pub fn segment_group_number(&self) -> Option<SegmentGroupNumber> {
         self.parts
@@ -180,7 +192,7 @@ impl<'a> SMBiosSystemSlot<'a> {
 
 impl fmt::Debug for SMBiosSystemSlot<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosSystemSlot<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosSystemSlot<'_>>())
             .field("header", &self.parts.header)
             .field("slot_designation", &self.slot_designation())
             .field("system_slot_type", &self.system_slot_type())
@@ -384,7 +396,7 @@ impl From<u8> for SystemSlotTypeData {
 
 impl fmt::Debug for SystemSlotTypeData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SystemSlotTypeData>())
+        fmt.debug_struct(core::any::type_name::<SystemSlotTypeData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -407,13 +419,13 @@ impl fmt::Display for SystemSlotTypeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.value {
             SystemSlotType::None => write!(f, "{}", &self.raw),
-            _ => write!(f, "{:?}", &self.value),
+            _ => write!(f, "{}", &self.value),
         }
     }
 }
 
 /// # System Slot Type
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SystemSlotType {
     /// Other
     Other,
@@ -479,8 +491,70 @@ pub enum SystemSlotType {
     None,
 }
 
+impl fmt::Display for SystemSlotType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SystemSlotType::Other => f.write_str("Other"),
+            SystemSlotType::Unknown => f.write_str("Unknown"),
+            SystemSlotType::Isa => f.write_str("ISA"),
+            SystemSlotType::Mca => f.write_str("MCA"),
+            SystemSlotType::Eisa => f.write_str("EISA"),
+            SystemSlotType::Pci => f.write_str("PCI"),
+            SystemSlotType::Pcmcia => f.write_str("PC Card (PCMCIA)"),
+            SystemSlotType::VlVesa => f.write_str("VL-VESA"),
+            SystemSlotType::Proprietary => f.write_str("Proprietary"),
+            SystemSlotType::ProcessorCardSlot => f.write_str("Processor Card Slot"),
+            SystemSlotType::ProprietaryMemoryCardSlot => {
+                f.write_str("Proprietary Memory Card Slot")
+            }
+            SystemSlotType::IORiserCardSlot => f.write_str("I/O Riser Card Slot"),
+            SystemSlotType::NuBus => f.write_str("NuBus"),
+            SystemSlotType::Pci66MhzCapable => f.write_str("PCI"),
+            SystemSlotType::Agp(AgpSlotWidth::X1) => f.write_str("AGP"),
+            SystemSlotType::Agp(width) => write!(f, "AGP {}", width),
+            SystemSlotType::Mxm(slot_type) => write!(f, "{}", slot_type),
+            SystemSlotType::PciX => f.write_str("PCI-X"),
+            SystemSlotType::M2(slot_type) => write!(f, "{}", slot_type),
+            SystemSlotType::OcpNic30SmallFormFactor => {
+                f.write_str("OCP NIC 3.0 Small Form Factor (SFF)")
+            }
+            SystemSlotType::OcpNic30LargeFormFactor => {
+                f.write_str("OCP NIC 3.0 Large Form Factor (LFF)")
+            }
+            SystemSlotType::OcpNicPriorTo30 => f.write_str("OCP NIC Prior to 3.0"),
+            SystemSlotType::CxlFlexbus1 => f.write_str("CXL Flexbus 1.0"),
+            SystemSlotType::PC98C20 => f.write_str("PC-98/C20"),
+            SystemSlotType::PC98C24 => f.write_str("PC-98/C24"),
+            SystemSlotType::PC98E => f.write_str("PC-98/E"),
+            SystemSlotType::PC98LocalBus => f.write_str("PC-98/Local Bus"),
+            SystemSlotType::PC98Card => f.write_str("PC-98/Card"),
+            SystemSlotType::PciExpress(PciExpressGeneration::Undefined, width) => {
+                write!(f, "{}", width)
+            }
+            SystemSlotType::PciExpress(
+                PciExpressGeneration::PCIExpressGen1,
+                PciExpressSlotWidth::UndefinedSlotWidth,
+            ) => f.write_str("PCI Express"),
+            SystemSlotType::PciExpress(PciExpressGeneration::PCIExpressGen1, width) => {
+                write!(f, "PCI Express {}", width)
+            }
+            SystemSlotType::PciExpress(generation, PciExpressSlotWidth::UndefinedSlotWidth) => {
+                write!(f, "{}", generation)
+            }
+            SystemSlotType::PciExpress(generation, width) => write!(f, "{} {}", generation, width),
+            SystemSlotType::EnterpriseAndDataCenter1UE1 => {
+                f.write_str("Enterprise and Datacenter 1U E1 Form Factor Slot (EDSFF E1.S, E1.L)")
+            }
+            SystemSlotType::EnterpriseAndDataCenter3InE3 => {
+                f.write_str("Enterprise and Datacenter 3\" E3 Form Factor Slot (EDSFF E3.S, E3.L)")
+            }
+            SystemSlotType::None => f.write_str("Undefined"),
+        }
+    }
+}
+
 /// The generation of PciExpress used by the slot.
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PciExpressGeneration {
     /// PCI Express Gen 1
     PCIExpressGen1,
@@ -498,8 +572,22 @@ pub enum PciExpressGeneration {
     Undefined,
 }
 
+impl fmt::Display for PciExpressGeneration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PciExpressGeneration::PCIExpressGen1 => "PCI Express Gen 1",
+            PciExpressGeneration::PCIExpressGen2 => "PCI Express Gen 2",
+            PciExpressGeneration::PCIExpressGen3 => "PCI Express Gen 3",
+            PciExpressGeneration::PCIExpressGen4 => "PCI Express Gen 4",
+            PciExpressGeneration::PCIExpressGen5 => "PCI Express Gen 5",
+            PciExpressGeneration::PCIExpressGen6 => "PCI Express Gen 6 and Beyond",
+            PciExpressGeneration::Undefined => "Undefined",
+        })
+    }
+}
+
 /// The slot width of a PCI Express slot specified in the SystemSlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PciExpressSlotWidth {
     /// An undefined slot width
     UndefinedSlotWidth,
@@ -523,8 +611,29 @@ pub enum PciExpressSlotWidth {
     PciExpressMini76,
 }
 
+impl fmt::Display for PciExpressSlotWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PciExpressSlotWidth::UndefinedSlotWidth => "Undefined",
+            PciExpressSlotWidth::X1 => "x1",
+            PciExpressSlotWidth::X2 => "x2",
+            PciExpressSlotWidth::X4 => "x4",
+            PciExpressSlotWidth::X8 => "x8",
+            PciExpressSlotWidth::X16 => "x16",
+            PciExpressSlotWidth::Sff8639 => "SFF-8639 (U.2)",
+            PciExpressSlotWidth::PciExpressMini52WithKeepouts => {
+                "PCI Express Mini 52-pin (CEM spec. 2.0) with bottom-side keep-outs"
+            }
+            PciExpressSlotWidth::PciExpressMini52WithoutKeepouts => {
+                "PCI Express Mini 52-pin (CEM spec. 2.0) without bottom-side keep-outs"
+            }
+            PciExpressSlotWidth::PciExpressMini76 => "PCI Express Mini 76-pin (CEM spec. 2.0)",
+        })
+    }
+}
+
 /// The slot width of an AGP slot specified in the SystemSlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum AgpSlotWidth {
     /// X1
     X1,
@@ -536,8 +645,19 @@ pub enum AgpSlotWidth {
     X8,
 }
 
+impl fmt::Display for AgpSlotWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AgpSlotWidth::X1 => "1X",
+            AgpSlotWidth::X2 => "2X",
+            AgpSlotWidth::X4 => "4X",
+            AgpSlotWidth::X8 => "8X",
+        })
+    }
+}
+
 /// An MXM SlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MXMSlotType {
     /// MXM Type I
     MxmTypeI,
@@ -555,8 +675,22 @@ pub enum MXMSlotType {
     Mxm3TypeB,
 }
 
+impl fmt::Display for MXMSlotType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MXMSlotType::MxmTypeI => "MXM Type I",
+            MXMSlotType::MxmTypeII => "MXM Type II",
+            MXMSlotType::MxmTypeIIIStandard => "MXM Type III (standard connector)",
+            MXMSlotType::MxmTypeIIIHE => "MXM Type III (HE connector)",
+            MXMSlotType::MxmTypeIV => "MXM Type IV",
+            MXMSlotType::Mxm3TypeA => "MXM 3.0 Type A",
+            MXMSlotType::Mxm3TypeB => "MXM 3.0 Type B",
+        })
+    }
+}
+
 /// An M.2 SlotType
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum M2SlotType {
     /// M.2 Socket 1-DP (Mechanical Key A)
     M2Socket1DP,
@@ -568,6 +702,17 @@ pub enum M2SlotType {
     M2Socket3,
 }
 
+impl fmt::Display for M2SlotType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            M2SlotType::M2Socket1DP => "M.2 Socket 1-DP (Mechanical Key A)",
+            M2SlotType::M2Socket1SD => "M.2 Socket 1-SD (Mechanical Key E)",
+            M2SlotType::M2Socket2 => "M.2 Socket 2 (Mechanical Key B)",
+            M2SlotType::M2Socket3 => "M.2 Socket 3 (Mechanical Key M)",
+        })
+    }
+}
+
 /// # Data Bus Width Data
 pub struct SlotWidthData {
     /// Raw value
@@ -616,7 +761,7 @@ impl From<u8> for SlotWidthData {
 
 impl fmt::Debug for SlotWidthData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SlotWidthData>())
+        fmt.debug_struct(core::any::type_name::<SlotWidthData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -636,7 +781,7 @@ impl Serialize for SlotWidthData {
 }
 
 /// # Slot Width
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SlotWidth {
     /// Other
     Other,
@@ -670,6 +815,28 @@ pub enum SlotWidth {
     None,
 }
 
+impl fmt::Display for SlotWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SlotWidth::Other => "Other",
+            SlotWidth::Unknown => "Unknown",
+            SlotWidth::Bit8 => "8 bit",
+            SlotWidth::Bit16 => "16 bit",
+            SlotWidth::Bit32 => "32 bit",
+            SlotWidth::Bit64 => "64 bit",
+            SlotWidth::Bit128 => "128 bit",
+            SlotWidth::X1 => "1x or x1",
+            SlotWidth::X2 => "2x or x2",
+            SlotWidth::X4 => "4x or x4",
+            SlotWidth::X8 => "8x or x8",
+            SlotWidth::X12 => "12x or x12",
+            SlotWidth::X16 => "16x or x16",
+            SlotWidth::X32 => "32x or x32",
+            SlotWidth::None => "Undefined",
+        })
+    }
+}
+
 /// # Slot Height Data
 pub struct SlotHeightData {
     /// Raw value
@@ -709,7 +876,7 @@ impl From<u8> for SlotHeightData {
 
 impl fmt::Debug for SlotHeightData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SlotHeightData>())
+        fmt.debug_struct(core::any::type_name::<SlotHeightData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -729,7 +896,7 @@ impl Serialize for SlotHeightData {
 }
 
 /// # Slot Height
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SlotHeight {
     /// Not Applicable
     NotApplicable,
@@ -745,6 +912,19 @@ pub enum SlotHeight {
     None,
 }
 
+impl fmt::Display for SlotHeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SlotHeight::NotApplicable => "Not Applicable",
+            SlotHeight::Other => "Other",
+            SlotHeight::Unknown => "Unknown",
+            SlotHeight::FullHeight => "Full Height",
+            SlotHeight::LowProfile => "Low-profile",
+            SlotHeight::None => "Undefined",
+        })
+    }
+}
+
 /// # System Slot Current Usage Data
 pub struct SlotCurrentUsageData {
     /// Raw value
@@ -784,7 +964,7 @@ impl From<u8> for SlotCurrentUsageData {
 
 impl fmt::Debug for SlotCurrentUsageData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SlotCurrentUsageData>())
+        fmt.debug_struct(core::any::type_name::<SlotCurrentUsageData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -804,7 +984,7 @@ impl Serialize for SlotCurrentUsageData {
 }
 
 /// # System Slot Current Usage
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SlotCurrentUsage {
     /// Other
     Other,
@@ -820,6 +1000,19 @@ pub enum SlotCurrentUsage {
     None,
 }
 
+impl fmt::Display for SlotCurrentUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SlotCurrentUsage::Other => "Other",
+            SlotCurrentUsage::Unknown => "Unknown",
+            SlotCurrentUsage::Available => "Available",
+            SlotCurrentUsage::InUse => "In use",
+            SlotCurrentUsage::Unavailable => "Unavailable",
+            SlotCurrentUsage::None => "Undefined",
+        })
+    }
+}
+
 /// # System Slot Current Usage Data
 pub struct SlotLengthData {
     /// Raw value
@@ -861,7 +1054,7 @@ impl From<u8> for SlotLengthData {
 
 impl fmt::Debug for SlotLengthData {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SlotLengthData>())
+        fmt.debug_struct(core::any::type_name::<SlotLengthData>())
             .field("raw", &self.raw)
             .field("value", &self.value)
             .finish()
@@ -881,7 +1074,7 @@ impl Serialize for SlotLengthData {
 }
 
 /// # System Slot Length
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SlotLength {
     /// Other
     Other,
@@ -899,6 +1092,20 @@ pub enum SlotLength {
     None,
 }
 
+impl fmt::Display for SlotLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SlotLength::Other => "Other",
+            SlotLength::Unknown => "Unknown",
+            SlotLength::ShortLength => "Short Length",
+            SlotLength::LongLength => "Long Length",
+            SlotLength::DriveFormFactor25 => "2.5\" drive form factor",
+            SlotLength::DriveFormFactor35 => "3.5\" drive form factor",
+            SlotLength::None => "Undefined",
+        })
+    }
+}
+
 /// # System Slot Characteristics 1
 #[derive(PartialEq, Eq)]
 pub struct SystemSlotCharacteristics1 {
@@ -966,7 +1173,7 @@ impl SystemSlotCharacteristics1 {
 
 impl fmt::Debug for SystemSlotCharacteristics1 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SystemSlotCharacteristics1>())
+        fmt.debug_struct(core::any::type_name::<SystemSlotCharacteristics1>())
             .field("raw", &self.raw)
             .field("unknown", &self.unknown())
             .field("provides5_volts", &self.provides5_volts())
@@ -1076,7 +1283,7 @@ impl SystemSlotCharacteristics2 {
 
 impl fmt::Debug for SystemSlotCharacteristics2 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SystemSlotCharacteristics2>())
+        fmt.debug_struct(core::any::type_name::<SystemSlotCharacteristics2>())
             .field("raw", &self.raw)
             .field(
                 "supports_power_management_event",
@@ -1131,8 +1338,77 @@ impl Serialize for SystemSlotCharacteristics2 {
     }
 }
 
+/// # Slot Characteristics
+///
+/// Named flags of both System Slot Characteristics bytes, see
+/// [SMBiosSystemSlot::slot_characteristics].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SlotCharacteristics {
+    /// Raw value of Slot Characteristics 1
+    pub raw_1: u8,
+    /// Raw value of Slot Characteristics 2, `None` prior to version 2.1
+    pub raw_2: Option<u8>,
+    /// Characteristics unknown
+    pub characteristics_unknown: bool,
+    /// Provides 5.0 volts
+    pub provides_5v: bool,
+    /// Provides 3.3 volts
+    pub provides_3_3v: bool,
+    /// Slot's opening is shared with another slot
+    pub shared_slot: bool,
+    /// PC Card slot supports PC Card-16
+    pub supports_pc_card_16: bool,
+    /// PC Card slot supports CardBus
+    pub supports_card_bus: bool,
+    /// PC Card slot supports Zoom Video
+    pub supports_zoom_video: bool,
+    /// PC Card slot supports Modem Ring Resume
+    pub supports_modem_ring_resume: bool,
+    /// PCI slot supports Power Management Event (PME#) signal
+    pub supports_pme: bool,
+    /// Slot supports hot-plug devices
+    pub supports_hot_plug: bool,
+    /// PCI slot supports SMBus signal
+    pub supports_smbus: bool,
+    /// PCIe slot supports bifurcation
+    pub supports_bifurcation: bool,
+    /// Slot supports async/surprise removal
+    pub supports_surprise_removal: bool,
+    /// Flexbus slot, CXL 1.0 capable
+    pub flexbus_cxl_1_0_capable: bool,
+    /// Flexbus slot, CXL 2.0 capable
+    pub flexbus_cxl_2_0_capable: bool,
+}
+
+impl SlotCharacteristics {
+    /// Decodes the raw Slot Characteristics 1 and 2 bytes
+    pub fn from_raw(raw_1: u8, raw_2: Option<u8>) -> Self {
+        let characteristics_1 = SystemSlotCharacteristics1::from(raw_1);
+        let characteristics_2 = SystemSlotCharacteristics2::from(raw_2.unwrap_or(0));
+        SlotCharacteristics {
+            raw_1,
+            raw_2,
+            characteristics_unknown: characteristics_1.unknown(),
+            provides_5v: characteristics_1.provides5_volts(),
+            provides_3_3v: characteristics_1.provides33_volts(),
+            shared_slot: characteristics_1.shared(),
+            supports_pc_card_16: characteristics_1.supports_pc_card16(),
+            supports_card_bus: characteristics_1.supports_card_bus(),
+            supports_zoom_video: characteristics_1.supports_zoom_video(),
+            supports_modem_ring_resume: characteristics_1.supports_modem_ring_resume(),
+            supports_pme: characteristics_2.supports_power_management_event(),
+            supports_hot_plug: characteristics_2.supports_hot_plug_devices(),
+            supports_smbus: characteristics_2.supports_smbus_signal(),
+            supports_bifurcation: characteristics_2.supports_bifurcation(),
+            supports_surprise_removal: characteristics_2.supports_suprise_removal(),
+            flexbus_cxl_1_0_capable: characteristics_2.flexbus_slot_cxl10_capable(),
+            flexbus_cxl_2_0_capable: characteristics_2.flexbus_slot_cxl20_capable(),
+        }
+    }
+}
+
 /// # Segment Group Number
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SegmentGroupNumber {
     /// Single-Segment Topology (no group number)
     SingleSegment,
@@ -1143,18 +1419,28 @@ pub enum SegmentGroupNumber {
     NotApplicable,
 }
 
+impl fmt::Display for SegmentGroupNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SegmentGroupNumber::SingleSegment => f.write_str("Single-Segment Topology"),
+            SegmentGroupNumber::Number(number) => write!(f, "{:#06X}", number),
+            SegmentGroupNumber::NotApplicable => f.write_str("Not applicable"),
+        }
+    }
+}
+
 impl From<u16> for SegmentGroupNumber {
     fn from(raw: u16) -> Self {
         match raw {
             0x00 => SegmentGroupNumber::SingleSegment,
-            0xFF => SegmentGroupNumber::NotApplicable,
+            0xFFFF => SegmentGroupNumber::NotApplicable,
             _ => SegmentGroupNumber::Number(raw),
         }
     }
 }
 
 /// # Bus Number
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BusNumber {
     /// Bus Number
     Number(u8),
@@ -1163,6 +1449,15 @@ pub enum BusNumber {
     NotApplicable,
 }
 
+impl fmt::Display for BusNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            BusNumber::Number(number) => write!(f, "{:#04X}", number),
+            BusNumber::NotApplicable => f.write_str("Not applicable"),
+        }
+    }
+}
+
 impl From<u8> for BusNumber {
     fn from(raw: u8) -> Self {
         match raw {
@@ -1173,7 +1468,7 @@ impl From<u8> for BusNumber {
 }
 
 /// # Device/Function Number
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum DeviceFunctionNumber {
     /// Device/Function Number
     Number {
@@ -1187,6 +1482,17 @@ pub enum DeviceFunctionNumber {
     NotApplicable,
 }
 
+impl fmt::Display for DeviceFunctionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DeviceFunctionNumber::Number { device, function } => {
+                write!(f, "{:02X}.{:X}", device, function)
+            }
+            DeviceFunctionNumber::NotApplicable => f.write_str("Not applicable"),
+        }
+    }
+}
+
 impl From<u8> for DeviceFunctionNumber {
     fn from(raw: u8) -> Self {
         match raw {
@@ -1253,7 +1559,7 @@ impl<'a> SlotPeerGroup<'a> {
 
 impl fmt::Debug for SlotPeerGroup<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SlotPeerGroup<'_>>())
+        fmt.debug_struct(core::any::type_name::<SlotPeerGroup<'_>>())
             .field("segment_group_number", &self.segment_group_number())
             .field("bus_number", &self.bus_number())
             .field("device_function_number", &self.device_function_number())
@@ -1369,6 +1675,7 @@ impl<'a> Serialize for SlotPeerGroupIterator<'a> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn unit_test() {
         // System Slot structure lengths and their versions:
@@ -1438,4 +1745,103 @@ mod tests {
 
         println!("{:?}", test_struct);
     }
+
+    #[test]
+    fn test_multiple_peer_groups() {
+        // PCIe Gen 3 x16 slot (3.2 layout) with 2 peer groups
+        let struct_type9 = vec![
+            0x09, 0x1D, 0x1D, 0x00, 0x01, 0xB6, 0x0D, 0x04, 0x04, 0x01, 0x00, 0x04, 0x0B, 0x00,
+            0x00, 0x17, 0x00, 0x0D, 0x02, 0x00, 0x00, 0x18, 0x00, 0x08, 0x00, 0x00, 0x19, 0x08,
+            0x08, b'S', b'L', b'O', b'T', b'1', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type9);
+        let test_struct = SMBiosSystemSlot::new(&parts);
+
+        assert_eq!(
+            test_struct.slot_designation().to_string(),
+            "SLOT1".to_string()
+        );
+        let characteristics_1 = test_struct.slot_characteristics_1().unwrap();
+        assert!(characteristics_1.provides33_volts());
+        assert!(!characteristics_1.provides5_volts());
+        let characteristics_2 = test_struct.slot_characteristics_2().unwrap();
+        assert!(characteristics_2.supports_power_management_event());
+        assert!(characteristics_2.supports_hot_plug_devices());
+        assert!(!characteristics_2.supports_smbus_signal());
+        assert!(characteristics_2.supports_bifurcation());
+
+        assert_eq!(test_struct.peer_group_count(), Some(2));
+        let peer_groups: Vec<(Option<u8>, Option<u8>, Option<u8>)> = test_struct
+            .peer_group_iterator()
+            .map(|peer_group| {
+                (
+                    peer_group.bus_number(),
+                    peer_group.device_function_number(),
+                    peer_group.data_bus_width(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            peer_groups,
+            vec![
+                (Some(0x18), Some(0x00), Some(0x08)),
+                (Some(0x19), Some(0x08), Some(0x08))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_slot_characteristics() {
+        // The J6B2 slot from unit_test() with PME, hot-plug and SMBus set
+        let struct_type9 = vec![
+            0x09, 0x11, 0x1C, 0x00, 0x01, 0xA5, 0x0D, 0x04, 0x04, 0x05, 0x07, 0x0C, 0x07, 0x00,
+            0x00, 0x00, 0x08, 0x4A, 0x36, 0x42, 0x32, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type9);
+        let test_struct = SMBiosSystemSlot::new(&parts);
+        let characteristics = test_struct.slot_characteristics().unwrap();
+
+        assert_eq!(characteristics.raw_1, 0x0C);
+        assert_eq!(characteristics.raw_2, Some(0x07));
+        assert!(!characteristics.characteristics_unknown);
+        assert!(!characteristics.provides_5v);
+        assert!(characteristics.provides_3_3v);
+        assert!(characteristics.shared_slot);
+        assert!(!characteristics.supports_card_bus);
+        assert!(characteristics.supports_pme);
+        assert!(characteristics.supports_hot_plug);
+        assert!(characteristics.supports_smbus);
+        assert!(!characteristics.supports_bifurcation);
+        assert!(!characteristics.flexbus_cxl_2_0_capable);
+
+        // 2.0 structure without Slot Characteristics 2
+        let characteristics = SlotCharacteristics::from_raw(0x01, None);
+        assert!(characteristics.characteristics_unknown);
+        assert_eq!(characteristics.raw_2, None);
+        assert!(!characteristics.supports_pme);
+    }
+
+    #[test]
+    fn test_display() {
+        let display = |raw: u8| SystemSlotTypeData::from(raw).to_string();
+        assert_eq!(display(0x06), "PCI");
+        assert_eq!(display(0x0F), "AGP");
+        assert_eq!(display(0x11), "AGP 4X");
+        assert_eq!(display(0x17), "M.2 Socket 3 (Mechanical Key M)");
+        assert_eq!(display(0x24), "PCI Express Gen 4 SFF-8639 (U.2)");
+        assert_eq!(display(0xA5), "PCI Express");
+        assert_eq!(display(0xA6), "PCI Express x1");
+        assert_eq!(display(0xB1), "PCI Express Gen 3");
+        assert_eq!(display(0xBD), "PCI Express Gen 4 x16");
+        // A value unknown to this standard displays the raw value
+        assert_eq!(display(0xFE), "254");
+
+        // Copy
+        let slot_type = SystemSlotTypeData::from(0xBD).value;
+        let copied = slot_type;
+        assert_eq!(slot_type, copied);
+        assert_eq!(SlotCurrentUsage::InUse.to_string(), "In use");
+    }
 }
@@ -1,7 +1,7 @@
 use crate::core::{strings::*, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # Temperature Probe (Type 28)
 ///
@@ -15,6 +15,7 @@ pub struct SMBiosTemperatureProbe<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosTemperatureProbe<'a> {
     const STRUCT_TYPE: u8 = 28u8;
+    const MIN_LENGTH: u8 = 0x14;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -119,7 +120,7 @@ impl<'a> SMBiosTemperatureProbe<'a> {
 
 impl fmt::Debug for SMBiosTemperatureProbe<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosTemperatureProbe<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosTemperatureProbe<'_>>())
             .field("header", &self.parts.header)
             .field("description", &self.description())
             .field("location_and_status", &self.location_and_status())
@@ -181,7 +182,7 @@ impl TemperatureProbeLocationAndStatus {
 
 impl fmt::Debug for TemperatureProbeLocationAndStatus {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<TemperatureProbeLocationAndStatus>())
+        fmt.debug_struct(core::any::type_name::<TemperatureProbeLocationAndStatus>())
             .field("raw", &self.raw)
             .field("location", &self.location())
             .field("status", &self.status())
@@ -203,7 +204,7 @@ impl Serialize for TemperatureProbeLocationAndStatus {
 }
 
 /// # Temperature Probe Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TemperatureProbeStatus {
     /// Other
     Other,
@@ -221,6 +222,37 @@ pub enum TemperatureProbeStatus {
     None,
 }
 
+impl fmt::Display for TemperatureProbeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TemperatureProbeStatus::Other => "Other",
+            TemperatureProbeStatus::Unknown => "Unknown",
+            TemperatureProbeStatus::OK => "OK",
+            TemperatureProbeStatus::NonCritical => "Non-critical",
+            TemperatureProbeStatus::Critical => "Critical",
+            TemperatureProbeStatus::NonRecoverable => "Non-recoverable",
+            TemperatureProbeStatus::None => "Undefined",
+        })
+    }
+}
+
+impl TemperatureProbeStatus {
+    /// The SMBIOS enumeration value of the status, the inverse of decoding
+    ///
+    /// Returns None for [TemperatureProbeStatus::None] which has no enumeration value.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            TemperatureProbeStatus::Other => Some(1),
+            TemperatureProbeStatus::Unknown => Some(2),
+            TemperatureProbeStatus::OK => Some(3),
+            TemperatureProbeStatus::NonCritical => Some(4),
+            TemperatureProbeStatus::Critical => Some(5),
+            TemperatureProbeStatus::NonRecoverable => Some(6),
+            TemperatureProbeStatus::None => None,
+        }
+    }
+}
+
 impl From<u8> for TemperatureProbeStatus {
     fn from(raw: u8) -> Self {
         match raw & 0b1110_0000 {
@@ -238,7 +270,7 @@ impl From<u8> for TemperatureProbeStatus {
 }
 
 /// # Temperature Probe Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TemperatureProbeLocation {
     /// Other
     Other,
@@ -274,6 +306,55 @@ pub enum TemperatureProbeLocation {
     None,
 }
 
+impl fmt::Display for TemperatureProbeLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TemperatureProbeLocation::Other => "Other",
+            TemperatureProbeLocation::Unknown => "Unknown",
+            TemperatureProbeLocation::Processor => "Processor",
+            TemperatureProbeLocation::Disk => "Disk",
+            TemperatureProbeLocation::PeripheralBay => "Peripheral Bay",
+            TemperatureProbeLocation::SystemManagementModule => "System Management Module",
+            TemperatureProbeLocation::Motherboard => "Motherboard",
+            TemperatureProbeLocation::MemoryModule => "Memory Module",
+            TemperatureProbeLocation::ProcessorModule => "Processor Module",
+            TemperatureProbeLocation::PowerUnit => "Power Unit",
+            TemperatureProbeLocation::AddInCard => "Add-in Card",
+            TemperatureProbeLocation::FrontPanelBoard => "Front Panel Board",
+            TemperatureProbeLocation::BackPanelBoard => "Back Panel Board",
+            TemperatureProbeLocation::PowerSystemBoard => "Power System Board",
+            TemperatureProbeLocation::DriveBackPlane => "Drive Back Plane",
+            TemperatureProbeLocation::None => "Undefined",
+        })
+    }
+}
+
+impl TemperatureProbeLocation {
+    /// The SMBIOS enumeration value of the location, the inverse of decoding
+    ///
+    /// Returns None for [TemperatureProbeLocation::None] which has no enumeration value.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            TemperatureProbeLocation::Other => Some(1),
+            TemperatureProbeLocation::Unknown => Some(2),
+            TemperatureProbeLocation::Processor => Some(3),
+            TemperatureProbeLocation::Disk => Some(4),
+            TemperatureProbeLocation::PeripheralBay => Some(5),
+            TemperatureProbeLocation::SystemManagementModule => Some(6),
+            TemperatureProbeLocation::Motherboard => Some(7),
+            TemperatureProbeLocation::MemoryModule => Some(8),
+            TemperatureProbeLocation::ProcessorModule => Some(9),
+            TemperatureProbeLocation::PowerUnit => Some(10),
+            TemperatureProbeLocation::AddInCard => Some(11),
+            TemperatureProbeLocation::FrontPanelBoard => Some(12),
+            TemperatureProbeLocation::BackPanelBoard => Some(13),
+            TemperatureProbeLocation::PowerSystemBoard => Some(14),
+            TemperatureProbeLocation::DriveBackPlane => Some(15),
+            TemperatureProbeLocation::None => None,
+        }
+    }
+}
+
 impl From<u8> for TemperatureProbeLocation {
     fn from(raw: u8) -> Self {
         match raw & 0b0001_1111 {
@@ -298,7 +379,7 @@ impl From<u8> for TemperatureProbeLocation {
 }
 
 /// # Probe Temperature
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ProbeTemperature {
     /// Temperature in 1/10 degrees C
     OneTenthDegreesC(u16),
@@ -306,6 +387,17 @@ pub enum ProbeTemperature {
     Unknown,
 }
 
+impl fmt::Display for ProbeTemperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ProbeTemperature::OneTenthDegreesC(temperature) => {
+                write!(f, "{:.1} °C", temperature as i16 as f32 / 10.0)
+            }
+            ProbeTemperature::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for ProbeTemperature {
     fn from(raw: u16) -> Self {
         match raw {
@@ -316,7 +408,7 @@ impl From<u16> for ProbeTemperature {
 }
 
 /// # Temperature Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum TemperatureProbeResolution {
     /// Resolution for the probe's reading in 1/1000 degrees C
     OneOneThousandthDegreesC(u16),
@@ -324,6 +416,17 @@ pub enum TemperatureProbeResolution {
     Unknown,
 }
 
+impl fmt::Display for TemperatureProbeResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TemperatureProbeResolution::OneOneThousandthDegreesC(resolution) => {
+                write!(f, "{:.3} °C", resolution as f32 / 1000.0)
+            }
+            TemperatureProbeResolution::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for TemperatureProbeResolution {
     fn from(raw: u16) -> Self {
         match raw {
@@ -334,19 +437,30 @@ impl From<u16> for TemperatureProbeResolution {
 }
 
 /// # Temperature Probe Accuracy
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum TemperatureProbeAccuracy {
-    /// Accuracy for the probe's reading in 1/100 degrees C
-    OneOneHundredthDegreesC(u16),
+    /// Accuracy for the probe's reading in 1/100th of a percent
+    OneOneHundredthPercent(u16),
     /// Accuracy is unknown
     Unknown,
 }
 
+impl fmt::Display for TemperatureProbeAccuracy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TemperatureProbeAccuracy::OneOneHundredthPercent(accuracy) => {
+                write!(f, "{:.2}%", accuracy as f32 / 100.0)
+            }
+            TemperatureProbeAccuracy::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for TemperatureProbeAccuracy {
     fn from(raw: u16) -> Self {
         match raw {
             0x8000 => TemperatureProbeAccuracy::Unknown,
-            _ => TemperatureProbeAccuracy::OneOneHundredthDegreesC(raw),
+            _ => TemperatureProbeAccuracy::OneOneHundredthPercent(raw),
         }
     }
 }
@@ -354,6 +468,7 @@ impl From<u16> for TemperatureProbeAccuracy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -388,7 +503,7 @@ mod tests {
             ProbeTemperature::Unknown => (),
         }
         match test_struct.accuracy().unwrap() {
-            TemperatureProbeAccuracy::OneOneHundredthDegreesC(_) => panic!("expected unknown"),
+            TemperatureProbeAccuracy::OneOneHundredthPercent(_) => panic!("expected unknown"),
             TemperatureProbeAccuracy::Unknown => (),
         }
         assert_eq!(test_struct.oem_defined(), Some(0));
@@ -397,4 +512,74 @@ mod tests {
             ProbeTemperature::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_known_readings() {
+        // Location unknown, status OK, readings 0 to 100.0 C
+        let struct_type28 = vec![
+            0x1C, 0x16, 0x2B, 0x00, 0x01, 0x62, 0xE8, 0x03, 0x00, 0x00, 0x7D, 0x00, 0x0A, 0x00,
+            0x32, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0x01, b'C', b'P', b'U', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type28);
+        let test_struct = SMBiosTemperatureProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(
+            location_and_status.location(),
+            TemperatureProbeLocation::Unknown
+        );
+        assert_eq!(location_and_status.status(), TemperatureProbeStatus::OK);
+        match test_struct.maximum_value().unwrap() {
+            ProbeTemperature::OneTenthDegreesC(tenths) => assert_eq!(tenths, 1000),
+            ProbeTemperature::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.minimum_value().unwrap() {
+            ProbeTemperature::OneTenthDegreesC(tenths) => assert_eq!(tenths, 0),
+            ProbeTemperature::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.resolution().unwrap() {
+            TemperatureProbeResolution::OneOneThousandthDegreesC(thousandths) => {
+                assert_eq!(thousandths, 125)
+            }
+            TemperatureProbeResolution::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.tolerance().unwrap() {
+            ProbeTemperature::OneTenthDegreesC(tenths) => assert_eq!(tenths, 10),
+            ProbeTemperature::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.accuracy().unwrap() {
+            TemperatureProbeAccuracy::OneOneHundredthPercent(accuracy) => {
+                assert_eq!(accuracy, 50)
+            }
+            TemperatureProbeAccuracy::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.nominal_value().unwrap() {
+            ProbeTemperature::OneTenthDegreesC(tenths) => assert_eq!(tenths, 281),
+            ProbeTemperature::Unknown => panic!("expected a reading"),
+        }
+    }
+
+    #[test]
+    fn test_code_round_trip() {
+        // Every status and location decoded from a raw byte encodes back to itself
+        for raw in 0..=u8::MAX {
+            let status = TemperatureProbeStatus::from(raw);
+            if status != TemperatureProbeStatus::None {
+                assert_eq!(
+                    TemperatureProbeStatus::from(status.code().unwrap() << 5),
+                    status
+                );
+            }
+            let location = TemperatureProbeLocation::from(raw);
+            if location != TemperatureProbeLocation::None {
+                assert_eq!(
+                    TemperatureProbeLocation::from(location.code().unwrap()),
+                    location
+                );
+            }
+        }
+        assert_eq!(TemperatureProbeStatus::None.code(), None);
+        assert_eq!(TemperatureProbeLocation::None.code(), None);
+    }
 }
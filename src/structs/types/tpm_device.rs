@@ -1,7 +1,8 @@
 use crate::core::{strings::*, UndefinedStruct};
+use crate::prelude::*;
 use crate::SMBiosStruct;
+use core::{array::TryFromSliceError, convert::TryFrom, fmt, ops::Deref};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::{array::TryFromSliceError, convert::TryFrom, fmt, ops::Deref};
 
 /// # TPM Device (Type 43)
 pub struct SMBiosTpmDevice<'a> {
@@ -10,6 +11,7 @@ pub struct SMBiosTpmDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosTpmDevice<'a> {
     const STRUCT_TYPE: u8 = 43u8;
+    const MIN_LENGTH: u8 = 0x1F;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -35,6 +37,29 @@ impl<'a> SMBiosTpmDevice<'a> {
             .and_then(|array| Some(VendorId::try_from(array).expect("Vendor Id is 4 bytes")))
     }
 
+    /// Vendor Id as the 4 character TCG vendor string
+    ///
+    /// Trailing NULs are trimmed and non-printable characters are
+    /// replaced with '.'. Some firmware stores the Id as a little-endian
+    /// DWORD (e.g. "\0XFI" for "IFX"), which is detected by a leading NUL
+    /// and reversed.
+    pub fn vendor_id_ascii(&self) -> Option<String> {
+        self.vendor_id().map(|vendor_id| {
+            let mut bytes = *vendor_id.array;
+            if bytes[0] == 0 {
+                bytes.reverse();
+            }
+            bytes
+                .iter()
+                .take_while(|&&byte| byte != 0)
+                .map(|&byte| match byte {
+                    0x20..=0x7E => byte as char,
+                    _ => '.',
+                })
+                .collect()
+        })
+    }
+
     /// Major spec version
     ///
     /// Major TPM version supported by the TPM device. For
@@ -107,7 +132,7 @@ impl<'a> SMBiosTpmDevice<'a> {
 
 impl fmt::Debug for SMBiosTpmDevice<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosTpmDevice<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosTpmDevice<'_>>())
             .field("header", &self.parts.header)
             .field("vendor_id", &self.vendor_id())
             .field("major_spec_version", &self.major_spec_version())
@@ -163,7 +188,7 @@ impl<'a> TryFrom<&'a [u8]> for VendorId<'a> {
 
 impl<'a> fmt::Debug for VendorId<'a> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<VendorId<'_>>())
+        fmt.debug_struct(core::any::type_name::<VendorId<'_>>())
             .field("array", &self.array)
             .field("string", &String::from_utf8_lossy(self.array))
             .finish()
@@ -242,7 +267,7 @@ impl TpmDeviceCharacteristics {
 
 impl fmt::Debug for TpmDeviceCharacteristics {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<TpmDeviceCharacteristics>())
+        fmt.debug_struct(core::any::type_name::<TpmDeviceCharacteristics>())
             .field("raw", &self.raw)
             .field("reserved_0", &self.reserved_0())
             .field("reserved_1", &self.reserved_1())
@@ -293,6 +318,7 @@ impl Serialize for TpmDeviceCharacteristics {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
     #[test]
     fn unit_test() {
         let struct_type43 = vec![
@@ -324,4 +350,43 @@ mod tests {
         );
         assert_eq!(test_struct.oem_defined(), Some(0));
     }
+
+    #[test]
+    fn test_vendor_id_ascii() {
+        // TPM 2.0, vendor "NTC" (Nuvoton), family configurable via firmware and software
+        let struct_type43 = vec![
+            0x2B, 0x1F, 0x3D, 0x00, b'N', b'T', b'C', 0x00, 0x02, 0x00, 0x07, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, b'T', b'P', b'M', b' ', b'2', b'.', b'0', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type43);
+        let test_struct = SMBiosTpmDevice::new(&parts);
+
+        assert_eq!(test_struct.vendor_id_ascii(), Some("NTC".to_string()));
+        assert_eq!(test_struct.major_spec_version(), Some(2));
+        assert_eq!(test_struct.minor_spec_version(), Some(0));
+        assert_eq!(test_struct.firmware_version_1(), Some(0x0002_0007));
+        assert_eq!(test_struct.firmware_version_2(), Some(0));
+        assert_eq!(test_struct.description().to_string(), "TPM 2.0".to_string());
+
+        let characteristics = test_struct.characteristics().unwrap();
+        assert!(!characteristics.not_supported());
+        assert!(characteristics.family_configurable_via_firmware());
+        assert!(characteristics.family_configurable_via_software());
+        assert!(!characteristics.family_configurable_via_oem());
+
+        // Little-endian stored vendor Id
+        let struct_type43 = vec![
+            0x2B, 0x1F, 0x3C, 0x00, 0x00, 0x58, 0x46, 0x49, 0x02, 0x00, 0x3E, 0x00, 0x05, 0x00,
+            0x00, 0x36, 0x0C, 0x00, 0x02, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x54, 0x50, 0x4D, 0x20, 0x32, 0x2E, 0x30, 0x00, 0x49, 0x4E, 0x46,
+            0x49, 0x4E, 0x45, 0x4F, 0x4E, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type43);
+        let test_struct = SMBiosTpmDevice::new(&parts);
+
+        assert_eq!(test_struct.vendor_id_ascii(), Some("IFX".to_string()));
+    }
 }
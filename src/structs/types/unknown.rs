@@ -1,6 +1,6 @@
 use crate::{Header, UndefinedStruct};
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// # OEM or Unknown Structure
 ///
@@ -33,7 +33,7 @@ impl<'a> SMBiosUnknown<'a> {
 impl fmt::Debug for SMBiosUnknown<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         let fields = &self.parts.fields[Header::SIZE..];
-        fmt.debug_struct(std::any::type_name::<SMBiosUnknown<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosUnknown<'_>>())
             .field("header", &self.parts.header)
             .field("fields", &fields)
             .field("strings", &self.parts.strings)
@@ -56,7 +56,7 @@ impl Serialize for SMBiosUnknown<'_> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
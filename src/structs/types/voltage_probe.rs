@@ -1,7 +1,7 @@
 use crate::core::{strings::*, UndefinedStruct};
 use crate::SMBiosStruct;
+use core::fmt;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
-use std::fmt;
 
 /// #  Voltage Probe (Type 26)
 ///
@@ -15,6 +15,7 @@ pub struct SMBiosVoltageProbe<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosVoltageProbe<'a> {
     const STRUCT_TYPE: u8 = 26u8;
+    const MIN_LENGTH: u8 = 0x14;
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -112,7 +113,7 @@ impl<'a> SMBiosVoltageProbe<'a> {
 
 impl fmt::Debug for SMBiosVoltageProbe<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<SMBiosVoltageProbe<'_>>())
+        fmt.debug_struct(core::any::type_name::<SMBiosVoltageProbe<'_>>())
             .field("header", &self.parts.header)
             .field("description", &self.description())
             .field("location_and_status", &self.location_and_status())
@@ -174,7 +175,7 @@ impl VoltageProbeLocationAndStatus {
 
 impl fmt::Debug for VoltageProbeLocationAndStatus {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(std::any::type_name::<VoltageProbeLocationAndStatus>())
+        fmt.debug_struct(core::any::type_name::<VoltageProbeLocationAndStatus>())
             .field("raw", &self.raw)
             .field("location", &self.location())
             .field("status", &self.status())
@@ -196,7 +197,7 @@ impl Serialize for VoltageProbeLocationAndStatus {
 }
 
 /// # Voltage Probe Status
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VoltageProbeStatus {
     /// Other
     Other,
@@ -214,6 +215,37 @@ pub enum VoltageProbeStatus {
     None,
 }
 
+impl fmt::Display for VoltageProbeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VoltageProbeStatus::Other => "Other",
+            VoltageProbeStatus::Unknown => "Unknown",
+            VoltageProbeStatus::OK => "OK",
+            VoltageProbeStatus::NonCritical => "Non-critical",
+            VoltageProbeStatus::Critical => "Critical",
+            VoltageProbeStatus::NonRecoverable => "Non-recoverable",
+            VoltageProbeStatus::None => "Undefined",
+        })
+    }
+}
+
+impl VoltageProbeStatus {
+    /// The SMBIOS enumeration value of the status, the inverse of decoding
+    ///
+    /// Returns None for [VoltageProbeStatus::None] which has no enumeration value.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            VoltageProbeStatus::Other => Some(1),
+            VoltageProbeStatus::Unknown => Some(2),
+            VoltageProbeStatus::OK => Some(3),
+            VoltageProbeStatus::NonCritical => Some(4),
+            VoltageProbeStatus::Critical => Some(5),
+            VoltageProbeStatus::NonRecoverable => Some(6),
+            VoltageProbeStatus::None => None,
+        }
+    }
+}
+
 impl From<u8> for VoltageProbeStatus {
     fn from(raw: u8) -> Self {
         match raw & 0b1110_0000 {
@@ -231,7 +263,7 @@ impl From<u8> for VoltageProbeStatus {
 }
 
 /// # Voltage Probe Location
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum VoltageProbeLocation {
     /// Other
     Other,
@@ -259,6 +291,47 @@ pub enum VoltageProbeLocation {
     None,
 }
 
+impl fmt::Display for VoltageProbeLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            VoltageProbeLocation::Other => "Other",
+            VoltageProbeLocation::Unknown => "Unknown",
+            VoltageProbeLocation::Processor => "Processor",
+            VoltageProbeLocation::Disk => "Disk",
+            VoltageProbeLocation::PeripheralBay => "Peripheral Bay",
+            VoltageProbeLocation::SystemManagementModule => "System Management Module",
+            VoltageProbeLocation::Motherboard => "Motherboard",
+            VoltageProbeLocation::MemoryModule => "Memory Module",
+            VoltageProbeLocation::ProcessorModule => "Processor Module",
+            VoltageProbeLocation::PowerUnit => "Power Unit",
+            VoltageProbeLocation::AddInCard => "Add-in Card",
+            VoltageProbeLocation::None => "Undefined",
+        })
+    }
+}
+
+impl VoltageProbeLocation {
+    /// The SMBIOS enumeration value of the location, the inverse of decoding
+    ///
+    /// Returns None for [VoltageProbeLocation::None] which has no enumeration value.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            VoltageProbeLocation::Other => Some(1),
+            VoltageProbeLocation::Unknown => Some(2),
+            VoltageProbeLocation::Processor => Some(3),
+            VoltageProbeLocation::Disk => Some(4),
+            VoltageProbeLocation::PeripheralBay => Some(5),
+            VoltageProbeLocation::SystemManagementModule => Some(6),
+            VoltageProbeLocation::Motherboard => Some(7),
+            VoltageProbeLocation::MemoryModule => Some(8),
+            VoltageProbeLocation::ProcessorModule => Some(9),
+            VoltageProbeLocation::PowerUnit => Some(10),
+            VoltageProbeLocation::AddInCard => Some(11),
+            VoltageProbeLocation::None => None,
+        }
+    }
+}
+
 impl From<u8> for VoltageProbeLocation {
     fn from(raw: u8) -> Self {
         match raw & 0b0001_1111 {
@@ -279,7 +352,7 @@ impl From<u8> for VoltageProbeLocation {
 }
 
 /// # Probe Voltage
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum ProbeVoltage {
     /// Voltage in millivolts
     Millivolts(u16),
@@ -287,6 +360,17 @@ pub enum ProbeVoltage {
     Unknown,
 }
 
+impl fmt::Display for ProbeVoltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ProbeVoltage::Millivolts(millivolts) => {
+                write!(f, "{:.3} V", millivolts as i16 as f32 / 1000.0)
+            }
+            ProbeVoltage::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for ProbeVoltage {
     fn from(raw: u16) -> Self {
         match raw {
@@ -297,7 +381,7 @@ impl From<u16> for ProbeVoltage {
 }
 
 /// # Voltage Probe Resolution
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum VoltageProbeResolution {
     /// Resolution for the probe's reading in tenths of millivolts
     TenthsOfMillivolts(u16),
@@ -305,6 +389,17 @@ pub enum VoltageProbeResolution {
     Unknown,
 }
 
+impl fmt::Display for VoltageProbeResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            VoltageProbeResolution::TenthsOfMillivolts(resolution) => {
+                write!(f, "{:.1} mV", resolution as f32 / 10.0)
+            }
+            VoltageProbeResolution::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for VoltageProbeResolution {
     fn from(raw: u16) -> Self {
         match raw {
@@ -315,7 +410,7 @@ impl From<u16> for VoltageProbeResolution {
 }
 
 /// # Voltage Probe Accuracy
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, Clone, Copy)]
 pub enum VoltageProbeAccuracy {
     /// Accuracy for the probe's reading in 1/100th of a percent
     OneOneHundredthPercent(u16),
@@ -323,6 +418,17 @@ pub enum VoltageProbeAccuracy {
     Unknown,
 }
 
+impl fmt::Display for VoltageProbeAccuracy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            VoltageProbeAccuracy::OneOneHundredthPercent(accuracy) => {
+                write!(f, "{:.2}%", accuracy as f32 / 100.0)
+            }
+            VoltageProbeAccuracy::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
 impl From<u16> for VoltageProbeAccuracy {
     fn from(raw: u16) -> Self {
         match raw {
@@ -335,6 +441,7 @@ impl From<u16> for VoltageProbeAccuracy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn unit_test() {
@@ -377,4 +484,70 @@ mod tests {
             ProbeVoltage::Unknown => (),
         }
     }
+
+    #[test]
+    fn test_known_readings() {
+        // Processor probe, status critical, readings 900 mV to 1500 mV
+        let struct_type26 = vec![
+            26, 0x16, 0x2B, 0x00, 0x01, 0xA3, 0xDC, 0x05, 0x84, 0x03, 0x0A, 0x00, 0x19, 0x00, 0xC8,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0xB0, 0x04, b'V', b'C', b'O', b'R', b'E', 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type26);
+        let test_struct = SMBiosVoltageProbe::new(&parts);
+
+        let location_and_status = test_struct.location_and_status().unwrap();
+        assert_eq!(
+            location_and_status.location(),
+            VoltageProbeLocation::Processor
+        );
+        assert_eq!(location_and_status.status(), VoltageProbeStatus::Critical);
+        match test_struct.maximum_value().unwrap() {
+            ProbeVoltage::Millivolts(millivolts) => assert_eq!(millivolts, 1500),
+            ProbeVoltage::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.minimum_value().unwrap() {
+            ProbeVoltage::Millivolts(millivolts) => assert_eq!(millivolts, 900),
+            ProbeVoltage::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.resolution().unwrap() {
+            VoltageProbeResolution::TenthsOfMillivolts(tenths) => assert_eq!(tenths, 10),
+            VoltageProbeResolution::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.tolerance().unwrap() {
+            ProbeVoltage::Millivolts(millivolts) => assert_eq!(millivolts, 25),
+            ProbeVoltage::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.accuracy().unwrap() {
+            VoltageProbeAccuracy::OneOneHundredthPercent(accuracy) => assert_eq!(accuracy, 200),
+            VoltageProbeAccuracy::Unknown => panic!("expected a reading"),
+        }
+        match test_struct.nominal_value().unwrap() {
+            ProbeVoltage::Millivolts(millivolts) => assert_eq!(millivolts, 1200),
+            ProbeVoltage::Unknown => panic!("expected a reading"),
+        }
+    }
+
+    #[test]
+    fn test_code_round_trip() {
+        // Every status and location decoded from a raw byte encodes back to itself
+        for raw in 0..=u8::MAX {
+            let status = VoltageProbeStatus::from(raw);
+            if status != VoltageProbeStatus::None {
+                assert_eq!(
+                    VoltageProbeStatus::from(status.code().unwrap() << 5),
+                    status
+                );
+            }
+            let location = VoltageProbeLocation::from(raw);
+            if location != VoltageProbeLocation::None {
+                assert_eq!(
+                    VoltageProbeLocation::from(location.code().unwrap()),
+                    location
+                );
+            }
+        }
+        assert_eq!(VoltageProbeStatus::None.code(), None);
+        assert_eq!(VoltageProbeLocation::None.code(), None);
+    }
 }
@@ -9,6 +9,10 @@ pub const SYS_ENTRY_FILE: &'static str = "/sys/firmware/dmi/tables/smbios_entry_
 /// Full path to the DMI file on Linux (contains BIOS table data)
 pub const SYS_TABLE_FILE: &'static str = "/sys/firmware/dmi/tables/DMI";
 
+#[cfg(target_os = "linux")]
+/// Full path to the per-structure DMI entries folder on Linux (contains one folder per structure)
+pub const SYS_ENTRIES_FOLDER: &str = "/sys/firmware/dmi/entries";
+
 /// Full path to the memory device (contains BIOS entry point and table data on *nix platforms)
 pub const DEV_MEM_FILE: &'static str = "/dev/mem";
 
@@ -44,38 +48,95 @@ pub const DEV_MEM_FILE: &'static str = "/dev/mem";
 // These are useful for cross checking against the results this library produces when reading
 // /sys/firmware/dmi/tables/DMI
 
-#[cfg(any(target_os = "linux"))]
-/// Loads [SMBiosData] from the device via /sys/firmware/dmi/tables (on Linux)
-pub fn table_load_from_device() -> Result<SMBiosData, Error> {
-    let version: SMBiosVersion;
+#[cfg(target_os = "linux")]
+/// Reads the SMBIOS version from /sys/firmware/dmi/tables/smbios_entry_point (on Linux)
+fn version_from_entry_file() -> Result<SMBiosVersion, Error> {
     let entry_path = std::path::Path::new(SYS_ENTRY_FILE);
 
     match SMBiosEntryPoint64::try_load_from_file(entry_path) {
-        Ok(entry_point) => {
-            version = SMBiosVersion {
-                major: entry_point.major_version(),
-                minor: entry_point.minor_version(),
-                revision: entry_point.docrev(),
-            }
-        }
+        Ok(entry_point) => Ok(SMBiosVersion {
+            major: entry_point.major_version(),
+            minor: entry_point.minor_version(),
+            revision: entry_point.docrev(),
+        }),
         Err(err) => match err.kind() {
-            ErrorKind::InvalidData => match SMBiosEntryPoint32::try_load_from_file(entry_path) {
-                Ok(entry_point) => {
-                    version = SMBiosVersion {
-                        major: entry_point.major_version(),
-                        minor: entry_point.minor_version(),
-                        revision: 0,
-                    }
-                }
-                Err(err) => return Err(err),
-            },
-            _ => return Err(err),
+            ErrorKind::InvalidData => {
+                let entry_point = SMBiosEntryPoint32::try_load_from_file(entry_path)?;
+                Ok(SMBiosVersion {
+                    major: entry_point.major_version(),
+                    minor: entry_point.minor_version(),
+                    revision: 0,
+                })
+            }
+            _ => Err(err),
         },
     }
+}
 
+#[cfg(any(target_os = "linux"))]
+/// Loads [SMBiosData] from the device via /sys/firmware/dmi/tables (on Linux)
+pub fn table_load_from_device() -> Result<SMBiosData, Error> {
+    let version = version_from_entry_file()?;
     SMBiosData::try_load_from_file(SYS_TABLE_FILE, Some(version))
 }
 
+#[cfg(target_os = "linux")]
+/// Loads [SMBiosData] from the per-structure files in /sys/firmware/dmi/entries (on Linux)
+///
+/// Useful when /sys/firmware/dmi/tables/DMI is not readable but the entries
+/// are. The version is read from the entry point when it is readable.
+pub fn table_load_from_entries() -> Result<SMBiosData, Error> {
+    let table = table_load_from_entries_folder(std::path::Path::new(SYS_ENTRIES_FOLDER))?;
+    Ok(SMBiosData::new(table, version_from_entry_file().ok()))
+}
+
+/// Loads an [UndefinedStructTable] from a folder laid out as /sys/firmware/dmi/entries
+///
+/// Each structure is a `<type>-<instance>` folder containing its bytes in a
+/// `raw` file. Structures are ordered by the `position` file the kernel
+/// provides. Those without one follow, ordered by type and then instance. Returns
+/// [ErrorKind::NotFound] when the folder does not exist.
+pub fn table_load_from_entries_folder(
+    folder: &std::path::Path,
+) -> Result<UndefinedStructTable, Error> {
+    let mut entries = Vec::new();
+
+    for entry in std::fs::read_dir(folder)? {
+        let path = entry?.path();
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let (struct_type, instance) = match name.split_once('-') {
+            Some((struct_type, instance)) => {
+                match (struct_type.parse::<u8>(), instance.parse::<u32>()) {
+                    (Ok(struct_type), Ok(instance)) => (struct_type, instance),
+                    _ => continue,
+                }
+            }
+            None => continue,
+        };
+        let position = std::fs::read_to_string(path.join("position"))
+            .ok()
+            .and_then(|position| position.trim().parse::<u32>().ok());
+
+        // Entries without a position follow those with one
+        entries.push((
+            (position.unwrap_or(u32::MAX), struct_type, instance),
+            std::fs::read(path.join("raw"))?,
+        ));
+    }
+
+    entries.sort_by_key(|(key, _)| *key);
+
+    Ok(UndefinedStructTable::from(
+        entries
+            .into_iter()
+            .flat_map(|(_, raw)| raw)
+            .collect::<Vec<u8>>(),
+    ))
+}
+
 #[cfg(any(target_os = "freebsd"))]
 /// Loads [SMBiosData] from the device via /dev/mem (on FreeBSD)
 pub fn table_load_from_device() -> Result<SMBiosData, Error> {
@@ -280,4 +341,70 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_entries_folder() -> io::Result<()> {
+        let folder = std::env::temp_dir().join(format!("smbioslib_entries_{}", std::process::id()));
+        let table_data = std::fs::read("tests/fixtures/qemu_i440fx_synthetic.bin")?;
+        let table = UndefinedStructTable::from(table_data.clone());
+
+        // Lay out the table the way the kernel does, one folder per structure
+        let mut instances = std::collections::HashMap::new();
+        for (position, undefined_struct) in table.iter().enumerate() {
+            let struct_type = undefined_struct.header.struct_type();
+            let instance = instances.entry(struct_type).or_insert(0u32);
+            let entry = folder.join(format!("{}-{}", struct_type, instance));
+            *instance += 1;
+
+            std::fs::create_dir_all(&entry)?;
+            std::fs::write(entry.join("raw"), undefined_struct.to_bytes())?;
+            std::fs::write(entry.join("position"), format!("{}\n", position))?;
+        }
+        let loaded = table_load_from_entries_folder(&folder);
+        std::fs::remove_dir_all(&folder)?;
+
+        let loaded = loaded?;
+        assert_eq!(loaded.iter().count(), table.iter().count());
+        assert_eq!(loaded.to_bytes(), table_data);
+
+        let err = table_load_from_entries_folder(&folder).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        Ok(())
+    }
+
+    #[test]
+    fn test_entries_folder_without_positions() -> io::Result<()> {
+        let folder =
+            std::env::temp_dir().join(format!("smbioslib_entries_mixed_{}", std::process::id()));
+
+        // Some firmware entries lack a readable position file
+        for (struct_type, position) in [
+            (3u8, None),
+            (1, Some(1)),
+            (127, None),
+            (0, Some(0)),
+            (2, None),
+        ]
+        .iter()
+        {
+            let entry = folder.join(format!("{}-0", struct_type));
+            std::fs::create_dir_all(&entry)?;
+            std::fs::write(
+                entry.join("raw"),
+                crate::StructureBuilder::new(*struct_type, *struct_type as u16).build(),
+            )?;
+            if let Some(position) = position {
+                std::fs::write(entry.join("position"), format!("{}\n", position))?;
+            }
+        }
+        let loaded = table_load_from_entries_folder(&folder);
+        std::fs::remove_dir_all(&folder)?;
+
+        let struct_types: Vec<u8> = loaded?
+            .iter()
+            .map(|undefined_struct| undefined_struct.header.struct_type())
+            .collect();
+        assert_eq!(struct_types, vec![0, 1, 2, 3, 127]);
+        Ok(())
+    }
 }
@@ -34,20 +34,32 @@ pub fn table_load_from_device() -> Result<SMBiosData, Error> {
 }
 
 /// Returns smbios raw data
+///
+/// The returned bytes begin with the 8 byte RawSMBIOSData header (calling method,
+/// SMBIOS major/minor version, DMI revision, and table length) which is followed
+/// by the SMBIOS table. Use [WinSMBiosData] to access the header fields.
 pub fn raw_smbios_from_device() -> Result<Vec<u8>, Error> {
     use std::ptr;
 
     unsafe {
         const MEMORY_ERROR_MESSAGE: &'static str = "Memory error";
-        const RAW_SMBIOS_SIGNATURE: u32 = 1381190978u32; // 'RSMB' ASCII bytes == 1381190978
+        // Firmware table provider signature for the raw SMBIOS table provider.
+        // The 4-byte ASCII tag 'RSMB' (0x52, 0x53, 0x4D, 0x42) read as a
+        // big-endian DWORD: 0x52534D42 == 1381190978
+        const RAW_SMBIOS_SIGNATURE: u32 = 0x52534D42u32;
         let max_i32: u32 = i32::MAX.try_into().unwrap();
         let firmware_table_buffer_ptr: *mut u8 = ptr::null_mut();
 
         let buffer_size =
             ffi::GetSystemFirmwareTable(RAW_SMBIOS_SIGNATURE, 0, firmware_table_buffer_ptr, 0);
 
-        // 0 is win32 exception, > i32::MAX is memory exception
-        if buffer_size == 0 || buffer_size > max_i32 {
+        // 0 is win32 exception
+        if buffer_size == 0 {
+            return Err(Error::last_os_error());
+        }
+
+        // > i32::MAX is memory exception
+        if buffer_size > max_i32 {
             return Err(Error::new(ErrorKind::Other, MEMORY_ERROR_MESSAGE));
         }
 
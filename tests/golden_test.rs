@@ -0,0 +1,113 @@
+//! Regression tests against stored raw SMBIOS tables
+//!
+//! Every file in `tests/fixtures` must have an entry in [FIXTURES]; a firmware
+//! quirk reported in a bug is captured by adding its table and expectations here.
+use smbioslib::*;
+use std::{fs, path::Path};
+
+const FIXTURES_FOLDER: &str = "tests/fixtures";
+
+/// Expected values of a stored table
+struct Fixture {
+    path: &'static str,
+    structure_count: usize,
+    bios_vendor: &'static str,
+    system_manufacturer: &'static str,
+    system_product_name: &'static str,
+    processor_versions: &'static [&'static str],
+    memory_device_locators: &'static [&'static str],
+}
+
+const FIXTURES: &[Fixture] = &[Fixture {
+    path: "tests/fixtures/qemu_i440fx_synthetic.bin",
+    structure_count: 9,
+    bios_vendor: "SeaBIOS",
+    system_manufacturer: "QEMU",
+    system_product_name: "Standard PC (i440FX + PIIX, 1996)",
+    processor_versions: &["pc-i440fx-8.2"],
+    memory_device_locators: &["DIMM 0"],
+}];
+
+fn check_fixture(fixture: &Fixture) {
+    let name = fixture.path;
+    let raw = fs::read(name).expect(name);
+    let data = load_smbios_data_from_file(Path::new(name)).expect(name);
+
+    // Files may carry a header (e.g. a Windows dump) ahead of the table
+    let table = data.to_bytes();
+    assert_eq!(data.iter().count(), fixture.structure_count, "{}", name);
+    assert!(raw.ends_with(&table), "{}: round trip", name);
+    assert!(
+        data.iter()
+            .all(|undefined_struct| !undefined_struct.is_empty()),
+        "{}",
+        name
+    );
+
+    let bios = data.first::<SMBiosInformation<'_>>().expect(name);
+    assert_eq!(bios.vendor().to_string(), fixture.bios_vendor, "{}", name);
+
+    let system = data.first::<SMBiosSystemInformation<'_>>().expect(name);
+    assert_eq!(
+        system.manufacturer().to_string(),
+        fixture.system_manufacturer,
+        "{}",
+        name
+    );
+    assert_eq!(
+        system.product_name().to_string(),
+        fixture.system_product_name,
+        "{}",
+        name
+    );
+
+    let processor_versions: Vec<String> = data
+        .collect::<SMBiosProcessorInformation<'_>>()
+        .iter()
+        .map(|processor| processor.processor_version().to_string())
+        .collect();
+    assert_eq!(processor_versions, fixture.processor_versions, "{}", name);
+
+    let memory_device_locators: Vec<String> = data
+        .collect::<SMBiosMemoryDevice<'_>>()
+        .iter()
+        .map(|memory_device| memory_device.device_locator().to_string())
+        .collect();
+    assert_eq!(
+        memory_device_locators, fixture.memory_device_locators,
+        "{}",
+        name
+    );
+
+    assert_eq!(
+        data.all_of_type(SMBiosEndOfTable::STRUCT_TYPE).count(),
+        1,
+        "{}",
+        name
+    );
+}
+
+#[test]
+fn golden_fixtures() {
+    for fixture in FIXTURES {
+        check_fixture(fixture);
+    }
+}
+
+#[test]
+fn fixtures_have_expectations() {
+    for entry in fs::read_dir(Path::new(FIXTURES_FOLDER)).expect("fixtures folder") {
+        let path = entry.expect("fixture entry").path();
+        match path.extension() {
+            Some(extension) if extension != "md" => {}
+            _ => continue,
+        }
+
+        let path = path.to_string_lossy().replace('\\', "/");
+        assert!(
+            FIXTURES.iter().any(|fixture| fixture.path == path),
+            "{} has no entry in FIXTURES",
+            path
+        );
+    }
+}
@@ -11,6 +11,71 @@ fn windows_dump() {
     }
 }
 
+/// Loads the table from sysfs and cross checks it with the raw table bytes
+#[cfg(target_os = "linux")]
+#[test]
+fn linux_sysfs_load() {
+    match (table_load_from_device(), raw_smbios_from_device()) {
+        (Ok(data), Ok(raw)) => {
+            assert!(data.version().is_some());
+            assert_eq!(
+                data.iter().count(),
+                UndefinedStructTable::from(raw).iter().count()
+            );
+        }
+        // Missing sysfs entries (e.g. containers) or restricted permissions
+        // must surface as an io::Error rather than an empty table.
+        (Err(err), _) | (_, Err(err)) => {
+            println!("failure: {:?}", err);
+            assert!(matches!(
+                err.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied
+            ));
+        }
+    }
+}
+
+/// Loads the table from the per-structure sysfs entries and cross checks it with the raw table bytes
+#[cfg(target_os = "linux")]
+#[test]
+fn linux_sysfs_entries_load() {
+    match (table_load_from_entries(), raw_smbios_from_device()) {
+        (Ok(data), Ok(raw)) => {
+            assert_eq!(
+                data.iter().count(),
+                UndefinedStructTable::from(raw).iter().count()
+            );
+        }
+        (Err(err), _) | (_, Err(err)) => {
+            println!("failure: {:?}", err);
+            assert!(matches!(
+                err.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied
+            ));
+        }
+    }
+}
+
+/// Loads the table from the IORegistry and cross checks it with the raw table bytes
+#[cfg(target_os = "macos")]
+#[test]
+fn macos_ioregistry_load() {
+    match (table_load_from_device(), raw_smbios_from_device()) {
+        (Ok(data), Ok(raw)) => {
+            assert!(data.version().is_some());
+            assert_eq!(
+                data.iter().count(),
+                UndefinedStructTable::from(raw).iter().count()
+            );
+        }
+        // Macs without SMBIOS firmware do not publish the AppleSMBIOS service
+        (Err(err), _) | (_, Err(err)) => {
+            println!("failure: {:?}", err);
+            assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+        }
+    }
+}
+
 #[test]
 fn retrieve_system_uuid() {
     match table_load_from_device() {